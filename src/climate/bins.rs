@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+use super::WeatherSample;
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{SpecificEnthalpy, Temperature};
+use crate::units::{Celcius, JoulesPerKg};
+
+/// Hours spent in one joint dry bulb and humidity ratio bin, with the mean conditions that
+/// occurred in it.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WeatherBin {
+    /// Lower edge of the dry bulb temperature bin
+    pub tdry_bulb: Temperature<Celcius>,
+    /// Lower edge of the humidity ratio bin in kg_H₂O kg_Air⁻¹
+    pub hum_ratio: f64,
+    /// Hours in the bin
+    pub hours: f64,
+    /// Mean dry bulb temperature of the bin
+    pub mean_tdry_bulb: Temperature<Celcius>,
+    /// Mean humidity ratio of the bin in kg_H₂O kg_Air⁻¹
+    pub mean_hum_ratio: f64,
+    /// Mean coincident wet bulb temperature of the bin
+    pub mean_twet_bulb: Temperature<Celcius>,
+    /// Mean moist air enthalpy of the bin
+    pub mean_enthalpy: SpecificEnthalpy<JoulesPerKg>,
+}
+
+/// Time weighted sums of one bin.
+#[derive(Default)]
+struct BinSums {
+    hours: f64,
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    twet_bulb: f64,
+    enthalpy: f64,
+}
+
+/// Return the joint bins of dry bulb temperature and humidity ratio that `samples` fall in, sorted
+/// by dry bulb temperature then humidity ratio. Empty bins are left out. `Value` when a width is
+/// not positive or a sample has a negative duration.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 19, bin method
+/// `tdry_bulb_width` Width of the dry bulb temperature bins in K
+/// `hum_ratio_width` Width of the humidity ratio bins in kg_H₂O kg_Air⁻¹
+pub fn get_weather_bins(
+    samples: &[WeatherSample],
+    tdry_bulb_width: f64,
+    hum_ratio_width: f64,
+) -> Result<Vec<WeatherBin>, PsychroLibErr> {
+    let positive = |width: f64| width.is_finite() && width > 0.0;
+    if !positive(tdry_bulb_width)
+        || !positive(hum_ratio_width)
+        || samples.iter().any(|sample| sample.hours < 0.0)
+    {
+        return Err(PsychroLibErr::Value);
+    }
+    let mut bins: BTreeMap<(i64, i64), BinSums> = BTreeMap::new();
+    for sample in samples {
+        let tdry_bulb = sample.state.tdry_bulb_c();
+        let hum_ratio = sample.state.hum_ratio();
+        let pres = sample.state.pres_ambient_pa();
+        let key = (
+            (tdry_bulb / tdry_bulb_width).floor() as i64,
+            (hum_ratio / hum_ratio_width).floor() as i64,
+        );
+        let sums = bins.entry(key).or_default();
+        sums.hours += sample.hours;
+        sums.tdry_bulb += sample.hours * tdry_bulb;
+        sums.hum_ratio += sample.hours * hum_ratio;
+        sums.twet_bulb += sample.hours * si::twet_bulb_from_hum_ratio(tdry_bulb, hum_ratio, pres)?;
+        sums.enthalpy += sample.hours * si::moist_air_enthalpy(tdry_bulb, hum_ratio);
+    }
+    Ok(bins
+        .into_iter()
+        .filter(|(_, sums)| sums.hours > 0.0)
+        .map(|((tdry_bulb, hum_ratio), sums)| WeatherBin {
+            tdry_bulb: Temperature::<Celcius>::from(tdry_bulb as f64 * tdry_bulb_width),
+            hum_ratio: hum_ratio as f64 * hum_ratio_width,
+            hours: sums.hours,
+            mean_tdry_bulb: Temperature::<Celcius>::from(sums.tdry_bulb / sums.hours),
+            mean_hum_ratio: sums.hum_ratio / sums.hours,
+            mean_twet_bulb: Temperature::<Celcius>::from(sums.twet_bulb / sums.hours),
+            mean_enthalpy: SpecificEnthalpy::<JoulesPerKg>::from(sums.enthalpy / sums.hours),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Pressure;
+    use crate::state::MoistAirState;
+    use crate::units::Pascal;
+
+    fn sample(tdry_bulb: f64, hum_ratio: f64, hours: f64) -> WeatherSample {
+        WeatherSample {
+            state: MoistAirState::from_hum_ratio(
+                Temperature::<Celcius>::from(tdry_bulb),
+                hum_ratio,
+                Pressure::<Pascal>::from(101_325),
+            )
+            .unwrap(),
+            hours,
+        }
+    }
+
+    #[test]
+    fn bins() {
+        let samples = [
+            sample(21.0, 0.0085, 1.0),
+            sample(-3.0, 0.002, 2.0),
+            sample(23.0, 0.0095, 3.0),
+            sample(23.5, 0.0105, 1.0),
+        ];
+        let bins = get_weather_bins(&samples, 5.0, 0.002).unwrap();
+        assert_eq!(bins.len(), 3);
+        assert_eq!(bins[0].tdry_bulb, Temperature::<Celcius>::from(-5));
+        assert!((bins[0].hum_ratio - 0.002).abs() < 1E-12);
+        assert!((bins[0].hours - 2.0).abs() < 1E-12);
+        assert_eq!(bins[1].tdry_bulb, Temperature::<Celcius>::from(20));
+        assert!((bins[1].hum_ratio - 0.008).abs() < 1E-12);
+        assert!((bins[1].hours - 4.0).abs() < 1E-12);
+        assert_eq!(bins[1].mean_tdry_bulb, Temperature::<Celcius>::from(22.5));
+        assert!((bins[1].mean_hum_ratio - 0.00925).abs() < 1E-12);
+        let mean_enthalpy = f64::from(&bins[1].mean_enthalpy);
+        let enthalpy = |t: f64, w: f64| si::moist_air_enthalpy(t, w);
+        let expected = (enthalpy(21.0, 0.0085) + 3.0 * enthalpy(23.0, 0.0095)) / 4.0;
+        assert!((mean_enthalpy - expected).abs() < 0.01);
+        assert!((bins[2].hum_ratio - 0.010).abs() < 1E-12);
+        assert!(matches!(
+            get_weather_bins(&samples, 0.0, 0.002),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+use super::WeatherSample;
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Duration, Energy, MassFlowRate, SpecificEnthalpy, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Day, EnergyUnit, KilojoulesPerKg, MassFlowRateUnit};
+
+/// Hours in a day.
+const HOURS_PER_DAY: f64 = 24.0;
+
+/// Base conditions the degree-days and enthalpy-days are counted against.
+#[derive(Debug)]
+pub struct DegreeDayBase {
+    /// Outdoor temperature below which heating degree-days accrue
+    pub heating: Temperature<Celcius>,
+    /// Outdoor temperature above which cooling degree-days accrue
+    pub cooling: Temperature<Celcius>,
+    /// Indoor state outdoor air is brought to, for the enthalpy-days
+    pub indoor: MoistAirState,
+}
+
+/// Degree-days and enthalpy-days of a weather record.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DegreeDays {
+    /// Heating degree-days in K day
+    pub heating: f64,
+    /// Cooling degree-days in K day
+    pub cooling: f64,
+    /// Cooling enthalpy-days, the outdoor enthalpy above the indoor enthalpy, in kJ kg⁻¹ day
+    pub enthalpy: f64,
+    /// Latent part of the cooling enthalpy-days, from the outdoor humidity ratio above the indoor
+    /// humidity ratio, in kJ kg⁻¹ day
+    pub latent: f64,
+}
+
+impl DegreeDays {
+    /// Return the energy to bring `dry_air_flow` of outdoor air down to the indoor enthalpy over
+    /// the record.
+    /// `dry_air_flow` Mass flow rate of dry air in lb h⁻¹ or kg s⁻¹ or kg h⁻¹
+    #[must_use]
+    pub fn cooling_energy<M: MassFlowRateUnit, E: EnergyUnit>(
+        &self,
+        dry_air_flow: MassFlowRate<M>,
+    ) -> Energy<E> {
+        self.energy(dry_air_flow, self.enthalpy)
+    }
+
+    /// Return the energy to remove the moisture of `dry_air_flow` of outdoor air down to the indoor
+    /// humidity ratio over the record.
+    /// `dry_air_flow` Mass flow rate of dry air in lb h⁻¹ or kg s⁻¹ or kg h⁻¹
+    #[must_use]
+    pub fn latent_energy<M: MassFlowRateUnit, E: EnergyUnit>(
+        &self,
+        dry_air_flow: MassFlowRate<M>,
+    ) -> Energy<E> {
+        self.energy(dry_air_flow, self.latent)
+    }
+
+    fn energy<M: MassFlowRateUnit, E: EnergyUnit>(
+        &self,
+        dry_air_flow: MassFlowRate<M>,
+        enthalpy_days: f64,
+    ) -> Energy<E> {
+        let power = dry_air_flow * SpecificEnthalpy::<KilojoulesPerKg>::from(enthalpy_days);
+        Energy::<E>::from(&(power * Duration::<Day>::from(1)))
+    }
+}
+
+/// Return the heating and cooling degree-days and the cooling enthalpy-days of `samples` against
+/// `base`. `Value` when a sample has a negative duration.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 19
+pub fn get_degree_days(
+    samples: &[WeatherSample],
+    base: &DegreeDayBase,
+) -> Result<DegreeDays, PsychroLibErr> {
+    if samples.iter().any(|sample| sample.hours < 0.0) {
+        return Err(PsychroLibErr::Value);
+    }
+    let theating_c = f64::from(&base.heating);
+    let tcooling_c = f64::from(&base.cooling);
+    let (tin, win) = (base.indoor.tdry_bulb_c(), base.indoor.hum_ratio());
+    let enthalpy_in = si::moist_air_enthalpy(tin, win);
+    let mut degree_days = DegreeDays {
+        heating: 0.0,
+        cooling: 0.0,
+        enthalpy: 0.0,
+        latent: 0.0,
+    };
+    for sample in samples {
+        let days = sample.hours / HOURS_PER_DAY;
+        let (tout, wout) = (sample.state.tdry_bulb_c(), sample.state.hum_ratio());
+        degree_days.heating += days * (theating_c - tout).max(0.0);
+        degree_days.cooling += days * (tout - tcooling_c).max(0.0);
+        let enthalpy = si::moist_air_enthalpy(tout, wout) - enthalpy_in;
+        degree_days.enthalpy += days * enthalpy.max(0.0) / 1000.0;
+        let latent = (si::LATENT_HEAT_VAPORIZATION + si::CP_VAPOR * tin) * (wout - win);
+        degree_days.latent += days * latent.max(0.0) / 1000.0;
+    }
+    Ok(degree_days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{KgPerSecond, KilowattHour};
+
+    #[test]
+    fn degree_days() {
+        let samples = [
+            WeatherSample {
+                state: MoistAirState::test_rel_hum(-5.0, 0.8),
+                hours: 24.0,
+            },
+            WeatherSample {
+                state: MoistAirState::test_rel_hum(30.0, 0.6),
+                hours: 12.0,
+            },
+            WeatherSample {
+                state: MoistAirState::test_rel_hum(20.0, 0.5),
+                hours: 12.0,
+            },
+        ];
+        let base = DegreeDayBase {
+            heating: Temperature::<Celcius>::from(18),
+            cooling: Temperature::<Celcius>::from(18),
+            indoor: MoistAirState::test_rel_hum(24.0, 0.5),
+        };
+        let degree_days = get_degree_days(&samples, &base).unwrap();
+        assert!((degree_days.heating - 23.0).abs() < 1E-6);
+        assert!((degree_days.cooling - 7.0).abs() < 1E-6);
+        assert!((degree_days.enthalpy - 11.689).abs() < 0.001);
+        assert!((degree_days.latent - 8.582).abs() < 0.001);
+        let energy: Energy<KilowattHour> =
+            degree_days.cooling_energy(MassFlowRate::<KgPerSecond>::from(1));
+        assert!((f64::from(energy) - 280.54).abs() < 0.01);
+        let samples = [WeatherSample {
+            state: MoistAirState::test_rel_hum(20.0, 0.5),
+            hours: -1.0,
+        }];
+        assert!(matches!(
+            get_degree_days(&samples, &base),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
@@ -0,0 +1,283 @@
+use super::WeatherSample;
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Pascal, PressureUnit, TemperatureUnit};
+
+/// Half width in K of the band around a design value whose hours give the mean coincident values.
+const COINCIDENT_BAND_K: f64 = 0.5;
+/// Share of the daily range below the design dry bulb temperature at the end of each hour, from
+/// 1:00 to 24:00 solar time.
+const DAILY_RANGE_FRACTIONS: [f64; 24] = [
+    0.88, 0.92, 0.95, 0.98, 1.00, 0.98, 0.91, 0.74, 0.55, 0.38, 0.23, 0.13, 0.05, 0.00, 0.00, 0.06,
+    0.14, 0.24, 0.39, 0.50, 0.59, 0.68, 0.75, 0.82,
+];
+
+/// Design conditions of a weather record at one frequency of exceedance.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignConditions {
+    /// Cooling dry bulb temperature, exceeded for the given share of the hours
+    pub tdry_bulb: Temperature<Celcius>,
+    /// Mean wet bulb temperature coincident with the cooling dry bulb temperature
+    pub tdry_bulb_mcwb: Temperature<Celcius>,
+    /// Wet bulb temperature exceeded for the given share of the hours
+    pub twet_bulb: Temperature<Celcius>,
+    /// Mean dry bulb temperature coincident with the design wet bulb temperature
+    pub twet_bulb_mcdb: Temperature<Celcius>,
+    /// Dew point temperature exceeded for the given share of the hours
+    pub tdew_point: Temperature<Celcius>,
+    /// Humidity ratio at the design dew point in kg_H₂O kg_Air⁻¹
+    pub hum_ratio: f64,
+    /// Mean dry bulb temperature coincident with the design dew point temperature
+    pub tdew_point_mcdb: Temperature<Celcius>,
+    /// Heating dry bulb temperature, the record is colder for the given share of the hours
+    pub heating_tdry_bulb: Temperature<Celcius>,
+}
+
+/// Conditions of one sample used to pick design values.
+struct Point {
+    hours: f64,
+    tdry_bulb: f64,
+    twet_bulb: f64,
+    tdew_point: f64,
+}
+
+/// Return the design conditions of `samples` for a frequency of `exceedance`, 0.004 for the 0.4%
+/// conditions and the matching 99.6% heating temperature. Mean coincident values average the
+/// hours within 0.5 K of the design value. Use a record of several years for stable values.
+/// `Range` when `exceedance` is outside ]0, 0.5]. `Value` when the record is empty or a sample has
+/// a negative duration.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 14
+pub fn get_design_conditions(
+    samples: &[WeatherSample],
+    exceedance: f64,
+) -> Result<DesignConditions, PsychroLibErr> {
+    if !(exceedance > 0.0 && exceedance <= 0.5) {
+        return Err(PsychroLibErr::Range);
+    }
+    if samples.iter().any(|sample| sample.hours < 0.0) {
+        return Err(PsychroLibErr::Value);
+    }
+    let total_hours: f64 = samples.iter().map(|sample| sample.hours).sum();
+    if total_hours <= 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    let mut points = samples
+        .iter()
+        .map(|sample| {
+            let tdry_bulb = sample.state.tdry_bulb_c();
+            let hum_ratio = sample.state.hum_ratio();
+            let pres = sample.state.pres_ambient_pa();
+            Ok(Point {
+                hours: sample.hours,
+                tdry_bulb,
+                twet_bulb: si::twet_bulb_from_hum_ratio(tdry_bulb, hum_ratio, pres)?,
+                tdew_point: si::tdew_point_from_hum_ratio(tdry_bulb, hum_ratio, pres)?,
+            })
+        })
+        .collect::<Result<Vec<_>, PsychroLibErr>>()?;
+    let target_hours = exceedance * total_hours;
+
+    let tdry_bulb = exceeded(&mut points, target_hours, |point| point.tdry_bulb);
+    let tdry_bulb_mcwb = coincident(
+        &points,
+        tdry_bulb,
+        |point| point.tdry_bulb,
+        |point| point.twet_bulb,
+    );
+    let twet_bulb = exceeded(&mut points, target_hours, |point| point.twet_bulb);
+    let twet_bulb_mcdb = coincident(
+        &points,
+        twet_bulb,
+        |point| point.twet_bulb,
+        |point| point.tdry_bulb,
+    );
+    let tdew_point = exceeded(&mut points, target_hours, |point| point.tdew_point);
+    let tdew_point_mcdb = coincident(
+        &points,
+        tdew_point,
+        |point| point.tdew_point,
+        |point| point.tdry_bulb,
+    );
+    let heating_tdry_bulb = -exceeded(&mut points, target_hours, |point| -point.tdry_bulb);
+    let pres = samples
+        .iter()
+        .map(|sample| sample.hours * sample.state.pres_ambient_pa())
+        .sum::<f64>()
+        / total_hours;
+    Ok(DesignConditions {
+        tdry_bulb: Temperature::<Celcius>::from(tdry_bulb),
+        tdry_bulb_mcwb: Temperature::<Celcius>::from(tdry_bulb_mcwb),
+        twet_bulb: Temperature::<Celcius>::from(twet_bulb),
+        twet_bulb_mcdb: Temperature::<Celcius>::from(twet_bulb_mcdb),
+        hum_ratio: si::hum_ratio_from_vap_pres(si::sat_vap_pres(tdew_point), pres)?,
+        tdew_point: Temperature::<Celcius>::from(tdew_point),
+        tdew_point_mcdb: Temperature::<Celcius>::from(tdew_point_mcdb),
+        heating_tdry_bulb: Temperature::<Celcius>::from(heating_tdry_bulb),
+    })
+}
+
+/// Return the 24 hourly states of a design day, from 1:00 to 24:00 solar time. The dry bulb
+/// temperature peaks at `tdry_bulb` at 15:00 and drops by `daily_range` at 5:00, and the wet bulb
+/// temperature peaks at `twet_bulb` and drops by `twet_bulb_range`, both following the standard
+/// fractional profile. Hours whose wet bulb temperature would exceed the dry bulb temperature are
+/// saturated. `Range` for a negative daily range or a wet bulb temperature above the dry bulb
+/// temperature.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 14 table 6
+/// `tdry_bulb` Design dry bulb temperature in °F  or °C or K
+/// `daily_range` Mean daily range of the dry bulb temperature in K
+/// `twet_bulb` Coincident wet bulb temperature in °F  or °C or K
+/// `twet_bulb_range` Mean coincident daily range of the wet bulb temperature in K
+pub fn get_design_day_profile<T: TemperatureUnit, TW: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    daily_range: f64,
+    twet_bulb: Temperature<TW>,
+    twet_bulb_range: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<Vec<MoistAirState>, PsychroLibErr> {
+    if [daily_range, twet_bulb_range]
+        .iter()
+        .any(|range| range.is_nan() || *range < 0.0)
+    {
+        return Err(PsychroLibErr::Range);
+    }
+    let tdb = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let twb = f64::from(&Temperature::<Celcius>::from(&twet_bulb));
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    si::check_pres_positive(pres)?;
+    if twb > tdb {
+        return Err(PsychroLibErr::Range);
+    }
+    DAILY_RANGE_FRACTIONS
+        .iter()
+        .map(|fraction| {
+            let t = tdb - fraction * daily_range;
+            let tw = (twb - fraction * twet_bulb_range).min(t);
+            MoistAirState::from_hum_ratio(
+                Temperature::<Celcius>::from(t),
+                si::hum_ratio_from_twet_bulb(t, tw, pres)?,
+                Pressure::<Pascal>::from(pres),
+            )
+        })
+        .collect()
+}
+
+/// Largest value of `key` that is reached or exceeded for `target_hours`.
+fn exceeded(points: &mut [Point], target_hours: f64, key: impl Fn(&Point) -> f64) -> f64 {
+    points.sort_by(|a, b| key(b).total_cmp(&key(a)));
+    let mut hours = 0.0;
+    for point in points.iter() {
+        hours += point.hours;
+        if hours >= target_hours {
+            return key(point);
+        }
+    }
+    points.last().map_or(f64::NAN, key)
+}
+
+/// Time weighted mean of `value` over the points whose `key` is within the coincident band of
+/// `design`.
+fn coincident(
+    points: &[Point],
+    design: f64,
+    key: impl Fn(&Point) -> f64,
+    value: impl Fn(&Point) -> f64,
+) -> f64 {
+    let (hours, sum) = points
+        .iter()
+        .filter(|point| (key(point) - design).abs() <= COINCIDENT_BAND_K)
+        .fold((0.0, 0.0), |(hours, sum), point| {
+            (hours + point.hours, sum + point.hours * value(point))
+        });
+    sum / hours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Pressure;
+    use crate::state::MoistAirState;
+    use crate::units::Pascal;
+
+    #[test]
+    fn design_conditions() {
+        let samples: Vec<WeatherSample> = (0..100)
+            .map(|i| WeatherSample {
+                state: MoistAirState::from_rel_hum(
+                    Temperature::<Celcius>::from(f64::from(i) * 0.4),
+                    0.5,
+                    Pressure::<Pascal>::from(101_325),
+                )
+                .unwrap(),
+                hours: 1.0,
+            })
+            .collect();
+        let design = get_design_conditions(&samples, 0.02).unwrap();
+        assert_eq!(design.tdry_bulb, Temperature::<Celcius>::from(39.2));
+        assert!((f64::from(&design.tdry_bulb_mcwb) - 29.637).abs() < 0.001);
+        assert!((f64::from(&design.twet_bulb) - 29.637).abs() < 0.001);
+        assert!((f64::from(&design.twet_bulb_mcdb) - 39.2).abs() < 0.001);
+        assert!((f64::from(&design.tdew_point) - 26.855).abs() < 0.001);
+        assert!((design.hum_ratio - 0.022_497).abs() < 1E-6);
+        assert_eq!(design.heating_tdry_bulb, Temperature::<Celcius>::from(0.4));
+        assert!(matches!(
+            get_design_conditions(&samples, 0.0),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_design_conditions(&[], 0.01),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn design_day() {
+        let profile = get_design_day_profile(
+            Temperature::<Celcius>::from(33),
+            14.0,
+            Temperature::<Celcius>::from(24),
+            4.0,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert_eq!(profile.len(), 24);
+        assert_eq!(
+            profile[14].tdry_bulb::<Celcius>(),
+            Temperature::<Celcius>::from(33)
+        );
+        assert_eq!(
+            profile[4].tdry_bulb::<Celcius>(),
+            Temperature::<Celcius>::from(19)
+        );
+        let twet_bulb: Temperature<Celcius> = profile[14].twet_bulb().unwrap();
+        assert!((f64::from(&twet_bulb) - 24.0).abs() < 2E-3);
+        // At 5:00 the wet bulb temperature is 20 °C, above the dry bulb, so the hour is saturated.
+        assert!((profile[4].rel_hum().unwrap() - 1.0).abs() < 1E-9);
+        // At 9:00 both have dropped by 55 % of their range.
+        let twet_bulb: Temperature<Celcius> = profile[8].twet_bulb().unwrap();
+        assert!((f64::from(&twet_bulb) - 21.8).abs() < 2E-3);
+        assert!(profile[8].hum_ratio() < profile[14].hum_ratio());
+        assert!(matches!(
+            get_design_day_profile(
+                Temperature::<Celcius>::from(33),
+                -1.0,
+                Temperature::<Celcius>::from(24),
+                4.0,
+                Pressure::<Pascal>::from(101_325),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_design_day_profile(
+                Temperature::<Celcius>::from(24),
+                14.0,
+                Temperature::<Celcius>::from(33),
+                4.0,
+                Pressure::<Pascal>::from(101_325),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+}
@@ -0,0 +1,12 @@
+//! Statistics of weather records for energy reporting and the selection of design conditions.
+mod bins;
+pub use bins::{get_weather_bins, WeatherBin};
+
+mod degree_days;
+pub use degree_days::{get_degree_days, DegreeDayBase, DegreeDays};
+
+mod design;
+pub use design::{get_design_conditions, get_design_day_profile, DesignConditions};
+
+mod weather;
+pub use weather::WeatherSample;
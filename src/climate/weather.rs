@@ -0,0 +1,10 @@
+use crate::state::MoistAirState;
+
+/// Outdoor state held for a number of hours, one entry of a weather record.
+#[derive(Debug)]
+pub struct WeatherSample {
+    /// Outdoor state
+    pub state: MoistAirState,
+    /// Duration of the sample in h
+    pub hours: f64,
+}
@@ -0,0 +1,63 @@
+//! Physical constants used throughout the psychrometric calculations, as typed quantities. Values
+//! follow ASHRAE Handbook - Fundamentals (2017) ch. 1 and are the ones the library uses internally.
+use crate::quantities::{Pressure, SpecificHeat, Temperature};
+use crate::units::{Celcius, JoulesPerKgKelvin, Pascal};
+
+/// Standard atmospheric pressure at sea level, 101 325 Pa.
+pub const ONE_ATM: Pressure<Pascal> = Pressure::from_milli_pascal(101_325_000);
+
+/// Standard atmospheric temperature at sea level, 15 °C.
+pub const STANDARD_SEA_LEVEL_TEMPERATURE: Temperature<Celcius> =
+    Temperature::from_micro_kelvin(288_150_000);
+
+/// Triple point of water, 0.01 °C.
+pub const TRIPLE_POINT_WATER: Temperature<Celcius> = Temperature::from_micro_kelvin(273_160_000);
+
+/// Freezing point of water at one atmosphere, 0 °C.
+pub const FREEZING_POINT_WATER: Temperature<Celcius> = Temperature::from_micro_kelvin(273_150_000);
+
+/// Gas constant for dry air, 287.042 J kg⁻¹ K⁻¹.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 1
+pub const R_DRY_AIR: SpecificHeat<JoulesPerKgKelvin> = SpecificHeat::from_base_units(287_042);
+
+/// Gas constant for water vapor, 461.52 J kg⁻¹ K⁻¹.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 1
+pub const R_WATER_VAPOR: SpecificHeat<JoulesPerKgKelvin> = SpecificHeat::from_base_units(461_520);
+
+/// Ratio of the molecular weight of water vapor to that of dry air, 18.015268 / 28.966.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 22
+pub const MOLECULAR_WEIGHT_RATIO: f64 = 0.621_945;
+
+/// Specific heat of dry air at constant pressure, 1006 J kg⁻¹ K⁻¹.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30
+pub const CP_DRY_AIR: SpecificHeat<JoulesPerKgKelvin> = SpecificHeat::from_base_units(1_006_000);
+
+/// Specific heat of water vapor at constant pressure, 1860 J kg⁻¹ K⁻¹.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30
+pub const CP_WATER_VAPOR: SpecificHeat<JoulesPerKgKelvin> =
+    SpecificHeat::from_base_units(1_860_000);
+
+/// Specific heat of liquid water, 4186 J kg⁻¹ K⁻¹.
+pub const CP_WATER: SpecificHeat<JoulesPerKgKelvin> = SpecificHeat::from_base_units(4_186_000);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::psychrolib::si;
+    use crate::units::{Atmosphere, Kelvin};
+
+    #[test]
+    fn match_internal_values() {
+        assert_eq!(ONE_ATM, Pressure::<Atmosphere>::from(1));
+        assert!((f64::from(&STANDARD_SEA_LEVEL_TEMPERATURE) - 15.0).abs() < 1E-9);
+        assert!((f64::from(&TRIPLE_POINT_WATER) - si::TRIPLE_POINT_WATER_C).abs() < 1E-9);
+        let freezing = Temperature::<Kelvin>::from(&FREEZING_POINT_WATER);
+        assert!((f64::from(&freezing) - si::ZERO_CELCIUS_K).abs() < 1E-9);
+        assert!((f64::from(&R_DRY_AIR) - si::R_DA).abs() < 1E-9);
+        assert!((f64::from(&R_WATER_VAPOR) - si::R_W).abs() < 1E-9);
+        assert!((MOLECULAR_WEIGHT_RATIO - si::MOLECULAR_WEIGHT_RATIO).abs() < f64::EPSILON);
+        assert!((f64::from(&CP_DRY_AIR) - si::CP_DA).abs() < 1E-9);
+        assert!((f64::from(&CP_WATER_VAPOR) - si::CP_VAPOR).abs() < 1E-9);
+        assert!((f64::from(&CP_WATER) - si::CP_WATER).abs() < 1E-9);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Duration, RelativeHumidity, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, DurationUnit};
+
+/// Condition checked on every sample.
+#[derive(Debug)]
+pub enum AlarmCondition {
+    /// Dew point within `margin` K of the surface temperature of the sample, or above it. Never
+    /// met by samples without a surface temperature.
+    DewPointNearSurface {
+        /// Margin in K
+        margin: f64,
+    },
+    /// Dew point above the threshold.
+    DewPointAbove(Temperature<Celcius>),
+    /// Relative humidity above the threshold.
+    RelHumAbove(RelativeHumidity),
+}
+
+impl AlarmCondition {
+    fn is_met(
+        &self,
+        state: &MoistAirState,
+        tsurface: Option<&Temperature<Celcius>>,
+    ) -> Result<bool, PsychroLibErr> {
+        let tdew_point = || {
+            si::tdew_point_from_hum_ratio(
+                state.tdry_bulb_c(),
+                state.hum_ratio(),
+                state.pres_ambient_pa(),
+            )
+        };
+        Ok(match self {
+            AlarmCondition::DewPointNearSurface { margin } => match tsurface {
+                Some(tsurface) => tdew_point()? >= f64::from(tsurface) - margin,
+                None => false,
+            },
+            AlarmCondition::DewPointAbove(threshold) => tdew_point()? > f64::from(threshold),
+            AlarmCondition::RelHumAbove(threshold) => state.rel_hum()? > threshold.as_fraction(),
+        })
+    }
+}
+
+/// Condition that raises an alarm once it has held without interruption for `persistence`.
+#[derive(Debug)]
+pub struct AlarmRule<U: DurationUnit> {
+    /// Condition checked on every sample
+    pub condition: AlarmCondition,
+    /// Time the condition must hold before the alarm is raised, zero to raise it at once
+    pub persistence: Duration<U>,
+}
+
+/// State measured at a point in time, with the temperature of the surface watched for
+/// condensation if there is one.
+#[derive(Debug)]
+pub struct AlarmSample<U: DurationUnit> {
+    /// Time of the measurement from any fixed origin
+    pub time: Duration<U>,
+    /// Measured state of the air
+    pub state: MoistAirState,
+    /// Temperature of the surface, for `AlarmCondition::DewPointNearSurface`
+    pub tsurface: Option<Temperature<Celcius>>,
+}
+
+/// Period during which an alarm was raised.
+#[derive(Debug)]
+pub struct AlarmEvent<U: DurationUnit> {
+    /// Time of the first sample of the uninterrupted run meeting the condition
+    pub start: Duration<U>,
+    /// Time of the sample at which the condition had held for the persistence
+    pub raised: Duration<U>,
+    /// Time of the first sample no longer meeting the condition. `None` when the alarm is still
+    /// raised at the last sample.
+    pub cleared: Option<Duration<U>>,
+}
+
+/// Return the alarms raised by `rule` over `samples`, in time order. The condition must hold on
+/// every sample of a run, so a single sample outside the condition resets the persistence timer.
+/// `Value` when the samples are not in time order.
+pub fn get_alarm_events<U: DurationUnit>(
+    samples: &[AlarmSample<U>],
+    rule: &AlarmRule<U>,
+) -> Result<Vec<AlarmEvent<U>>, PsychroLibErr> {
+    let persistence = f64::from(&rule.persistence);
+    let mut events: Vec<AlarmEvent<U>> = Vec::new();
+    let mut run_start: Option<f64> = None;
+    let mut raised = false;
+    let mut previous_time = f64::NEG_INFINITY;
+    for sample in samples {
+        let time = f64::from(&sample.time);
+        if time < previous_time {
+            return Err(PsychroLibErr::Value);
+        }
+        previous_time = time;
+        if rule
+            .condition
+            .is_met(&sample.state, sample.tsurface.as_ref())?
+        {
+            let start = *run_start.get_or_insert(time);
+            if !raised && time - start >= persistence {
+                events.push(AlarmEvent {
+                    start: Duration::<U>::from(start),
+                    raised: Duration::<U>::from(time),
+                    cleared: None,
+                });
+                raised = true;
+            }
+        } else {
+            if raised {
+                if let Some(event) = events.last_mut() {
+                    event.cleared = Some(Duration::<U>::from(time));
+                }
+            }
+            run_start = None;
+            raised = false;
+        }
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Pressure;
+    use crate::units::{Minute, Pascal};
+
+    fn sample(minute: i64, tdew_point: f64, tsurface: f64) -> AlarmSample<Minute> {
+        AlarmSample {
+            time: Duration::<Minute>::from(minute),
+            state: MoistAirState::from_tdew_point(
+                Temperature::<Celcius>::from(22),
+                Temperature::<Celcius>::from(tdew_point),
+                Pressure::<Pascal>::from(101_325),
+            )
+            .unwrap(),
+            tsurface: Some(Temperature::<Celcius>::from(tsurface)),
+        }
+    }
+
+    #[test]
+    fn surface_condensation() {
+        let rule = AlarmRule {
+            condition: AlarmCondition::DewPointNearSurface { margin: 2.0 },
+            persistence: Duration::<Minute>::from(15),
+        };
+        let samples = [
+            sample(0, 13.5, 15.0),
+            sample(5, 13.5, 15.0),
+            sample(10, 13.5, 15.0),
+            sample(15, 13.5, 18.0),
+            sample(20, 12.0, 15.0),
+            sample(25, 14.0, 15.0),
+            sample(30, 14.0, 15.0),
+            sample(40, 14.0, 15.0),
+            sample(45, 15.5, 15.0),
+            sample(50, 10.0, 15.0),
+        ];
+        // The surface warms up before the condition has held for 15 minutes.
+        assert!(get_alarm_events(&samples[..4], &rule).unwrap().is_empty());
+        let events = get_alarm_events(&samples[..8], &rule).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].start, Duration::<Minute>::from(25));
+        assert_eq!(events[0].raised, Duration::<Minute>::from(40));
+        assert!(events[0].cleared.is_none());
+        let events = get_alarm_events(&samples, &rule).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].cleared, Some(Duration::<Minute>::from(50)));
+    }
+
+    #[test]
+    fn thresholds() {
+        let rule = AlarmRule {
+            condition: AlarmCondition::RelHumAbove(RelativeHumidity::from_percent(60.0).unwrap()),
+            persistence: Duration::<Minute>::from(0),
+        };
+        let samples = [sample(0, 10.0, 15.0), sample(1, 16.0, 15.0)];
+        let events = get_alarm_events(&samples, &rule).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].raised, Duration::<Minute>::from(1));
+        let rule = AlarmRule {
+            condition: AlarmCondition::DewPointAbove(Temperature::<Celcius>::from(12)),
+            persistence: Duration::<Minute>::from(0),
+        };
+        assert_eq!(get_alarm_events(&samples, &rule).unwrap().len(), 1);
+        let unordered = [sample(1, 10.0, 15.0), sample(0, 16.0, 15.0)];
+        assert!(matches!(
+            get_alarm_events(&unordered, &rule),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{RelativeHumidity, Temperature};
+use crate::state::MoistAirState;
+use crate::units::Celcius;
+
+/// Humidity the controller holds, with a deadband centered on the setpoint.
+#[derive(Debug)]
+pub enum HumidityTarget {
+    /// Relative humidity setpoint.
+    RelHum {
+        /// Setpoint
+        setpoint: RelativeHumidity,
+        /// Width of the deadband as a fraction [0-1], 0.05 for ±2.5 %
+        deadband: f64,
+    },
+    /// Dew point setpoint. Tracks absolute humidity, so it doesn't react to the dry bulb
+    /// temperature like a relative humidity setpoint does.
+    DewPoint {
+        /// Setpoint
+        setpoint: Temperature<Celcius>,
+        /// Width of the deadband in K
+        deadband: f64,
+    },
+}
+
+/// What the controlled equipment does to the air.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ControlAction {
+    /// Removes water, runs when the air is too humid.
+    #[default]
+    Dehumidify,
+    /// Adds water, runs when the air is too dry.
+    Humidify,
+}
+
+/// Command sent to the equipment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ActuatorCommand {
+    /// Run the equipment.
+    On,
+    /// Stop the equipment.
+    #[default]
+    Off,
+}
+
+/// On/off humidity controller with hysteresis. The equipment starts when the humidity leaves the
+/// deadband on the side it corrects and stops once it has crossed to the other side, so it
+/// doesn't cycle on noise around the setpoint.
+#[derive(Debug)]
+pub struct HumidityController {
+    target: HumidityTarget,
+    action: ControlAction,
+    command: ActuatorCommand,
+}
+
+impl HumidityController {
+    /// Controller holding `target` with equipment that does `action`, starting with the
+    /// equipment off. Errors with `Range` when the deadband is negative.
+    pub fn new(target: HumidityTarget, action: ControlAction) -> Result<Self, PsychroLibErr> {
+        let deadband = match &target {
+            HumidityTarget::RelHum { deadband, .. } | HumidityTarget::DewPoint { deadband, .. } => {
+                *deadband
+            }
+        };
+        if deadband.is_nan() || deadband < 0.0 {
+            return Err(PsychroLibErr::Range);
+        }
+        Ok(HumidityController {
+            target,
+            action,
+            command: ActuatorCommand::Off,
+        })
+    }
+
+    /// Command from the latest state, kept until a state crosses the other edge of the
+    /// deadband.
+    pub fn update(&mut self, state: &MoistAirState) -> Result<ActuatorCommand, PsychroLibErr> {
+        let (value, setpoint, deadband) = match &self.target {
+            HumidityTarget::RelHum { setpoint, deadband } => {
+                (state.rel_hum()?, setpoint.as_fraction(), *deadband)
+            }
+            HumidityTarget::DewPoint { setpoint, deadband } => (
+                si::tdew_point_from_hum_ratio(
+                    state.tdry_bulb_c(),
+                    state.hum_ratio(),
+                    state.pres_ambient_pa(),
+                )?,
+                f64::from(setpoint),
+                *deadband,
+            ),
+        };
+        // Positive when the air is on the side the equipment corrects.
+        let error = match self.action {
+            ControlAction::Dehumidify => value - setpoint,
+            ControlAction::Humidify => setpoint - value,
+        };
+        if error > deadband / 2.0 {
+            self.command = ActuatorCommand::On;
+        } else if error < -deadband / 2.0 {
+            self.command = ActuatorCommand::Off;
+        }
+        Ok(self.command)
+    }
+
+    /// Command given by the last update.
+    #[must_use]
+    pub fn command(&self) -> ActuatorCommand {
+        self.command
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dehumidify_rel_hum() {
+        let target = HumidityTarget::RelHum {
+            setpoint: RelativeHumidity::from_percent(50.0).unwrap(),
+            deadband: 0.06,
+        };
+        let mut controller = HumidityController::new(target, ControlAction::Dehumidify).unwrap();
+        let commands: Vec<ActuatorCommand> = [0.50, 0.52, 0.54, 0.51, 0.48, 0.46, 0.49]
+            .iter()
+            .map(|rel_hum| {
+                controller
+                    .update(&MoistAirState::test_rel_hum(22.0, *rel_hum))
+                    .unwrap()
+            })
+            .collect();
+        use ActuatorCommand::{Off, On};
+        assert_eq!(commands, [Off, Off, On, On, On, Off, Off]);
+        assert_eq!(controller.command(), Off);
+    }
+
+    #[test]
+    fn humidify_dew_point() {
+        let target = HumidityTarget::DewPoint {
+            setpoint: Temperature::<Celcius>::from(5),
+            deadband: 2.0,
+        };
+        let mut controller = HumidityController::new(target, ControlAction::Humidify).unwrap();
+        // Dew points of about 1.9 °C, 5.3 °C and 7.7 °C.
+        assert_eq!(
+            controller
+                .update(&MoistAirState::test_rel_hum(20.0, 0.3))
+                .unwrap(),
+            ActuatorCommand::On
+        );
+        assert_eq!(
+            controller
+                .update(&MoistAirState::test_rel_hum(20.0, 0.38))
+                .unwrap(),
+            ActuatorCommand::On
+        );
+        assert_eq!(
+            controller
+                .update(&MoistAirState::test_rel_hum(20.0, 0.45))
+                .unwrap(),
+            ActuatorCommand::Off
+        );
+        let negative = HumidityTarget::DewPoint {
+            setpoint: Temperature::<Celcius>::from(5),
+            deadband: -1.0,
+        };
+        assert!(matches!(
+            HumidityController::new(negative, ControlAction::Humidify),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+}
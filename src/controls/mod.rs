@@ -0,0 +1,12 @@
+//! Alarms and controllers evaluated on moist air states, the logic behind monitoring dashboards
+//! and humidity control loops.
+mod alarm;
+pub use alarm::{get_alarm_events, AlarmCondition, AlarmEvent, AlarmRule, AlarmSample};
+
+mod controller;
+pub use controller::{ActuatorCommand, ControlAction, HumidityController, HumidityTarget};
+
+mod setpoint;
+pub use setpoint::{
+    get_rel_hum_setpoint, get_tdew_point_setpoint, DewPointSetpoint, RelHumSetpoint,
+};
@@ -0,0 +1,117 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{RelativeHumidity, Temperature};
+use crate::units::{Celcius, TemperatureUnit};
+
+/// Dew point setpoint with its band.
+#[derive(Debug)]
+pub struct DewPointSetpoint {
+    /// Setpoint
+    pub setpoint: Temperature<Celcius>,
+    /// Lowest dew point within the band
+    pub lower: Temperature<Celcius>,
+    /// Highest dew point within the band
+    pub upper: Temperature<Celcius>,
+}
+
+/// Relative humidity setpoint with its band.
+#[derive(Debug)]
+pub struct RelHumSetpoint {
+    /// Setpoint
+    pub setpoint: RelativeHumidity,
+    /// Lowest relative humidity within the band
+    pub lower: RelativeHumidity,
+    /// Highest relative humidity within the band, at most 100 %
+    pub upper: RelativeHumidity,
+}
+
+/// Return the dew point setpoint holding the same humidity as `rel_hum` at the reference
+/// temperature, and the dew points at the edges of its band. The result doesn't depend on the
+/// ambient pressure. `Range` when the band reaches 0 % or the reference temperature is out of
+/// range.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 5, 6 and 24
+/// `rel_hum_tolerance` Half width of the band as a fraction [0-1], 0.05 for ±5 %
+/// `treference` Dry bulb temperature the relative humidity setpoint applies at in °F  or °C or K
+pub fn get_tdew_point_setpoint<T: TemperatureUnit>(
+    rel_hum: &RelativeHumidity,
+    rel_hum_tolerance: f64,
+    treference: Temperature<T>,
+) -> Result<DewPointSetpoint, PsychroLibErr> {
+    let treference = f64::from(&Temperature::<Celcius>::from(&treference));
+    let rel_hum = rel_hum.as_fraction();
+    if rel_hum - rel_hum_tolerance.abs() <= 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let sat_vap_pres = si::sat_vap_pres(treference);
+    let tdew_point = |rel_hum: f64| -> Result<Temperature<Celcius>, PsychroLibErr> {
+        let tdew_point = si::tdew_point_from_vap_pres(treference, rel_hum.min(1.0) * sat_vap_pres)?;
+        Ok(Temperature::<Celcius>::from(tdew_point))
+    };
+    Ok(DewPointSetpoint {
+        setpoint: tdew_point(rel_hum)?,
+        lower: tdew_point(rel_hum - rel_hum_tolerance.abs())?,
+        upper: tdew_point(rel_hum + rel_hum_tolerance.abs())?,
+    })
+}
+
+/// Return the relative humidity at the reference temperature of air with dew point
+/// `tdew_point`, and at the edges of its band. Relative humidities are capped at 100 %.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 5, 6 and 24
+/// `tdew_point` Dew point setpoint in °F  or °C or K
+/// `tdew_point_tolerance` Half width of the band in K
+/// `treference` Dry bulb temperature the relative humidity applies at in °F  or °C or K
+pub fn get_rel_hum_setpoint<TD: TemperatureUnit, T: TemperatureUnit>(
+    tdew_point: Temperature<TD>,
+    tdew_point_tolerance: f64,
+    treference: Temperature<T>,
+) -> Result<RelHumSetpoint, PsychroLibErr> {
+    let tdew_point = f64::from(&Temperature::<Celcius>::from(&tdew_point));
+    let treference = f64::from(&Temperature::<Celcius>::from(&treference));
+    let sat_vap_pres = si::sat_vap_pres(treference);
+    let rel_hum = |tdew_point: f64| {
+        RelativeHumidity::from_fraction((si::sat_vap_pres(tdew_point) / sat_vap_pres).min(1.0))
+    };
+    Ok(RelHumSetpoint {
+        setpoint: rel_hum(tdew_point)?,
+        lower: rel_hum(tdew_point - tdew_point_tolerance.abs())?,
+        upper: rel_hum(tdew_point + tdew_point_tolerance.abs())?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Fahrenheit;
+
+    #[test]
+    fn tdew_point_setpoint() {
+        let rel_hum = RelativeHumidity::from_percent(50.0).unwrap();
+        let setpoint =
+            get_tdew_point_setpoint(&rel_hum, 0.05, Temperature::<Celcius>::from(22)).unwrap();
+        assert!((f64::from(&setpoint.setpoint) - 11.110).abs() < 2E-3);
+        assert!((f64::from(&setpoint.lower) - 9.534).abs() < 2E-3);
+        assert!((f64::from(&setpoint.upper) - 12.553).abs() < 2E-3);
+        assert!(matches!(
+            get_tdew_point_setpoint(&rel_hum, 0.5, Temperature::<Celcius>::from(22)),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn rel_hum_setpoint() {
+        let setpoint = get_rel_hum_setpoint(
+            Temperature::<Celcius>::from(11.11),
+            1.0,
+            Temperature::<Fahrenheit>::from(71.6),
+        )
+        .unwrap();
+        assert!((setpoint.setpoint.as_fraction() - 0.5).abs() < 1E-3);
+        assert!(setpoint.lower.as_fraction() < 0.5 && setpoint.upper.as_fraction() > 0.5);
+        let saturated = get_rel_hum_setpoint(
+            Temperature::<Celcius>::from(21.5),
+            1.0,
+            Temperature::<Celcius>::from(22),
+        )
+        .unwrap();
+        assert!((saturated.upper.as_fraction() - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,63 @@
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use super::{PRES_AMBIENT_RANGE_PA, TDRY_BULB_RANGE_C};
+use crate::quantities::{Pressure, Quantity, RelativeHumidity, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{dimension, Celcius, Pascal, PressureUnit, TemperatureUnit};
+
+/// Value within [`range.0`, `range.1`] taken from 32 bits of `u`.
+fn in_range(u: &mut Unstructured<'_>, range: (f64, f64)) -> Result<f64> {
+    let fraction = f64::from(u.int_in_range(0..=u32::MAX)?) / f64::from(u32::MAX);
+    Ok(range.0 + fraction * (range.1 - range.0))
+}
+
+impl<'a, T: TemperatureUnit> Arbitrary<'a> for Temperature<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let tdry_bulb = Temperature::<Celcius>::from(in_range(u, TDRY_BULB_RANGE_C)?);
+        Ok(Self::from(&tdry_bulb))
+    }
+}
+
+impl<'a, P: PressureUnit> Arbitrary<'a> for Quantity<dimension::Pressure, P> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let pres = Pressure::<Pascal>::from(in_range(u, PRES_AMBIENT_RANGE_PA)?);
+        Ok(Self::from(&pres))
+    }
+}
+
+impl<'a> Arbitrary<'a> for RelativeHumidity {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        RelativeHumidity::from_fraction(in_range(u, (0.0, 1.0))?)
+            .map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+impl<'a> Arbitrary<'a> for MoistAirState {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let tdry_bulb = Temperature::<Celcius>::arbitrary(u)?;
+        let rel_hum = RelativeHumidity::arbitrary(u)?;
+        let pres_ambient = Pressure::<Pascal>::arbitrary(u)?;
+        MoistAirState::from_rel_hum(tdry_bulb, rel_hum.as_fraction(), pres_ambient)
+            .map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_states() {
+        let bytes: Vec<u8> = (0..=255).cycle().step_by(7).take(4096).collect();
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..300 {
+            let state = MoistAirState::arbitrary(&mut u).unwrap();
+            let tdry_bulb = state.tdry_bulb_c();
+            assert!((TDRY_BULB_RANGE_C.0..=TDRY_BULB_RANGE_C.1).contains(&tdry_bulb));
+            assert!((0.0..=1.0 + 1E-9).contains(&state.rel_hum().unwrap()));
+        }
+        let mut empty = Unstructured::new(&[]);
+        let pres = Pressure::<Pascal>::arbitrary(&mut empty).unwrap();
+        assert_eq!(pres, Pressure::<Pascal>::from(PRES_AMBIENT_RANGE_PA.0));
+    }
+}
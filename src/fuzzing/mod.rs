@@ -0,0 +1,17 @@
+//! Generators of physically valid quantities and consistent moist air states for fuzzing and
+//! property testing code built on this crate. `arbitrary` implements `Arbitrary` for
+//! temperatures, pressures, relative humidities and moist air states, `proptest` adds strategies
+//! for the same. Every value is drawn from the ranges below.
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "proptest")]
+mod strategies;
+#[cfg(feature = "proptest")]
+pub use strategies::{moist_air_state, pres_ambient, rel_hum, tdry_bulb};
+
+/// Range of generated dry bulb temperatures in °C, the outdoor extremes of populated places.
+pub const TDRY_BULB_RANGE_C: (f64, f64) = (-50.0, 60.0);
+/// Range of generated ambient pressures in Pa, from about 5000 m above sea level to a deep
+/// high pressure system at sea level.
+pub const PRES_AMBIENT_RANGE_PA: (f64, f64) = (50_000.0, 110_000.0);
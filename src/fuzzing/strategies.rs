@@ -0,0 +1,58 @@
+use core::fmt;
+
+use proptest::prelude::*;
+
+use super::{PRES_AMBIENT_RANGE_PA, TDRY_BULB_RANGE_C};
+use crate::quantities::{Pressure, RelativeHumidity, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Pascal, PressureUnit, TemperatureUnit};
+
+/// Dry bulb temperatures within `TDRY_BULB_RANGE_C`.
+pub fn tdry_bulb<T: TemperatureUnit + fmt::Debug>() -> impl Strategy<Value = Temperature<T>> {
+    (TDRY_BULB_RANGE_C.0..=TDRY_BULB_RANGE_C.1)
+        .prop_map(|tdry_bulb| Temperature::<T>::from(&Temperature::<Celcius>::from(tdry_bulb)))
+}
+
+/// Ambient pressures within `PRES_AMBIENT_RANGE_PA`.
+pub fn pres_ambient<P: PressureUnit + fmt::Debug>() -> impl Strategy<Value = Pressure<P>> {
+    (PRES_AMBIENT_RANGE_PA.0..=PRES_AMBIENT_RANGE_PA.1)
+        .prop_map(|pres| Pressure::<P>::from(&Pressure::<Pascal>::from(pres)))
+}
+
+/// Relative humidities within [0-1].
+pub fn rel_hum() -> impl Strategy<Value = RelativeHumidity> {
+    (0.0..=1.0_f64).prop_map(|fraction| {
+        // Always within [0-1].
+        RelativeHumidity::from_fraction(fraction).unwrap_or_else(|_| unreachable!())
+    })
+}
+
+/// Moist air states from the temperatures, relative humidities and pressures above.
+pub fn moist_air_state() -> impl Strategy<Value = MoistAirState> {
+    (tdry_bulb::<Celcius>(), rel_hum(), pres_ambient::<Pascal>()).prop_filter_map(
+        "state outside the psychrometric range",
+        |(tdry_bulb, rel_hum, pres_ambient)| {
+            MoistAirState::from_rel_hum(tdry_bulb, rel_hum.as_fraction(), pres_ambient).ok()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn consistent_states(state in moist_air_state()) {
+            let rel_hum = state.rel_hum().unwrap();
+            prop_assert!((0.0..=1.0 + 1E-9).contains(&rel_hum));
+            let rebuilt = MoistAirState::from_rel_hum(
+                Temperature::<Celcius>::from(state.tdry_bulb_c()),
+                rel_hum,
+                Pressure::<Pascal>::from(state.pres_ambient_pa()),
+            )
+            .unwrap();
+            prop_assert!((rebuilt.hum_ratio() - state.hum_ratio()).abs() < 1E-6);
+        }
+    }
+}
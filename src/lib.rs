@@ -28,6 +28,7 @@
 //!     - celcius
 //!     - kelvin
 //!     - fahrenheit
+//!     - rankine
 //! - Pressure
 //!     - pascal
 //!     - psi
@@ -36,7 +37,81 @@
 //!     - joules per kilogram
 //!     - kilojoules per kilogram
 //!     - btu per pound
+//! - Specific Entropy
+//!     - joules per kilogram kelvin
+//!     - kilojoules per kilogram kelvin
+//!     - btu per pound rankine
+//! - Specific Heat (same units as Specific Entropy)
+//! - Specific Volume
+//!     - cubic meter per kilogram
+//!     - cubic foot per pound
+//! - Relative Humidity
+//!     - fraction
+//!     - percent
+//! - Humidity Ratio
+//!     - kilogram per kilogram
+//!     - gram per kilogram
+//!     - pound per pound
+//!     - grain per pound
+//! - Length
+//!     - millimeter
+//!     - meter
+//!     - kilometer
+//!     - inch
+//!     - foot
+//! - Volumetric Flow Rate
+//!     - cubic meter per second
+//!     - cubic meter per hour
+//!     - liter per second
+//!     - cubic foot per minute
+//! - Power
+//!     - watt
+//!     - kilowatt
+//!     - btu per hour
+//!     - ton of refrigeration
+//! - Mass Flow Rate
+//!     - kilogram per second
+//!     - kilogram per hour
+//!     - pound per hour
+//! - Density
+//!     - kilogram per cubic meter
+//!     - gram per cubic meter
+//!     - pound per cubic foot
+//! - Duration
+//!     - second
+//!     - minute
+//!     - hour
+//!     - day
+//! - Energy
+//!     - joule
+//!     - kilojoule
+//!     - kilowatt hour
+//!     - btu
 
+//! # Optional features
+//! - `serde`: serialize and deserialize quantities, in their own unit or in one picked with
+//!   `serialize_in` and `deserialize_in`, or as text with the unit with `as_string`
+//! - `schemars`: JSON Schemas of quantities, moist air states and process results
+//! - `arbitrary` and `proptest`: generators of valid quantities and moist air states for fuzzing
+//!   and property tests
+//! - `rand`: random moist air states from a climate zone or from ranges of temperature and
+//!   relative humidity
+//! - `tracing`: spans and events for the progress of the iterative solvers
+//!
+//! The property functions, quantities, units and states are always built. Default features
+//! that can be turned off to slim embedded builds:
+//! - `process`: air conditioning and drying processes, alarms and controllers
+//! - `io`: conversions from raw sensor output and resampling of time series
+//! - `climate`: degree days, bins and design conditions from weather records
+//! - `preservation`: collection preservation metrics
+//! - `meteorology`: weather station and atmospheric helpers
+//! - `tables`: property tables in the layout of the ASHRAE Handbook
+//!
+//! # Humidity ratio floor
+//! The calculations raise humidity ratios below 1E-7 kg_H₂O kg_Air⁻¹ to that floor, both inputs
+//! and results, so logarithms and iterations stay defined in bone dry air. The free functions do
+//! this silently. `MoistAirState` records it in `Diagnostics::hum_ratio_floored`.
+//!
 //! # Functions implemented so far
 //! - `get_trankine_from_tfahrenheit`
 //! - `get_tfahrenheit_from_trankine`
@@ -51,6 +126,105 @@
 //! - `get_vap_pres_from_rel_hum`
 //! - `get_hum_ratio_from_vap_pres`
 //! - `get_hum_ratio_from_rel_hum`
+//! - `get_rel_hum_from_vap_pres_with_policy`
+//! - `get_hum_ratio_from_rel_hum_with_policy`
+//! - `get_sat_vap_pres_with_coefficients`
+//! - `get_moist_air_enthalpy_from_hum_ratio_with_coefficients`
+//! - `get_specific_hum_from_hum_ratio`
+//! - `get_hum_ratio_from_specific_hum`
+//! - `get_mixing_ratio_from_rel_hum`
+//! - `get_specific_hum_from_rel_hum`
+//! - `get_virtual_temperature`
+//! - `get_potential_temperature`
+//! - `get_standard_atm_pressure`
+//! - `get_standard_atm_temperature`
+//! - `get_pressure_altitude`
+//! - `get_reduced_sea_level_pressure`
+//! - `get_vap_pres_from_psychrometer`
+//! - `get_rel_hum_from_psychrometer`
+//! - `get_drying_potential`
+//! - `get_sat_air_enthalpy`
+//! - `get_cooling_tower_performance`
+//! - `is_condensing`
+//! - `get_surface_condensation`
+//! - `get_min_insulation_thickness`
+//! - `get_density_correction_factor`
+//! - `get_standard_flow`
+//! - `get_actual_flow`
+//! - `get_ventilation_load`
+//! - `get_room_humidity_trajectory`
+//! - `get_preservation_index`
+//! - `get_preservation_summary`
+//! - `get_equilibrium_moisture_content`
+//! - `get_moist_air_entropy`
+//! - `get_humid_heat`
+//! - `get_sat_temp_from_pressure`
+//! - `get_sat_hum_ratio_ice`
+//! - `get_enthalpy_potential`
+//! - `get_apparatus_dew_point`
+//! - `get_coil_leaving_from_adp`
+//! - `get_shr_line`
+//! - `get_erv_frosting`
+//! - `get_dehumidifier_removal`
+//! - `get_evaporative_cooler`
+//! - `get_steam_injection`
+//! - `get_erv_preheat`
+//! - `get_sht_tdry_bulb`
+//! - `get_sht_rel_hum`
+//! - `get_sht_reading`
+//! - `get_dht_tdry_bulb`
+//! - `get_dht_rel_hum`
+//! - `get_dht_reading`
+//! - `get_alarm_events`
+//! - `get_tdew_point_setpoint`
+//! - `get_rel_hum_setpoint`
+//! - `get_mold_index_trajectory`
+//! - `get_degree_days`
+//! - `get_weather_bins`
+//! - `get_design_conditions`
+//! - `get_design_day_profile`
+//! - `get_saturation_table`
+//! - `get_moist_air_table`
+//! - `get_cooled_air`
+//! - `get_tadiabatic_saturation`
+//! - `get_moist_air_gas_constant`
+//! - `get_moist_air_molecular_weight`
+//! - `get_compressibility_factor`
+//! - `get_moist_air_density_with_gas_model`
+//! - `get_sat_vap_pres_with_range`
+//! - `get_hum_ratio_from_rel_hum_with_range`
+//! - `get_moist_air_enthalpy_from_rel_hum_with_range`
+//! - `get_sat_air_enthalpy_with_range`
+//! - `get_pressure_dew_point`
+//! - `get_atmospheric_dew_point`
+//! - `get_ppmv_from_hum_ratio`
+//! - `get_hum_ratio_from_ppmv`
+//! - `get_ppmv_from_vap_pres`
+//! - `get_vap_pres_from_ppmv`
+//! - `get_ppmw_from_hum_ratio`
+//! - `get_hum_ratio_from_ppmw`
+//! - `get_tdew_point_from_vap_pres`
+//! - `get_tdew_point_from_rel_hum`
+//! - `get_tdew_point_from_hum_ratio`
+//! - `get_twet_bulb_from_tdew_point`
+//! - `get_rel_hum_from_tdew_point`
+//! - `get_sat_hum_ratio`
+//! - `get_vapor_pressure_deficit`
+//! - `get_degree_of_saturation`
+//! - `get_dry_air_enthalpy`
+//! - `get_dry_air_density`
+//! - `get_dry_air_volume`
+//! - `get_tdry_bulb_from_enthalpy_and_hum_ratio`
+//! - `get_hum_ratio_from_enthalpy_and_tdry_bulb`
+//! - `get_sea_level_pressure`
+//! - `get_station_pressure`
+//! - `calc_psychrometrics_from_rel_hum`
+//! - `calc_psychrometrics_from_twet_bulb`
+//! - `get_absolute_hum_from_vap_pres`
+//! - `get_absolute_hum_from_rel_hum`
+//! - `get_vap_pres_from_absolute_hum`
+//! - `get_rel_hum_from_absolute_hum`
+//! - `get_vapor_pressure_deficit_from_rel_hum`
 
 #![forbid(unsafe_code)]
 #![warn(clippy::all)]
@@ -64,7 +238,43 @@
 
 // TODO: Implement display and formatting for various quantities
 // TODO: Implement pressure, relative humidity, humidity ratio, specific enthalpy
+/// Statistics of weather records for energy reporting and design.
+#[cfg(feature = "climate")]
+pub mod climate;
+/// Typed physical constants used by the psychrometric calculations.
+pub mod constants;
+/// Alarms and controllers operating on moist air states.
+#[cfg(feature = "process")]
+pub mod controls;
+/// Valid quantities and states for fuzzing and property tests.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub mod fuzzing;
+/// Weather station and atmospheric helpers built on top of psychrolib.
+#[cfg(feature = "meteorology")]
+pub mod meteorology;
+/// Collection preservation metrics from temperature and humidity records.
+#[cfg(feature = "preservation")]
+pub mod preservation;
+/// Air conditioning and drying processes.
+#[cfg(feature = "process")]
+pub mod process;
 pub mod psychrolib;
 /// Funtions for psychrometric calculations.
 pub mod quantities;
+/// Random moist air states for Monte Carlo studies.
+#[cfg(feature = "rand")]
+pub mod sampling;
+/// Conversions from raw sensor output.
+#[cfg(feature = "io")]
+pub mod sensors;
+/// Resampling of irregular time series of states and quantities.
+#[cfg(feature = "io")]
+pub mod series;
+/// Moist air states built from any combination of measured inputs.
+pub mod state;
+/// Property tables in the layout of the ASHRAE Handbook.
+#[cfg(feature = "tables")]
+pub mod tables;
 pub mod units;
+/// Self test against the PsychroLib and ASHRAE reference values.
+pub mod validate;
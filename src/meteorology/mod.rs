@@ -0,0 +1,454 @@
+//! Helpers for weather station and atmospheric work. These are not part of PsychroLib but are
+//! built on the same formulas.
+use crate::psychrolib::{
+    get_hum_ratio_from_rel_hum, get_specific_hum_from_hum_ratio, si, PsychroLibErr,
+};
+use crate::quantities::{HumidityRatio, Length, Pressure, Temperature};
+use crate::units::{
+    Celcius, HumidityRatioUnit, Kelvin, KgPerKg, LengthUnit, Meter, Pascal, PressureUnit,
+    TemperatureUnit,
+};
+
+/// Return the mixing ratio given dry-bulb temperature, relative humidity, and pressure.
+/// Meteorologists call the humidity ratio the mixing ratio and usually quote it in g kg⁻¹.
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_mixing_ratio_from_rel_hum<T: TemperatureUnit, P: PressureUnit, H: HumidityRatioUnit>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<HumidityRatio<H>, PsychroLibErr> {
+    let hum_ratio = get_hum_ratio_from_rel_hum(tdry_bulb, rel_hum, pres_ambient)?;
+    Ok(HumidityRatio::<H>::from(&HumidityRatio::<KgPerKg>::from(
+        hum_ratio,
+    )))
+}
+
+/// Return the specific humidity given dry-bulb temperature, relative humidity, and pressure.
+/// Specific humidity is the mass of water vapor per mass of moist air, not dry air, so it is
+/// slightly smaller than the mixing ratio. It is a mass ratio of water too, so it comes in the
+/// units of `HumidityRatio`.
+/// Returns: Specific humidity in g kg⁻¹ or kg kg⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_specific_hum_from_rel_hum<T: TemperatureUnit, P: PressureUnit, H: HumidityRatioUnit>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<HumidityRatio<H>, PsychroLibErr> {
+    let hum_ratio = get_hum_ratio_from_rel_hum(tdry_bulb, rel_hum, pres_ambient)?;
+    let specific_hum = get_specific_hum_from_hum_ratio(hum_ratio)?;
+    Ok(HumidityRatio::<H>::from(&HumidityRatio::<KgPerKg>::from(
+        specific_hum,
+    )))
+}
+
+/// Return the virtual temperature given dry-bulb temperature and humidity ratio.
+/// Virtual temperature is the temperature dry air would need to have the density of the moist
+/// air at the same pressure.
+/// Reference: Wallace & Hobbs, Atmospheric Science (2006) eqn 3.16 without the approximation
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+pub fn get_virtual_temperature<T: TemperatureUnit, TV: TemperatureUnit>(
+    tdry_bulb: Temperature<T>,
+    hum_ratio: f64,
+) -> Result<Temperature<TV>, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let tdry_k = f64::from(&Temperature::<Kelvin>::from(&tdry_bulb));
+    let tvirtual_k = tdry_k * (1.0 + hum_ratio / si::MOLECULAR_WEIGHT_RATIO) / (1.0 + hum_ratio);
+    Ok(Temperature::<TV>::from(&Temperature::<Kelvin>::from(
+        tvirtual_k,
+    )))
+}
+
+/// Return the potential temperature given dry-bulb temperature, pressure and reference pressure.
+/// Potential temperature is the temperature the air would reach if brought adiabatically to the
+/// reference pressure, usually 1000 hPa. Uses Poisson's equation with the dry air constants.
+/// Reference: Wallace & Hobbs, Atmospheric Science (2006) eqn 3.54
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// `pres_reference` Reference pressure in Psi  or Pa or atm
+pub fn get_potential_temperature<
+    T: TemperatureUnit,
+    P: PressureUnit,
+    PR: PressureUnit,
+    TP: TemperatureUnit,
+>(
+    tdry_bulb: Temperature<T>,
+    pres_ambient: Pressure<P>,
+    pres_reference: Pressure<PR>,
+) -> Result<Temperature<TP>, PsychroLibErr> {
+    let pres_reference = Pressure::<P>::from(&pres_reference);
+    si::check_pres_positive(f64::from(&pres_ambient))?;
+    si::check_pres_positive(f64::from(&pres_reference))?;
+    let tdry_k = f64::from(&Temperature::<Kelvin>::from(&tdry_bulb));
+    let tpotential_k = tdry_k * (pres_reference / pres_ambient).powf(si::R_DA / si::CP_DA);
+    Ok(Temperature::<TP>::from(&Temperature::<Kelvin>::from(
+        tpotential_k,
+    )))
+}
+
+/// Return the pressure altitude given station pressure. This is the altitude at which the
+/// standard atmosphere has the given pressure, the inverse of `get_standard_atm_pressure`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 3 solved for altitude
+/// Returns: Pressure altitude in ft  or m
+/// `pres_station` Station pressure in Psi  or Pa or atm
+pub fn get_pressure_altitude<P: PressureUnit, L: LengthUnit>(
+    pres_station: Pressure<P>,
+) -> Result<Length<L>, PsychroLibErr> {
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_station));
+    si::check_pres_positive(pres)?;
+    let altitude_m = (1. - (pres / 101325.).powf(1. / 5.2559)) / 2.25577E-05;
+    Ok(Length::<L>::from(&Length::<Meter>::from(altitude_m)))
+}
+
+/// Formula used to reduce a station barometer reading to sea level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarometricReduction {
+    /// International barometric formula with the air column temperature estimated from the
+    /// outdoor temperature and the standard lapse rate. Used by most European weather services.
+    #[default]
+    International,
+    /// US altimeter setting as reported in METARs (ASOS). It assumes the standard atmosphere
+    /// and ignores the outdoor temperature.
+    UsAltimeterSetting,
+}
+
+/// Return sea level pressure given a barometer reading, the altitude of the sensor and the
+/// outdoor temperature, reduced with the chosen formula.
+/// Reference: WMO Guide to Meteorological Instruments (WMO-No. 8) ch. 3 and
+/// NWS ASOS User's Guide (1998) section 3.2.3
+/// Returns: Sea level pressure in Psi  or Pa or atm
+/// `pres_station` Barometer reading in Psi  or Pa or atm
+/// `altitude` Altitude of the barometer in ft  or m
+/// `tdry_bulb` Outdoor dry bulb temperature in °F  or °C or K
+pub fn get_reduced_sea_level_pressure<
+    P: PressureUnit,
+    L: LengthUnit,
+    T: TemperatureUnit,
+    PS: PressureUnit,
+>(
+    pres_station: Pressure<P>,
+    altitude: Length<L>,
+    tdry_bulb: Temperature<T>,
+    reduction: BarometricReduction,
+) -> Result<Pressure<PS>, PsychroLibErr> {
+    let pres_hpa = f64::from(&Pressure::<Pascal>::from(&pres_station)) / 100.;
+    si::check_pres_positive(pres_hpa)?;
+    let altitude_m = f64::from(&Length::<Meter>::from(&altitude));
+    let sea_level_hpa = match reduction {
+        BarometricReduction::International => {
+            let tdry_c = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+            let lapse = 0.0065 * altitude_m;
+            pres_hpa * (1. - lapse / (tdry_c + lapse + si::ZERO_CELCIUS_K)).powf(-5.257)
+        }
+        BarometricReduction::UsAltimeterSetting => {
+            let n = 0.190284;
+            ((pres_hpa - 0.3).powf(n) + 8.4228807E-05 * altitude_m).powf(1. / n)
+        }
+    };
+    Ok(Pressure::<PS>::from(&Pressure::<Pascal>::from(
+        sea_level_hpa * 100.,
+    )))
+}
+
+/// Scale height in m of an isothermal air column at the mean of the station temperature and the
+/// temperature the standard lapse rate gives at sea level.
+fn scale_height(altitude_m: f64, tdry_bulb_c: f64) -> f64 {
+    let tcolumn_c = tdry_bulb_c + 0.0065 * altitude_m / 2.;
+    287.055 * si::t_kelvin(tcolumn_c) / 9.807
+}
+
+/// Return sea level pressure given station pressure, the altitude of the station and the outdoor
+/// temperature, with the hypsometric equation as in PsychroLib. Close to but not the same as the
+/// reductions of `get_reduced_sea_level_pressure`, which follow the weather services.
+/// Reference: Hess, Introduction to Theoretical Meteorology (1959) ch. 6.5 and
+/// Stull, Meteorology for Scientists and Engineers (2000) ch. 1
+/// Returns: Sea level pressure in Psi  or Pa or atm
+/// `pres_station` Station pressure in Psi  or Pa or atm
+/// `altitude` Altitude of the station in ft  or m
+/// `tdry_bulb` Outdoor dry bulb temperature in °F  or °C or K
+/// Errors with `Value` when the pressure is not positive.
+pub fn get_sea_level_pressure<
+    P: PressureUnit,
+    L: LengthUnit,
+    T: TemperatureUnit,
+    PS: PressureUnit,
+>(
+    pres_station: Pressure<P>,
+    altitude: Length<L>,
+    tdry_bulb: Temperature<T>,
+) -> Result<Pressure<PS>, PsychroLibErr> {
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_station));
+    si::check_pres_positive(pres)?;
+    let altitude_m = f64::from(&Length::<Meter>::from(&altitude));
+    let tdry_c = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    Ok(Pressure::<PS>::from(&Pressure::<Pascal>::from(
+        pres * (altitude_m / scale_height(altitude_m, tdry_c)).exp(),
+    )))
+}
+
+/// Return station pressure given sea level pressure, the altitude of the station and the outdoor
+/// temperature. The inverse of `get_sea_level_pressure`.
+/// Reference: Hess, Introduction to Theoretical Meteorology (1959) ch. 6.5 and
+/// Stull, Meteorology for Scientists and Engineers (2000) ch. 1
+/// Returns: Station pressure in Psi  or Pa or atm
+/// `pres_sea_level` Sea level pressure in Psi  or Pa or atm
+/// `altitude` Altitude of the station in ft  or m
+/// `tdry_bulb` Outdoor dry bulb temperature in °F  or °C or K
+/// Errors with `Value` when the pressure is not positive.
+pub fn get_station_pressure<
+    P: PressureUnit,
+    L: LengthUnit,
+    T: TemperatureUnit,
+    PS: PressureUnit,
+>(
+    pres_sea_level: Pressure<P>,
+    altitude: Length<L>,
+    tdry_bulb: Temperature<T>,
+) -> Result<Pressure<PS>, PsychroLibErr> {
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_sea_level));
+    si::check_pres_positive(pres)?;
+    let altitude_m = f64::from(&Length::<Meter>::from(&altitude));
+    let tdry_c = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    Ok(Pressure::<PS>::from(&Pressure::<Pascal>::from(
+        pres / (altitude_m / scale_height(altitude_m, tdry_c)).exp(),
+    )))
+}
+
+/// Psychrometer coefficient A of the psychrometer equation, in K⁻¹, for the wet bulb covered
+/// with water. Over ice it is scaled by the ratio of the latent heats of vaporization and
+/// sublimation.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PsychrometerCoefficient {
+    /// Assmann aspirated psychrometer, 6.53·10⁻⁴ (1 + 9.44·10⁻⁴ t_w). WMO-No. 8 annex 4.B
+    #[default]
+    Assmann,
+    /// Sling (whirling) psychrometer, 6.62·10⁻⁴. FAO-56 ch. 3
+    Sling,
+    /// Naturally ventilated psychrometer in a thermometer screen, 7.99·10⁻⁴. WMO-No. 8 annex 4.B
+    Screen,
+    /// Coefficient from the instrument specification.
+    Custom(f64),
+}
+
+impl PsychrometerCoefficient {
+    fn over_water(self, twet_bulb_c: f64) -> f64 {
+        match self {
+            PsychrometerCoefficient::Assmann => 6.53E-04 * (1. + 9.44E-04 * twet_bulb_c),
+            PsychrometerCoefficient::Sling => 6.62E-04,
+            PsychrometerCoefficient::Screen => 7.99E-04,
+            PsychrometerCoefficient::Custom(a) => a,
+        }
+    }
+}
+
+/// Return vapor pressure from a psychrometer reading with the classical psychrometer equation
+/// e = e_s(t_w) - A p (t - t_w).
+/// Reference: WMO Guide to Meteorological Instruments (WMO-No. 8) annex 4.B
+/// Returns: Partial pressure of water vapor in moist air in Psi  or Pa or atm
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `twet_bulb` Wet bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_vap_pres_from_psychrometer<
+    T: TemperatureUnit,
+    TW: TemperatureUnit,
+    P: PressureUnit,
+    PV: PressureUnit,
+>(
+    tdry_bulb: Temperature<T>,
+    twet_bulb: Temperature<TW>,
+    pres_ambient: Pressure<P>,
+    coefficient: PsychrometerCoefficient,
+) -> Result<Pressure<PV>, PsychroLibErr> {
+    let tdb = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let twb = f64::from(&Temperature::<Celcius>::from(&twet_bulb));
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    si::check_pres_positive(pres)?;
+    if twb > tdb {
+        return Err(PsychroLibErr::Range);
+    }
+    si::check_tdry_bulb_range(tdb)?;
+    let mut a = coefficient.over_water(twb);
+    if twb < si::FREEZING_POINT_WATER_C {
+        a *= 2501. / 2834.;
+    }
+    let vap_pres = si::sat_vap_pres(twb) - a * pres * (tdb - twb);
+    if vap_pres < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(Pressure::<PV>::from(&Pressure::<Pascal>::from(vap_pres)))
+}
+
+/// Return relative humidity from a psychrometer reading with the classical psychrometer
+/// equation. See `get_vap_pres_from_psychrometer`.
+/// Returns: Relative humidity [0-1]
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `twet_bulb` Wet bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_rel_hum_from_psychrometer<T: TemperatureUnit, TW: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    twet_bulb: Temperature<TW>,
+    pres_ambient: Pressure<P>,
+    coefficient: PsychrometerCoefficient,
+) -> Result<f64, PsychroLibErr> {
+    let tdb = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let vap_pres: Pressure<Pascal> =
+        get_vap_pres_from_psychrometer(tdry_bulb, twet_bulb, pres_ambient, coefficient)?;
+    Ok(f64::from(&vap_pres) / si::sat_vap_pres(tdb))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::psychrolib::get_standard_atm_pressure;
+    use crate::units::{Atmosphere, Fahrenheit, Foot, GramsPerKg};
+
+    #[test]
+    fn mixing_ratio_and_specific_hum_in_grams_per_kg() {
+        let mixing_ratio: HumidityRatio<GramsPerKg> = get_mixing_ratio_from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            0.5,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((f64::from(mixing_ratio) - 9.881).abs() < 0.005);
+        let specific_hum: HumidityRatio<GramsPerKg> = get_specific_hum_from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            0.5,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((f64::from(specific_hum) - 9.784).abs() < 0.005);
+    }
+
+    #[test]
+    fn virtual_temperature() {
+        let tvirtual: Temperature<Celcius> =
+            get_virtual_temperature(Temperature::<Fahrenheit>::from(86), 0.02).unwrap();
+        assert_eq!(tvirtual, Temperature::<Celcius>::from(33.613_19));
+        let tvirtual: Temperature<Celcius> =
+            get_virtual_temperature(Temperature::<Celcius>::from(30), 0.0).unwrap();
+        assert_eq!(tvirtual, Temperature::<Celcius>::from(30));
+    }
+
+    #[test]
+    fn potential_temperature() {
+        let tpotential: Temperature<Celcius> = get_potential_temperature(
+            Temperature::<Celcius>::from(20),
+            Pressure::<Pascal>::from(85_000),
+            Pressure::<Pascal>::from(100_000),
+        )
+        .unwrap();
+        assert_eq!(tpotential, Temperature::<Celcius>::from(33.913_93));
+        let tpotential: Temperature<Celcius> = get_potential_temperature(
+            Temperature::<Celcius>::from(20),
+            Pressure::<Atmosphere>::from(1),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert_eq!(tpotential, Temperature::<Celcius>::from(20));
+    }
+
+    #[test]
+    fn pressure_altitude() {
+        let altitude: Length<Meter> =
+            get_pressure_altitude(Pressure::<Pascal>::from(84_555.932)).unwrap();
+        assert_eq!(altitude, Length::<Meter>::from(1500));
+        let pres_station: Pressure<Pascal> =
+            get_standard_atm_pressure(Length::<Foot>::from(5280)).unwrap();
+        let altitude: Length<Foot> = get_pressure_altitude(pres_station).unwrap();
+        assert!((f64::from(altitude) - 5280.0).abs() < 0.01);
+        assert!(matches!(
+            get_pressure_altitude::<Pascal, Meter>(Pressure::<Pascal>::from(0)),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn reduced_sea_level_pressure() {
+        let pres: Pressure<Pascal> = get_reduced_sea_level_pressure(
+            Pressure::<Pascal>::from(95_000),
+            Length::<Meter>::from(500),
+            Temperature::<Celcius>::from(15),
+            BarometricReduction::International,
+        )
+        .unwrap();
+        assert_eq!(pres, Pressure::<Pascal>::from(100_769.72));
+        let pres: Pressure<Pascal> = get_reduced_sea_level_pressure(
+            Pressure::<Pascal>::from(95_000),
+            Length::<Meter>::from(500),
+            Temperature::<Fahrenheit>::from(100),
+            BarometricReduction::UsAltimeterSetting,
+        )
+        .unwrap();
+        assert_eq!(pres, Pressure::<Pascal>::from(100_812.34));
+    }
+
+    #[test]
+    fn sea_level_and_station_pressure() {
+        use crate::units::Psi;
+        let pres: Pressure<Pascal> = get_sea_level_pressure(
+            Pressure::<Pascal>::from(101_226.5),
+            Length::<Meter>::from(105),
+            Temperature::<Celcius>::from(17.19),
+        )
+        .unwrap();
+        assert!((f64::from(&pres) - 102_483.46).abs() < 0.01);
+        let pres: Pressure<Psi> = get_sea_level_pressure(
+            Pressure::<Psi>::from(14.681_662_559),
+            Length::<Foot>::from(343.3),
+            Temperature::<Fahrenheit>::from(62.5),
+        )
+        .unwrap();
+        assert!((f64::from(&pres) - 14.863_49).abs() < 1E-4);
+        let pres: Pressure<Pascal> = get_station_pressure(
+            Pressure::<Pascal>::from(102_483.46),
+            Length::<Meter>::from(105),
+            Temperature::<Celcius>::from(17.19),
+        )
+        .unwrap();
+        assert!((f64::from(&pres) - 101_226.5).abs() < 0.01);
+        assert!(matches!(
+            get_station_pressure::<Pascal, Meter, Celcius, Pascal>(
+                Pressure::<Pascal>::from(0),
+                Length::<Meter>::from(105),
+                Temperature::<Celcius>::from(17.19),
+            ),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn rel_hum_from_psychrometer() {
+        let rel_hum = |coefficient| {
+            get_rel_hum_from_psychrometer(
+                Temperature::<Celcius>::from(25),
+                Temperature::<Celcius>::from(18),
+                Pressure::<Pascal>::from(101_325),
+                coefficient,
+            )
+            .unwrap()
+        };
+        assert!((rel_hum(PsychrometerCoefficient::Assmann) - 0.502_73).abs() < 1E-4);
+        assert!((rel_hum(PsychrometerCoefficient::Sling) - 0.503_20).abs() < 1E-4);
+        assert!((rel_hum(PsychrometerCoefficient::Screen) - 0.472_54).abs() < 1E-4);
+        assert!((rel_hum(PsychrometerCoefficient::Custom(7.99E-04)) - 0.472_54).abs() < 1E-4);
+    }
+
+    #[test]
+    fn rel_hum_from_psychrometer_over_ice() {
+        let rel_hum = get_rel_hum_from_psychrometer(
+            Temperature::<Celcius>::from(-2),
+            Temperature::<Celcius>::from(-5),
+            Pressure::<Pascal>::from(101_325),
+            PsychrometerCoefficient::Sling,
+        )
+        .unwrap();
+        assert!((rel_hum - 0.433_01).abs() < 1E-4);
+    }
+}
@@ -0,0 +1,180 @@
+//! Collection preservation metrics from records of temperature and relative humidity, as used to
+//! monitor museums, archives and libraries.
+use crate::psychrolib::PsychroLibErr;
+use crate::quantities::{RelativeHumidity, Temperature};
+use crate::units::{Celcius, TemperatureUnit};
+
+mod mold;
+pub use mold::{get_mold_index_trajectory, MoldSensitivity};
+
+mod wetness;
+pub use wetness::{SurfaceTemperature, TimeOfWetnessCategory, WetnessAccumulator, WetnessSummary};
+
+/// Relative humidity above which mold can germinate on most collection materials.
+const MOLD_GERMINATION_REL_HUM: f64 = 0.65;
+/// Deviation of relative humidity from its mean allowed by ASHRAE class AA.
+const SAFE_REL_HUM_DEVIATION: f64 = 0.05;
+/// Deviation of relative humidity from its mean allowed by ASHRAE class A.
+const MODERATE_REL_HUM_DEVIATION: f64 = 0.10;
+
+/// Temperature and relative humidity held for a number of hours.
+#[derive(Debug)]
+pub struct ClimateSample<T: TemperatureUnit> {
+    /// Dry bulb temperature in °F  or °C or K
+    pub tdry_bulb: Temperature<T>,
+    /// Relative humidity
+    pub rel_hum: RelativeHumidity,
+    /// Duration of the sample in h
+    pub hours: f64,
+}
+
+/// Share of time spent in each band of mechanical damage risk. The bands follow the short term
+/// fluctuation limits of ASHRAE classes AA and A around the mean relative humidity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MechanicalRisk {
+    /// Within ±5% of the mean relative humidity [0-1]
+    pub safe: f64,
+    /// Between ±5% and ±10% of the mean relative humidity [0-1]
+    pub moderate: f64,
+    /// Further than ±10% from the mean relative humidity [0-1]
+    pub high: f64,
+}
+
+/// Time weighted preservation metrics of a climate record.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PreservationSummary {
+    /// Time weighted Preservation Index in years. The years until chemical decay of organic
+    /// materials becomes noticeable if the record repeated itself.
+    pub preservation_index: f64,
+    /// Hours during which mold can germinate.
+    pub mold_risk_hours: f64,
+    /// Share of time in each band of mechanical damage risk.
+    pub mechanical_risk: MechanicalRisk,
+    /// Time weighted mean relative humidity [0-1]
+    pub mean_rel_hum: f64,
+}
+
+/// Return the Preservation Index in years, the time until chemical decay of organic materials such
+/// as paper becomes noticeable when kept at `tdry_bulb` and `rel_hum`.
+/// Reference: Image Permanence Institute, Sebera (1994) Isoperms
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+#[must_use]
+pub fn get_preservation_index<T: TemperatureUnit>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: RelativeHumidity,
+) -> f64 {
+    preservation_index(
+        f64::from(&Temperature::<Celcius>::from(&tdry_bulb)),
+        rel_hum,
+    )
+}
+
+fn preservation_index(tdry_bulb_c: f64, rel_hum: RelativeHumidity) -> f64 {
+    let t_k = tdry_bulb_c + 273.15;
+    (95_220.0 / (8.314 * t_k) - 0.0284 * rel_hum.as_percent() - 28.023).exp() / 365.0
+}
+
+/// Return the time weighted preservation metrics of `samples`. The Preservation Index of the
+/// record is the harmonic time weighted mean of the index of each sample, so short warm and humid
+/// spells count for more than their duration. `Value` when the record is empty or a sample has a
+/// negative duration.
+pub fn get_preservation_summary<T: TemperatureUnit>(
+    samples: &[ClimateSample<T>],
+) -> Result<PreservationSummary, PsychroLibErr> {
+    if samples.iter().any(|sample| sample.hours < 0.0) {
+        return Err(PsychroLibErr::Value);
+    }
+    let total_hours: f64 = samples.iter().map(|sample| sample.hours).sum();
+    if total_hours <= 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    let mean_rel_hum = samples
+        .iter()
+        .map(|sample| sample.hours * sample.rel_hum.as_fraction())
+        .sum::<f64>()
+        / total_hours;
+
+    let mut decay_rate = 0.0;
+    let mut mold_risk_hours = 0.0;
+    let mut mechanical_risk = MechanicalRisk {
+        safe: 0.0,
+        moderate: 0.0,
+        high: 0.0,
+    };
+    for sample in samples {
+        let tdry_bulb_c = f64::from(&Temperature::<Celcius>::from(&sample.tdry_bulb));
+        let rel_hum = sample.rel_hum.as_fraction();
+        decay_rate += sample.hours / preservation_index(tdry_bulb_c, sample.rel_hum);
+        if rel_hum > MOLD_GERMINATION_REL_HUM {
+            mold_risk_hours += sample.hours;
+        }
+        let deviation = (rel_hum - mean_rel_hum).abs();
+        let band = if deviation <= SAFE_REL_HUM_DEVIATION {
+            &mut mechanical_risk.safe
+        } else if deviation <= MODERATE_REL_HUM_DEVIATION {
+            &mut mechanical_risk.moderate
+        } else {
+            &mut mechanical_risk.high
+        };
+        *band += sample.hours / total_hours;
+    }
+    Ok(PreservationSummary {
+        preservation_index: total_hours / decay_rate,
+        mold_risk_hours,
+        mechanical_risk,
+        mean_rel_hum,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Fahrenheit;
+
+    fn sample(tdry_bulb: f64, rel_hum: f64, hours: f64) -> ClimateSample<Celcius> {
+        ClimateSample {
+            tdry_bulb: Temperature::<Celcius>::from(tdry_bulb),
+            rel_hum: RelativeHumidity::from_fraction(rel_hum).unwrap(),
+            hours,
+        }
+    }
+
+    #[test]
+    fn index() {
+        let rel_hum = RelativeHumidity::from_fraction(0.45).unwrap();
+        let pi = get_preservation_index(Temperature::<Celcius>::from(20), rel_hum);
+        assert!((pi - 47.83).abs() < 0.01);
+        let pi = get_preservation_index(Temperature::<Fahrenheit>::from(68), rel_hum);
+        assert!((pi - 47.83).abs() < 0.01);
+    }
+
+    #[test]
+    fn summary() {
+        let samples = [
+            sample(20.0, 0.45, 10.0),
+            sample(20.0, 0.50, 6.0),
+            sample(28.0, 0.70, 4.0),
+        ];
+        let summary = get_preservation_summary(&samples).unwrap();
+        assert!((summary.mean_rel_hum - 0.515).abs() < 1E-9);
+        assert!((summary.mold_risk_hours - 4.0).abs() < 1E-9);
+        assert_eq!(
+            summary.mechanical_risk,
+            MechanicalRisk {
+                safe: 0.3,
+                moderate: 0.5,
+                high: 0.2
+            }
+        );
+        assert!((summary.preservation_index - 23.99).abs() < 0.01);
+        let empty: [ClimateSample<Celcius>; 0] = [];
+        assert!(matches!(
+            get_preservation_summary(&empty),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
@@ -0,0 +1,219 @@
+use super::ClimateSample;
+use crate::psychrolib::PsychroLibErr;
+use crate::quantities::Temperature;
+use crate::units::{Celcius, TemperatureUnit};
+
+/// Longest step in h used to integrate the index within a sample.
+const MAX_STEP_HOURS: f64 = 1.0;
+/// Hours of a dry period during which the index declines at the fast rate.
+const FAST_DECLINE_HOURS: f64 = 6.0;
+/// Hours of a dry period after which the index declines at the slow rate.
+const SLOW_DECLINE_HOURS: f64 = 24.0;
+/// Decline of the index per h at the start of a dry period.
+const FAST_DECLINE_RATE: f64 = 0.001_33;
+/// Decline of the index per h once a dry period lasts longer than a day.
+const SLOW_DECLINE_RATE: f64 = 0.000_667;
+
+/// Sensitivity class of a surface to mold growth in the VTT model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MoldSensitivity {
+    /// Untreated pine sapwood and similar surfaces.
+    #[default]
+    VerySensitive,
+    /// Glued wooden boards, paper coated products and wood based boards.
+    Sensitive,
+    /// Cement and plastic based materials, mineral fibres.
+    MediumResistant,
+    /// Glass and metal products.
+    Resistant,
+}
+
+struct Coefficients {
+    /// Growth intensity while the index is below 1 and from 1 on.
+    k1: (f64, f64),
+    /// Coefficients A, B and C of the maximum index.
+    a: f64,
+    b: f64,
+    c: f64,
+    /// Lowest relative humidity in % at which growth can start.
+    rel_hum_min: f64,
+    /// Relative rate of decline in dry periods.
+    decline: f64,
+}
+
+impl MoldSensitivity {
+    fn coefficients(self) -> Coefficients {
+        match self {
+            MoldSensitivity::VerySensitive => Coefficients {
+                k1: (1.0, 2.0),
+                a: 1.0,
+                b: 7.0,
+                c: 2.0,
+                rel_hum_min: 80.0,
+                decline: 1.0,
+            },
+            MoldSensitivity::Sensitive => Coefficients {
+                k1: (0.578, 0.386),
+                a: 0.3,
+                b: 6.0,
+                c: 1.0,
+                rel_hum_min: 80.0,
+                decline: 0.5,
+            },
+            MoldSensitivity::MediumResistant => Coefficients {
+                k1: (0.072, 0.097),
+                a: 0.0,
+                b: 5.0,
+                c: 1.5,
+                rel_hum_min: 85.0,
+                decline: 0.25,
+            },
+            MoldSensitivity::Resistant => Coefficients {
+                k1: (0.033, 0.014),
+                a: 0.0,
+                b: 3.0,
+                c: 1.0,
+                rel_hum_min: 85.0,
+                decline: 0.1,
+            },
+        }
+    }
+}
+
+/// Return the VTT mold index at the end of each of `samples`, starting from a clean surface. The
+/// index goes from 0, no growth, to 6, heavy growth covering the surface. It grows while the
+/// temperature is above 0 °C and the relative humidity above the critical level, and declines in
+/// dry periods. `Value` when a sample has a negative duration.
+/// Reference: Hukka and Viitanen (1999), Ojanen et al. (2010) Mold growth modeling of building
+/// structures using sensitivity classes of materials
+pub fn get_mold_index_trajectory<T: TemperatureUnit>(
+    samples: &[ClimateSample<T>],
+    sensitivity: MoldSensitivity,
+) -> Result<Vec<f64>, PsychroLibErr> {
+    if samples.iter().any(|sample| sample.hours < 0.0) {
+        return Err(PsychroLibErr::Value);
+    }
+    let coefficients = sensitivity.coefficients();
+    let mut index = 0.0;
+    let mut dry_hours = 0.0;
+    let mut trajectory = Vec::with_capacity(samples.len());
+    for sample in samples {
+        let tdry_bulb_c = f64::from(&Temperature::<Celcius>::from(&sample.tdry_bulb));
+        let rel_hum = sample.rel_hum.as_percent();
+        let rel_hum_crit = critical_rel_hum(tdry_bulb_c).max(coefficients.rel_hum_min);
+        let mut remaining = sample.hours;
+        while remaining > 0.0 {
+            let step = remaining.min(MAX_STEP_HOURS);
+            if tdry_bulb_c > 0.0 && rel_hum >= rel_hum_crit {
+                dry_hours = 0.0;
+                index +=
+                    step * growth_rate(tdry_bulb_c, rel_hum, rel_hum_crit, index, &coefficients);
+            } else {
+                index -= step * decline_rate(dry_hours, step) * coefficients.decline;
+                dry_hours += step;
+            }
+            index = index.max(0.0);
+            remaining -= step;
+        }
+        trajectory.push(index);
+    }
+    Ok(trajectory)
+}
+
+/// Relative humidity in % below which mold cannot grow on pine sapwood at `tdry_bulb_c`.
+fn critical_rel_hum(tdry_bulb_c: f64) -> f64 {
+    if tdry_bulb_c <= 20.0 {
+        ((-0.002_67 * tdry_bulb_c + 0.160) * tdry_bulb_c - 3.13) * tdry_bulb_c + 100.0
+    } else {
+        80.0
+    }
+}
+
+/// Growth of the index per h.
+fn growth_rate(
+    tdry_bulb_c: f64,
+    rel_hum: f64,
+    rel_hum_crit: f64,
+    index: f64,
+    coefficients: &Coefficients,
+) -> f64 {
+    let weeks_to_germinate = (-0.68 * tdry_bulb_c.ln() - 13.9 * rel_hum.ln() + 66.02).exp();
+    let k1 = if index < 1.0 {
+        coefficients.k1.0
+    } else {
+        coefficients.k1.1
+    };
+    let excess = (rel_hum_crit - rel_hum) / (rel_hum_crit - 100.0);
+    let index_max = coefficients.a + coefficients.b * excess - coefficients.c * excess * excess;
+    let k2 = (1.0 - (2.3 * (index - index_max)).exp()).max(0.0);
+    k1 * k2 / (7.0 * 24.0 * weeks_to_germinate)
+}
+
+/// Mean decline of the index per h over a `step` starting `dry_hours` into a dry period.
+fn decline_rate(dry_hours: f64, step: f64) -> f64 {
+    let end = dry_hours + step;
+    let fast = (end.min(FAST_DECLINE_HOURS) - dry_hours).max(0.0);
+    let slow = (end - dry_hours.max(SLOW_DECLINE_HOURS)).max(0.0);
+    (fast * FAST_DECLINE_RATE + slow * SLOW_DECLINE_RATE) / step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::RelativeHumidity;
+
+    fn sample(tdry_bulb: f64, rel_hum: f64, hours: f64) -> ClimateSample<Celcius> {
+        ClimateSample {
+            tdry_bulb: Temperature::<Celcius>::from(tdry_bulb),
+            rel_hum: RelativeHumidity::from_fraction(rel_hum).unwrap(),
+            hours,
+        }
+    }
+
+    #[test]
+    fn germination() {
+        // Pine sapwood germinates after about 1.48 weeks at 20 °C and 97%.
+        let samples = [sample(20.0, 0.97, 124.0), sample(20.0, 0.97, 124.0)];
+        let trajectory =
+            get_mold_index_trajectory(&samples, MoldSensitivity::VerySensitive).unwrap();
+        assert!((trajectory[0] - 0.4975).abs() < 0.001);
+        assert!((trajectory[1] - 0.995).abs() < 0.001);
+        let trajectory = get_mold_index_trajectory(&samples, MoldSensitivity::Resistant).unwrap();
+        assert!(trajectory[1] < 0.04);
+        let dry = [sample(20.0, 0.75, 1000.0), sample(-5.0, 1.0, 1000.0)];
+        let trajectory = get_mold_index_trajectory(&dry, MoldSensitivity::VerySensitive).unwrap();
+        assert_eq!(trajectory, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn saturation() {
+        let samples = [sample(25.0, 1.0, 24.0 * 365.0)];
+        let trajectory =
+            get_mold_index_trajectory(&samples, MoldSensitivity::VerySensitive).unwrap();
+        assert!(trajectory[0] <= 6.0 && trajectory[0] > 5.9);
+        let trajectory = get_mold_index_trajectory(&samples, MoldSensitivity::Resistant).unwrap();
+        assert!(trajectory[0] < 2.0 && trajectory[0] > 1.0);
+    }
+
+    #[test]
+    fn decline() {
+        let wet =
+            get_mold_index_trajectory(&[sample(20.0, 0.97, 248.0)], MoldSensitivity::VerySensitive)
+                .unwrap()[0];
+        let samples = [
+            sample(20.0, 0.97, 248.0),
+            sample(20.0, 0.50, 10.0),
+            sample(20.0, 0.50, 20.0),
+        ];
+        let trajectory =
+            get_mold_index_trajectory(&samples, MoldSensitivity::VerySensitive).unwrap();
+        assert!((wet - trajectory[1] - 6.0 * FAST_DECLINE_RATE).abs() < 1E-9);
+        assert!((wet - trajectory[2] - 6.0 * (FAST_DECLINE_RATE + SLOW_DECLINE_RATE)).abs() < 1E-9);
+        let trajectory = get_mold_index_trajectory(&samples, MoldSensitivity::Sensitive).unwrap();
+        assert!((trajectory[1] - trajectory[2] - 3.0 * SLOW_DECLINE_RATE).abs() < 1E-9);
+        assert!(matches!(
+            get_mold_index_trajectory(&[sample(20.0, 0.5, -1.0)], MoldSensitivity::Sensitive),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::Temperature;
+use crate::state::MoistAirState;
+use crate::units::Celcius;
+
+/// Relative humidity above which a metal surface counts as wet.
+const WET_REL_HUM: f64 = 0.8;
+/// Temperature in °C above which a metal surface counts as wet.
+const WET_TDRY_BULB_C: f64 = 0.0;
+/// Hours in a year, to scale records of any length to an annual time of wetness.
+const HOURS_PER_YEAR: f64 = 8760.0;
+
+/// Temperature of the surface watched for condensation.
+#[derive(Debug)]
+pub enum SurfaceTemperature {
+    /// Measured surface temperature.
+    Measured(Temperature<Celcius>),
+    /// Inner surface of an envelope element, from the outdoor temperature and the temperature
+    /// factor of the element. The surface sits at `toutdoor + factor * (tindoor - toutdoor)` where
+    /// the indoor temperature is the dry bulb temperature of the state.
+    Modeled {
+        /// Outdoor temperature
+        toutdoor: Temperature<Celcius>,
+        /// Temperature factor at the inner surface [0-1], about 0.7 for a thermal bridge
+        factor: f64,
+    },
+}
+
+impl SurfaceTemperature {
+    fn tsurface_c(&self, tindoor_c: f64) -> f64 {
+        match self {
+            SurfaceTemperature::Measured(tsurface) => f64::from(tsurface),
+            SurfaceTemperature::Modeled { toutdoor, factor } => {
+                let toutdoor_c = f64::from(toutdoor);
+                toutdoor_c + factor * (tindoor_c - toutdoor_c)
+            }
+        }
+    }
+}
+
+/// Time of wetness category of ISO 9223, from the annual hours of wetness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeOfWetnessCategory {
+    /// Up to 10 h per year, indoor climate controlled.
+    #[default]
+    T1,
+    /// Up to 250 h per year, indoor not climate controlled.
+    T2,
+    /// Up to 2500 h per year, sheltered outdoor in dry climates.
+    T3,
+    /// Up to 5500 h per year, outdoor in most climates.
+    T4,
+    /// More than 5500 h per year, very humid climates.
+    T5,
+}
+
+/// Wetness metrics of a record of states.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WetnessSummary {
+    /// Hours the surface was at or below the dew point of the air.
+    pub condensation_hours: f64,
+    /// Longest uninterrupted condensation spell in h.
+    pub longest_condensation_hours: f64,
+    /// Hours with relative humidity above 80% and temperature above 0 °C.
+    pub time_of_wetness_hours: f64,
+    /// Hours of the record.
+    pub total_hours: f64,
+}
+
+impl WetnessSummary {
+    /// Return the time of wetness category, scaling the record to a year.
+    /// Reference: ISO 9223 (1992) Corrosion of metals and alloys - Corrosivity of atmospheres
+    #[must_use]
+    pub fn time_of_wetness_category(&self) -> TimeOfWetnessCategory {
+        if self.total_hours <= 0.0 {
+            return TimeOfWetnessCategory::T1;
+        }
+        let annual = self.time_of_wetness_hours * HOURS_PER_YEAR / self.total_hours;
+        if annual <= 10.0 {
+            TimeOfWetnessCategory::T1
+        } else if annual <= 250.0 {
+            TimeOfWetnessCategory::T2
+        } else if annual <= 2500.0 {
+            TimeOfWetnessCategory::T3
+        } else if annual <= 5500.0 {
+            TimeOfWetnessCategory::T4
+        } else {
+            TimeOfWetnessCategory::T5
+        }
+    }
+}
+
+/// Running count of condensation hours and time of wetness, fed one state at a time so records can
+/// be processed as they arrive.
+#[derive(Debug, Default)]
+pub struct WetnessAccumulator {
+    condensation_hours: f64,
+    condensation_spell_hours: f64,
+    longest_condensation_hours: f64,
+    time_of_wetness_hours: f64,
+    total_hours: f64,
+}
+
+impl WetnessAccumulator {
+    /// Accumulator with no states added.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `state` held for `hours` next to `surface`. `Value` for a negative duration.
+    pub fn add(
+        &mut self,
+        state: &MoistAirState,
+        surface: &SurfaceTemperature,
+        hours: f64,
+    ) -> Result<(), PsychroLibErr> {
+        if hours < 0.0 {
+            return Err(PsychroLibErr::Value);
+        }
+        let tdry_bulb_c = state.tdry_bulb_c();
+        let tdew_point_c =
+            si::tdew_point_from_hum_ratio(tdry_bulb_c, state.hum_ratio(), state.pres_ambient_pa())?;
+        if surface.tsurface_c(tdry_bulb_c) <= tdew_point_c {
+            self.condensation_hours += hours;
+            self.condensation_spell_hours += hours;
+            self.longest_condensation_hours = self
+                .longest_condensation_hours
+                .max(self.condensation_spell_hours);
+        } else {
+            self.condensation_spell_hours = 0.0;
+        }
+        if state.rel_hum()? > WET_REL_HUM && tdry_bulb_c > WET_TDRY_BULB_C {
+            self.time_of_wetness_hours += hours;
+        }
+        self.total_hours += hours;
+        Ok(())
+    }
+
+    /// Return the metrics of the states added so far.
+    #[must_use]
+    pub fn summary(&self) -> WetnessSummary {
+        WetnessSummary {
+            condensation_hours: self.condensation_hours,
+            longest_condensation_hours: self.longest_condensation_hours,
+            time_of_wetness_hours: self.time_of_wetness_hours,
+            total_hours: self.total_hours,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulate() {
+        // Dew point of 20 °C and 60% is 12.0 °C.
+        let mut accumulator = WetnessAccumulator::new();
+        let cold = SurfaceTemperature::Measured(Temperature::<Celcius>::from(10));
+        let warm = SurfaceTemperature::Measured(Temperature::<Celcius>::from(15));
+        let bridge = SurfaceTemperature::Modeled {
+            toutdoor: Temperature::<Celcius>::from(-5),
+            factor: 0.6,
+        };
+        accumulator
+            .add(&MoistAirState::test_rel_hum(20.0, 0.6), &cold, 2.0)
+            .unwrap();
+        accumulator
+            .add(&MoistAirState::test_rel_hum(20.0, 0.6), &bridge, 1.0)
+            .unwrap();
+        accumulator
+            .add(&MoistAirState::test_rel_hum(20.0, 0.6), &warm, 3.0)
+            .unwrap();
+        accumulator
+            .add(&MoistAirState::test_rel_hum(20.0, 0.85), &cold, 1.5)
+            .unwrap();
+        accumulator
+            .add(&MoistAirState::test_rel_hum(-2.0, 0.9), &warm, 4.0)
+            .unwrap();
+        let summary = accumulator.summary();
+        assert!((summary.condensation_hours - 4.5).abs() < 1E-9);
+        assert!((summary.longest_condensation_hours - 3.0).abs() < 1E-9);
+        assert!((summary.time_of_wetness_hours - 1.5).abs() < 1E-9);
+        assert!((summary.total_hours - 11.5).abs() < 1E-9);
+        assert!(matches!(
+            accumulator.add(&MoistAirState::test_rel_hum(20.0, 0.6), &warm, -1.0),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn category() {
+        let summary = |time_of_wetness_hours| WetnessSummary {
+            condensation_hours: 0.0,
+            longest_condensation_hours: 0.0,
+            time_of_wetness_hours,
+            total_hours: 876.0,
+        };
+        assert_eq!(
+            summary(1.0).time_of_wetness_category(),
+            TimeOfWetnessCategory::T1
+        );
+        assert_eq!(
+            summary(100.0).time_of_wetness_category(),
+            TimeOfWetnessCategory::T3
+        );
+        assert_eq!(
+            summary(600.0).time_of_wetness_category(),
+            TimeOfWetnessCategory::T5
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::VolumetricFlowRate;
+use crate::state::MoistAirState;
+use crate::units::{CubicMetersPerHour, VolumetricFlowRateUnit};
+
+/// Reference conditions that standard volumetric flows are quoted at. Both are dry air at
+/// 101 325 Pa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StandardConditions {
+    /// ASHRAE standard air at 20 °C, 1.204 kg m⁻³. Used for SCFM.
+    #[default]
+    StandardAir,
+    /// DIN 1343 normal conditions at 0 °C, 1.292 kg m⁻³. Used for Nm³ h⁻¹.
+    Normal,
+}
+
+impl StandardConditions {
+    fn density(self) -> f64 {
+        let tdry_bulb = match self {
+            StandardConditions::StandardAir => 20.0,
+            StandardConditions::Normal => 0.0,
+        };
+        101_325.0 / (si::R_DA * si::t_kelvin(tdry_bulb))
+    }
+}
+
+/// Return the ratio of the density of `state` to the density at `standard` conditions.
+/// Fan pressures and powers scale with this factor.
+pub fn get_density_correction_factor(
+    state: &MoistAirState,
+    standard: StandardConditions,
+) -> Result<f64, PsychroLibErr> {
+    let density = si::moist_air_density(
+        state.tdry_bulb_c(),
+        state.hum_ratio(),
+        state.pres_ambient_pa(),
+    )?;
+    Ok(density / standard.density())
+}
+
+/// Return the flow at `standard` conditions that carries the same mass as `actual_flow` of air
+/// at `state`, e.g. SCFM from ACFM.
+pub fn get_standard_flow<V: VolumetricFlowRateUnit, VS: VolumetricFlowRateUnit>(
+    actual_flow: VolumetricFlowRate<V>,
+    state: &MoistAirState,
+    standard: StandardConditions,
+) -> Result<VolumetricFlowRate<VS>, PsychroLibErr> {
+    let factor = get_density_correction_factor(state, standard)?;
+    let actual_flow = f64::from(&VolumetricFlowRate::<CubicMetersPerHour>::from(
+        &actual_flow,
+    ));
+    Ok(VolumetricFlowRate::<VS>::from(&VolumetricFlowRate::<
+        CubicMetersPerHour,
+    >::from(
+        actual_flow * factor
+    )))
+}
+
+/// Return the flow of air at `state` that carries the same mass as `standard_flow` at
+/// `standard` conditions, e.g. ACFM from SCFM.
+pub fn get_actual_flow<VS: VolumetricFlowRateUnit, V: VolumetricFlowRateUnit>(
+    standard_flow: VolumetricFlowRate<VS>,
+    state: &MoistAirState,
+    standard: StandardConditions,
+) -> Result<VolumetricFlowRate<V>, PsychroLibErr> {
+    let factor = get_density_correction_factor(state, standard)?;
+    let standard_flow = f64::from(&VolumetricFlowRate::<CubicMetersPerHour>::from(
+        &standard_flow,
+    ));
+    Ok(VolumetricFlowRate::<V>::from(&VolumetricFlowRate::<
+        CubicMetersPerHour,
+    >::from(
+        standard_flow / factor
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::{Pressure, Temperature};
+    use crate::units::{Celcius, CubicFeetPerMinute, Pascal};
+
+    /// Air at 84 kPa, about 1600 m above sea level.
+    fn state_at_altitude() -> MoistAirState {
+        MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(35),
+            0.4,
+            Pressure::<Pascal>::from(84_000),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn density_correction_factor() {
+        let factor =
+            get_density_correction_factor(&state_at_altitude(), StandardConditions::StandardAir)
+                .unwrap();
+        assert!((factor - 0.780_67).abs() < 1E-5);
+        let factor =
+            get_density_correction_factor(&state_at_altitude(), StandardConditions::Normal)
+                .unwrap();
+        assert!((factor - 0.727_41).abs() < 1E-5);
+    }
+
+    #[test]
+    fn actual_and_standard_flow() {
+        let standard_flow: VolumetricFlowRate<CubicFeetPerMinute> = get_standard_flow(
+            VolumetricFlowRate::<CubicFeetPerMinute>::from(10_000),
+            &state_at_altitude(),
+            StandardConditions::StandardAir,
+        )
+        .unwrap();
+        assert!((f64::from(&standard_flow) - 7806.71).abs() < 0.01);
+        let actual_flow: VolumetricFlowRate<CubicFeetPerMinute> = get_actual_flow(
+            standard_flow,
+            &state_at_altitude(),
+            StandardConditions::StandardAir,
+        )
+        .unwrap();
+        assert!((f64::from(actual_flow) - 10_000.0).abs() < 0.01);
+    }
+}
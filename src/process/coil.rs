@@ -0,0 +1,250 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, SpecificEnthalpy, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, JoulesPerKg, Pascal, SpecificEnthalpyUnit, TemperatureUnit};
+
+/// Step used to bracket the apparatus dew point below the leaving temperature, in K.
+const BRACKET_STEP_K: f64 = 0.5;
+
+/// Apparatus dew point and bypass factor of a cooling coil.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ApparatusDewPoint {
+    /// Temperature where the extended process line meets the saturation curve.
+    pub tadp: Temperature<Celcius>,
+    /// Share of the air that leaves the coil as if it had bypassed it [0-1]. The contact
+    /// factor is its complement.
+    pub bypass_factor: f64,
+}
+
+/// Return the apparatus dew point and bypass factor of a cooling coil from its measured entering
+/// and leaving states. The straight process line through both states is extended until it meets
+/// the saturation curve. `Range` when the air is not cooled or is saturated on leaving.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 and HVAC Systems and Equipment (2020)
+/// ch. 23
+pub fn get_apparatus_dew_point(
+    entering: &MoistAirState,
+    leaving: &MoistAirState,
+) -> Result<ApparatusDewPoint, PsychroLibErr> {
+    let (t1, w1) = (entering.tdry_bulb_c(), entering.hum_ratio());
+    let (t2, w2) = (leaving.tdry_bulb_c(), leaving.hum_ratio());
+    let pres = leaving.pres_ambient_pa();
+    if t2 >= t1 {
+        return Err(PsychroLibErr::Range);
+    }
+    let tadp = saturation_intersection(t2, w2, (w2 - w1) / (t2 - t1), pres)?;
+    Ok(ApparatusDewPoint {
+        tadp: Temperature::<Celcius>::from(tadp),
+        bypass_factor: (t2 - tadp) / (t1 - tadp),
+    })
+}
+
+/// Temperature where the line through (`t0`, `w0`) with `slope` in kg_H₂O kg_Air⁻¹ K⁻¹ meets the
+/// saturation curve below `t0`. `Range` when (`t0`, `w0`) is already saturated.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+fn saturation_intersection(t0: f64, w0: f64, slope: f64, pres: f64) -> Result<f64, PsychroLibErr> {
+    let excess = |t: f64| -> Result<f64, PsychroLibErr> {
+        Ok(w0 + slope * (t - t0) - si::sat_hum_ratio(t, pres)?)
+    };
+    if excess(t0)? >= 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+
+    let lower_bound = si::TEMPERATURE_BOUNDS.0;
+    let mut tsat_sup = t0;
+    let mut tsat_inf = t0 - BRACKET_STEP_K;
+    while excess(tsat_inf)? < 0.0 {
+        tsat_sup = tsat_inf;
+        tsat_inf -= BRACKET_STEP_K;
+        if tsat_inf < lower_bound {
+            return Err(PsychroLibErr::Convergence);
+        }
+    }
+    si::trace_iteration!(tsat_inf, tsat_sup, "bracketed");
+    let mut index = 1;
+    while tsat_sup - tsat_inf > si::TOLERANCE {
+        let tsat = (tsat_sup + tsat_inf) / 2.0;
+        let residual = excess(tsat)?;
+        si::trace_iteration!(iteration = index, tsat, residual);
+        if residual < 0.0 {
+            tsat_sup = tsat;
+        } else {
+            tsat_inf = tsat;
+        }
+        if index >= si::MAX_ITER_COUNT {
+            return Err(PsychroLibErr::Convergence);
+        }
+        index += 1;
+    }
+    Ok((tsat_sup + tsat_inf) / 2.0)
+}
+
+/// Room process line of a given sensible heat ratio.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShrLine {
+    /// Change of humidity ratio per change of dry bulb temperature along the line in
+    /// kg_H₂O kg_Air⁻¹ K⁻¹.
+    pub slope: f64,
+    /// Temperature where the line meets the saturation curve. The lowest supply temperature, and
+    /// the apparatus dew point a coil needs, to meet the sensible heat ratio.
+    pub tadp: Temperature<Celcius>,
+    /// Humidity ratio of saturated air at `tadp` in kg_H₂O kg_Air⁻¹.
+    pub hum_ratio_adp: f64,
+}
+
+/// Return the line of every supply state that removes sensible and latent heat from `room` in the
+/// ratio `shr`, and where it meets the saturation curve. A coil with an apparatus dew point above
+/// `tadp` can't meet the ratio. `Range` when `shr` is outside (0-1] or the room is saturated.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 43 and 44
+/// `shr` Sensible heat ratio, sensible over total heat (0-1]
+pub fn get_shr_line(room: &MoistAirState, shr: f64) -> Result<ShrLine, PsychroLibErr> {
+    if shr <= 0.0 || shr > 1.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let (troom, wroom) = (room.tdry_bulb_c(), room.hum_ratio());
+    let pres = room.pres_ambient_pa();
+    let humid_heat = si::CP_DA + si::CP_VAPOR * wroom;
+    let heat_of_vaporization = 2_501_000.0 + si::CP_VAPOR * troom;
+    let slope = humid_heat * (1.0 / shr - 1.0) / heat_of_vaporization;
+    let tadp = saturation_intersection(troom, wroom, slope, pres)?;
+    Ok(ShrLine {
+        slope,
+        tadp: Temperature::<Celcius>::from(tadp),
+        hum_ratio_adp: si::sat_hum_ratio(tadp, pres)?,
+    })
+}
+
+/// Air leaving a cooling coil and the heat it removes per mass of dry air.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct CoilLeaving<S: SpecificEnthalpyUnit> {
+    /// State of the air leaving the coil.
+    pub leaving: MoistAirState,
+    /// Heat removed by cooling the air, at the humid heat of the entering air.
+    pub sensible: SpecificEnthalpy<S>,
+    /// Heat removed by condensing water out of the air.
+    pub latent: SpecificEnthalpy<S>,
+    /// Enthalpy drop of the air across the coil.
+    pub total: SpecificEnthalpy<S>,
+}
+
+/// Return the leaving state and loads of a cooling coil rated by its apparatus dew point and
+/// bypass factor. The leaving air is a mix of `bypass_factor` of the entering air and the rest
+/// saturated at `tadp`. `Range` when the bypass factor is outside [0-1] or `tadp` is not below the
+/// entering dry bulb temperature.
+/// Reference: ASHRAE Handbook - HVAC Systems and Equipment (2020) ch. 23
+/// `tadp` Apparatus dew point in °F  or °C or K
+/// `bypass_factor` Bypass factor [0-1]
+pub fn get_coil_leaving_from_adp<T: TemperatureUnit, S: SpecificEnthalpyUnit>(
+    entering: &MoistAirState,
+    tadp: Temperature<T>,
+    bypass_factor: f64,
+) -> Result<CoilLeaving<S>, PsychroLibErr> {
+    let (t1, w1) = (entering.tdry_bulb_c(), entering.hum_ratio());
+    let pres = entering.pres_ambient_pa();
+    let tadp = f64::from(&Temperature::<Celcius>::from(&tadp));
+    if !(0.0..=1.0).contains(&bypass_factor) || tadp >= t1 {
+        return Err(PsychroLibErr::Range);
+    }
+    let wadp = si::sat_hum_ratio(tadp, pres)?;
+    let t2 = tadp + bypass_factor * (t1 - tadp);
+    let w2 = (wadp + bypass_factor * (w1 - wadp)).min(w1);
+    let total = si::moist_air_enthalpy(t1, w1) - si::moist_air_enthalpy(t2, w2);
+    let sensible = (si::CP_DA + si::CP_VAPOR * w1) * (t1 - t2);
+    let enthalpy =
+        |jpkg: f64| SpecificEnthalpy::<S>::from(&SpecificEnthalpy::<JoulesPerKg>::from(jpkg));
+    Ok(CoilLeaving {
+        leaving: MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(t2),
+            w2,
+            Pressure::<Pascal>::from(pres),
+        )?,
+        sensible: enthalpy(sensible),
+        latent: enthalpy(total - sensible),
+        total: enthalpy(total),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Fahrenheit, KilojoulesPerKg};
+
+    #[test]
+    fn apparatus_dew_point() {
+        let adp = get_apparatus_dew_point(
+            &MoistAirState::test_hum_ratio(27.0, 0.011),
+            &MoistAirState::test_hum_ratio(12.55, 0.008_135_55),
+        )
+        .unwrap();
+        assert!((f64::from(&adp.tadp) - 10.0).abs() < 2E-3);
+        assert!((adp.bypass_factor - 0.15).abs() < 1E-4);
+        let sensible = get_apparatus_dew_point(
+            &MoistAirState::test_hum_ratio(27.0, 0.008),
+            &MoistAirState::test_hum_ratio(20.0, 0.008),
+        )
+        .unwrap();
+        let tdew_point: Temperature<Celcius> = MoistAirState::test_hum_ratio(27.0, 0.008)
+            .tdew_point()
+            .unwrap();
+        assert!((f64::from(&sensible.tadp) - f64::from(&tdew_point)).abs() < 2E-3);
+        let heating = get_apparatus_dew_point(
+            &MoistAirState::test_hum_ratio(20.0, 0.008),
+            &MoistAirState::test_hum_ratio(27.0, 0.008),
+        );
+        assert!(matches!(heating, Err(PsychroLibErr::Range)));
+    }
+
+    #[test]
+    fn shr_line() {
+        let line = get_shr_line(&MoistAirState::test_hum_ratio(24.0, 0.009_3), 0.75).unwrap();
+        assert!((line.slope - 1.339_94E-4).abs() < 1E-9);
+        assert!((f64::from(&line.tadp) - 9.450_7).abs() < 2E-3);
+        let w_line = 0.009_3 + line.slope * (f64::from(&line.tadp) - 24.0);
+        assert!((line.hum_ratio_adp - w_line).abs() < 1E-6);
+        let sensible = get_shr_line(&MoistAirState::test_hum_ratio(24.0, 0.009_3), 1.0).unwrap();
+        let tdew_point: Temperature<Celcius> = MoistAirState::test_hum_ratio(24.0, 0.009_3)
+            .tdew_point()
+            .unwrap();
+        assert!((f64::from(&sensible.tadp) - f64::from(&tdew_point)).abs() < 2E-3);
+        assert!(matches!(
+            get_shr_line(&MoistAirState::test_hum_ratio(24.0, 0.009_3), 0.0),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn leaving_from_adp() {
+        let coil: CoilLeaving<KilojoulesPerKg> = get_coil_leaving_from_adp(
+            &MoistAirState::test_hum_ratio(27.0, 0.011),
+            Temperature::<Fahrenheit>::from(50),
+            0.15,
+        )
+        .unwrap();
+        assert!((coil.leaving.tdry_bulb_c() - 12.55).abs() < 1E-3);
+        assert!((coil.leaving.hum_ratio() - 0.008_135_55).abs() < 1E-6);
+        assert_eq!(
+            coil.total,
+            SpecificEnthalpy::<KilojoulesPerKg>::from(22.063_21)
+        );
+        assert_eq!(
+            coil.sensible,
+            SpecificEnthalpy::<KilojoulesPerKg>::from(14.832_35)
+        );
+        let adp =
+            get_apparatus_dew_point(&MoistAirState::test_hum_ratio(27.0, 0.011), &coil.leaving)
+                .unwrap();
+        assert!((adp.bypass_factor - 0.15).abs() < 1E-4);
+        let coil = get_coil_leaving_from_adp::<_, KilojoulesPerKg>(
+            &MoistAirState::test_hum_ratio(27.0, 0.011),
+            Temperature::<Celcius>::from(10),
+            1.2,
+        );
+        assert!(matches!(coil, Err(PsychroLibErr::Range)));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Length, Pressure, SpecificEnthalpy, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, JoulesPerKg, LengthUnit, Meter, Pascal, TemperatureUnit};
+
+/// Thickest insulation considered when solving for the minimum thickness, in m.
+const MAX_INSULATION_THICKNESS_M: f64 = 1.0;
+/// Resolution of the minimum insulation thickness, in m.
+const INSULATION_THICKNESS_TOLERANCE_M: f64 = 1E-5;
+
+/// Return true when a surface at `tsurface` is at or below the dew point of `ambient`.
+/// `tsurface` Surface temperature in °F  or °C or K
+pub fn is_condensing<T: TemperatureUnit>(
+    ambient: &MoistAirState,
+    tsurface: Temperature<T>,
+) -> Result<bool, PsychroLibErr> {
+    let tdew_point: Temperature<Celcius> = ambient.tdew_point()?;
+    Ok(tsurface.micro_kelvin <= tdew_point.micro_kelvin)
+}
+
+/// Air cooled at constant pressure, with the water that condensed out of it.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CooledAir {
+    /// State after cooling, saturated when water condensed.
+    pub state: MoistAirState,
+    /// Liquid water condensed in kg_H₂O kg_Air⁻¹, zero above the dew point.
+    pub condensed: f64,
+    /// Heat removed from the air and its condensate, which leaves at the final temperature.
+    pub heat_removed: SpecificEnthalpy<JoulesPerKg>,
+}
+
+/// Return the state of `initial` cooled to `tfinal` at constant pressure, with the water
+/// condensed per kg of dry air when `tfinal` is below the dew point. The condensate is assumed
+/// to leave at `tfinal`, as liquid water above 0 °C and as frost below.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 44 & 45
+/// `tfinal` Final dry bulb temperature in °F  or °C or K
+/// Errors with `Value` when `tfinal` is above the initial temperature.
+pub fn get_cooled_air<T: TemperatureUnit>(
+    initial: &MoistAirState,
+    tfinal: Temperature<T>,
+) -> Result<CooledAir, PsychroLibErr> {
+    let tfinal_c = f64::from(&Temperature::<Celcius>::from(&tfinal));
+    let (tinitial_c, pres) = (initial.tdry_bulb_c(), initial.pres_ambient_pa());
+    si::check_tdry_bulb_range(tfinal_c)?;
+    if tfinal_c > tinitial_c {
+        return Err(PsychroLibErr::Value);
+    }
+    let hum_ratio = initial.hum_ratio().min(si::sat_hum_ratio(tfinal_c, pres)?);
+    let condensed = initial.hum_ratio() - hum_ratio;
+    let heat_removed = si::moist_air_enthalpy(tinitial_c, initial.hum_ratio())
+        - si::moist_air_enthalpy(tfinal_c, hum_ratio)
+        - condensed * si::condensed_water_enthalpy(tfinal_c);
+    Ok(CooledAir {
+        state: MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(tfinal_c),
+            hum_ratio,
+            Pressure::<Pascal>::from(pres),
+        )?,
+        condensed,
+        heat_removed: SpecificEnthalpy::from(heat_removed),
+    })
+}
+
+/// Insulation around a cold duct or pipe.
+#[derive(Debug)]
+pub struct Insulation {
+    /// Thermal conductivity of the insulation in W m⁻¹ K⁻¹.
+    pub conductivity: f64,
+    /// Combined convective and radiative coefficient of the outer surface in W m⁻² K⁻¹.
+    /// About 9 for still indoor air.
+    pub surface_coefficient: f64,
+    /// Outer diameter of the bare pipe. `None` for flat duct walls.
+    pub pipe_diameter: Option<Length<Meter>>,
+}
+
+impl Insulation {
+    /// Resistance of the insulation per area of its outer surface in m² K W⁻¹.
+    fn resistance(&self, thickness_m: f64) -> f64 {
+        match &self.pipe_diameter {
+            None => thickness_m / self.conductivity,
+            Some(diameter) => {
+                let r_pipe = f64::from(diameter) / 2.0;
+                let r_outer = r_pipe + thickness_m;
+                r_outer * (r_outer / r_pipe).ln() / self.conductivity
+            }
+        }
+    }
+
+    fn tsurface_c(&self, tambient_c: f64, tfluid_c: f64, thickness_m: f64) -> f64 {
+        let r_surface = 1.0 / self.surface_coefficient;
+        tambient_c
+            - (tambient_c - tfluid_c) * r_surface / (self.resistance(thickness_m) + r_surface)
+    }
+
+    fn check(&self) -> Result<(), PsychroLibErr> {
+        let pipe_ok = self
+            .pipe_diameter
+            .as_ref()
+            .is_none_or(|diameter| f64::from(diameter) > 0.0);
+        if self.conductivity <= 0.0 || self.surface_coefficient <= 0.0 || !pipe_ok {
+            return Err(PsychroLibErr::Value);
+        }
+        Ok(())
+    }
+}
+
+/// Outer surface of an insulated duct or pipe.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SurfaceCondensation {
+    /// Temperature of the outer surface of the insulation.
+    pub tsurface: Temperature<Celcius>,
+    /// Dew point of the ambient air.
+    pub tdew_point: Temperature<Celcius>,
+    /// True when the surface is at or below the dew point.
+    pub condensing: bool,
+}
+
+/// Return the outer surface temperature of insulation around a cold fluid and whether moisture
+/// from the ambient air condenses on it. Assumes steady one dimensional conduction.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 23
+/// `tfluid` Temperature of the fluid in the duct or pipe in °F  or °C or K
+/// `thickness` Insulation thickness in in  or mm  or m
+pub fn get_surface_condensation<TF: TemperatureUnit, L: LengthUnit>(
+    ambient: &MoistAirState,
+    tfluid: Temperature<TF>,
+    insulation: &Insulation,
+    thickness: Length<L>,
+) -> Result<SurfaceCondensation, PsychroLibErr> {
+    insulation.check()?;
+    let thickness_m = f64::from(&Length::<Meter>::from(&thickness));
+    if thickness_m < 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    let tfluid_c = f64::from(&Temperature::<Celcius>::from(&tfluid));
+    let tsurface = Temperature::<Celcius>::from(insulation.tsurface_c(
+        ambient.tdry_bulb_c(),
+        tfluid_c,
+        thickness_m,
+    ));
+    let tdew_point: Temperature<Celcius> = ambient.tdew_point()?;
+    Ok(SurfaceCondensation {
+        condensing: tsurface.micro_kelvin <= tdew_point.micro_kelvin,
+        tsurface,
+        tdew_point,
+    })
+}
+
+/// Return the thinnest insulation that keeps its outer surface above the dew point of the
+/// ambient air. Zero when the bare surface doesn't condense, `Convergence` when more than 1 m
+/// would be needed.
+/// Returns: Insulation thickness in in  or mm  or m
+/// `tfluid` Temperature of the fluid in the duct or pipe in °F  or °C or K
+pub fn get_min_insulation_thickness<TF: TemperatureUnit, L: LengthUnit>(
+    ambient: &MoistAirState,
+    tfluid: Temperature<TF>,
+    insulation: &Insulation,
+) -> Result<Length<L>, PsychroLibErr> {
+    insulation.check()?;
+    let tambient_c = ambient.tdry_bulb_c();
+    let tfluid_c = f64::from(&Temperature::<Celcius>::from(&tfluid));
+    let tdew_point_c =
+        si::tdew_point_from_hum_ratio(tambient_c, ambient.hum_ratio(), ambient.pres_ambient_pa())?;
+    let condensing =
+        |thickness_m| insulation.tsurface_c(tambient_c, tfluid_c, thickness_m) <= tdew_point_c;
+    if !condensing(0.0) {
+        return Ok(Length::<L>::from(0.0));
+    }
+    if condensing(MAX_INSULATION_THICKNESS_M) {
+        return Err(PsychroLibErr::Convergence);
+    }
+    let (mut thickness_inf, mut thickness_sup) = (0.0, MAX_INSULATION_THICKNESS_M);
+    while thickness_sup - thickness_inf > INSULATION_THICKNESS_TOLERANCE_M {
+        let thickness_m = (thickness_inf + thickness_sup) / 2.0;
+        if condensing(thickness_m) {
+            thickness_inf = thickness_m;
+        } else {
+            thickness_sup = thickness_m;
+        }
+    }
+    Ok(Length::<L>::from(&Length::<Meter>::from(thickness_sup)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Pressure;
+    use crate::units::{Millimeter, Pascal};
+
+    fn ambient() -> MoistAirState {
+        MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(30),
+            0.7,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap()
+    }
+
+    fn insulation(pipe_diameter: Option<Length<Meter>>) -> Insulation {
+        Insulation {
+            conductivity: 0.035,
+            surface_coefficient: 9.0,
+            pipe_diameter,
+        }
+    }
+
+    #[test]
+    fn surface_temperature() {
+        let surface = get_surface_condensation(
+            &ambient(),
+            Temperature::<Celcius>::from(7),
+            &insulation(None),
+            Length::<Millimeter>::from(10),
+        )
+        .unwrap();
+        assert_eq!(surface.tsurface, Temperature::<Celcius>::from(23.56));
+        assert!(surface.condensing);
+        let surface = get_surface_condensation(
+            &ambient(),
+            Temperature::<Celcius>::from(7),
+            &insulation(Some(Length::<Meter>::from(0.05))),
+            Length::<Millimeter>::from(10),
+        )
+        .unwrap();
+        assert_eq!(surface.tsurface, Temperature::<Celcius>::from(24.290_32));
+        assert!(!surface.condensing);
+        assert!(is_condensing(&ambient(), Temperature::<Celcius>::from(23.9)).unwrap());
+    }
+
+    #[test]
+    fn cooled_air() {
+        let cooled = get_cooled_air(&ambient(), Temperature::<Celcius>::from(15)).unwrap();
+        let sat_hum_ratio = 0.010_647;
+        assert!((cooled.state.hum_ratio() - sat_hum_ratio).abs() < 1E-5);
+        assert!((cooled.state.rel_hum().unwrap() - 1.0).abs() < 1E-9);
+        assert!((cooled.condensed - (ambient().hum_ratio() - sat_hum_ratio)).abs() < 1E-5);
+        let heat_removed = f64::from(&cooled.heat_removed);
+        assert!((heat_removed - 35_708.0).abs() < 10.0);
+        let cooled = get_cooled_air(&ambient(), Temperature::<Celcius>::from(28)).unwrap();
+        assert!(cooled.condensed.abs() < f64::EPSILON);
+        // Frost leaves with the heat of fusion removed as well.
+        let cooled = get_cooled_air(&ambient(), Temperature::<Celcius>::from(-10)).unwrap();
+        assert!((cooled.state.hum_ratio() - 0.001_599_4).abs() < 1E-6);
+        let heat_removed = f64::from(&cooled.heat_removed);
+        assert!((heat_removed - 90_419.0).abs() < 10.0);
+        assert!(matches!(
+            get_cooled_air(&ambient(), Temperature::<Celcius>::from(35)),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn min_thickness() {
+        let thickness: Length<Millimeter> = get_min_insulation_thickness(
+            &ambient(),
+            Temperature::<Celcius>::from(7),
+            &insulation(None),
+        )
+        .unwrap();
+        assert!((f64::from(thickness) - 10.84).abs() < 0.05);
+        let thickness: Length<Millimeter> = get_min_insulation_thickness(
+            &ambient(),
+            Temperature::<Celcius>::from(7),
+            &insulation(Some(Length::<Meter>::from(0.05))),
+        )
+        .unwrap();
+        assert!((f64::from(thickness) - 9.30).abs() < 0.05);
+        let thickness: Length<Millimeter> = get_min_insulation_thickness(
+            &ambient(),
+            Temperature::<Celcius>::from(25),
+            &insulation(None),
+        )
+        .unwrap();
+        assert!(f64::from(thickness).abs() < f64::EPSILON);
+    }
+}
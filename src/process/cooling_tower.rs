@@ -0,0 +1,159 @@
+use crate::psychrolib::{get_sat_air_enthalpy, si, PsychroLibErr};
+use crate::quantities::{Pressure, SpecificEnthalpy, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, JoulesPerKg, Kelvin, Pascal, SpecificEnthalpyUnit, TemperatureUnit};
+
+/// Cooling tower operating point.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct CoolingTowerPerformance<S: SpecificEnthalpyUnit> {
+    /// Drop of the water temperature across the tower in K or °F, following `TD`.
+    pub range: f64,
+    /// Outlet water temperature above the entering air wet bulb in K or °F, following `TD`.
+    pub approach: f64,
+    /// Heat picked up by the air between the entering state and saturation at the leaving air
+    /// temperature, per mass of dry air.
+    pub heat_rejected: SpecificEnthalpy<S>,
+    /// Water flow per dry air flow (L/G) that balances the heat rejected, in kg_H₂O kg_Air⁻¹.
+    pub water_to_air_ratio: f64,
+}
+
+/// Difference of two temperatures in the temperature interval of unit `TD`.
+fn difference<TD: TemperatureUnit, T1: TemperatureUnit, T2: TemperatureUnit>(
+    a: &Temperature<T1>,
+    b: &Temperature<T2>,
+) -> f64 {
+    (a.micro_kelvin - b.micro_kelvin) as f64 / TD::conv_factor_micro_kelvin() as f64
+}
+
+/// Return range, approach and heat rejection of a cooling tower. Leaving air is assumed
+/// saturated at `tleaving_air`. Errors with `Range` when the operating point is not feasible:
+/// outlet water at or below the entering wet bulb, water not cooled, leaving air warmer than
+/// the entering water or picking up no heat.
+/// `twater_in` Entering water temperature in °F  or °C or K
+/// `twater_out` Leaving water temperature in °F  or °C or K
+/// `entering_air` Entering air state
+/// `tleaving_air` Leaving air temperature in °F  or °C or K
+pub fn get_cooling_tower_performance<
+    TD: TemperatureUnit,
+    S: SpecificEnthalpyUnit,
+    TI: TemperatureUnit,
+    TO: TemperatureUnit,
+    TL: TemperatureUnit,
+>(
+    twater_in: Temperature<TI>,
+    twater_out: Temperature<TO>,
+    entering_air: &MoistAirState,
+    tleaving_air: Temperature<TL>,
+) -> Result<CoolingTowerPerformance<S>, PsychroLibErr> {
+    let twet_bulb_in: Temperature<Celcius> = entering_air.twet_bulb()?;
+    let range = difference::<TD, _, _>(&twater_in, &twater_out);
+    let approach = difference::<TD, _, _>(&twater_out, &twet_bulb_in);
+    if range <= 0.0 || approach <= 0.0 || tleaving_air.micro_kelvin > twater_in.micro_kelvin {
+        return Err(PsychroLibErr::Range);
+    }
+    let leaving_enthalpy: SpecificEnthalpy<JoulesPerKg> = get_sat_air_enthalpy(
+        tleaving_air,
+        Pressure::<Pascal>::from(entering_air.pres_ambient_pa()),
+    )?;
+    let entering_enthalpy =
+        si::moist_air_enthalpy(entering_air.tdry_bulb_c(), entering_air.hum_ratio());
+    let heat_rejected = f64::from(&leaving_enthalpy) - entering_enthalpy;
+    if heat_rejected <= 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let range_k = difference::<Kelvin, _, _>(&twater_in, &twater_out);
+    Ok(CoolingTowerPerformance {
+        range,
+        approach,
+        heat_rejected: SpecificEnthalpy::<S>::from(&SpecificEnthalpy::<JoulesPerKg>::from(
+            heat_rejected,
+        )),
+        water_to_air_ratio: heat_rejected / (si::CP_WATER * range_k),
+    })
+}
+
+/// Return the enthalpy driving potential between moist air and a wet surface, the enthalpy of
+/// `air` less that of saturated air at `tsurface`. Positive when the air gives heat to the
+/// surface as on a wet cooling coil, negative when it picks up heat as in a cooling tower. Merkel
+/// type analysis integrates its inverse along the coil or the fill.
+/// Reference: ASHRAE Handbook - HVAC Systems and Equipment (2020) ch. 40 eqn 2
+/// Returns: Enthalpy difference in J Kg_Air⁻¹ or kJ Kg_Air⁻¹ or Btu lb_Air⁻¹
+/// `tsurface` Temperature of the wet surface or water in °F  or °C or K
+pub fn get_enthalpy_potential<T: TemperatureUnit, S: SpecificEnthalpyUnit>(
+    air: &MoistAirState,
+    tsurface: Temperature<T>,
+) -> Result<SpecificEnthalpy<S>, PsychroLibErr> {
+    let surface_enthalpy: SpecificEnthalpy<JoulesPerKg> =
+        get_sat_air_enthalpy(tsurface, Pressure::<Pascal>::from(air.pres_ambient_pa()))?;
+    let air_enthalpy = si::moist_air_enthalpy(air.tdry_bulb_c(), air.hum_ratio());
+    Ok(SpecificEnthalpy::<S>::from(
+        &SpecificEnthalpy::<JoulesPerKg>::from(air_enthalpy - f64::from(&surface_enthalpy)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Fahrenheit, KilojoulesPerKg};
+
+    fn entering_air() -> MoistAirState {
+        MoistAirState::from_twet_bulb(
+            Temperature::<Celcius>::from(30),
+            Temperature::<Celcius>::from(24),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn performance() {
+        let performance: CoolingTowerPerformance<KilojoulesPerKg> =
+            get_cooling_tower_performance::<Kelvin, _, _, _, _>(
+                Temperature::<Celcius>::from(35),
+                Temperature::<Celcius>::from(29),
+                &entering_air(),
+                Temperature::<Celcius>::from(32),
+            )
+            .unwrap();
+        assert!((performance.range - 6.0).abs() < 1E-3);
+        assert!((performance.approach - 5.0).abs() < 2E-3);
+        assert_eq!(
+            performance.heat_rejected,
+            SpecificEnthalpy::<JoulesPerKg>::from(38_717.89)
+        );
+        assert!((performance.water_to_air_ratio - 1.5416).abs() < 1E-3);
+        let performance: CoolingTowerPerformance<KilojoulesPerKg> =
+            get_cooling_tower_performance::<Fahrenheit, _, _, _, _>(
+                Temperature::<Celcius>::from(35),
+                Temperature::<Celcius>::from(29),
+                &entering_air(),
+                Temperature::<Celcius>::from(32),
+            )
+            .unwrap();
+        assert!((performance.range - 10.8).abs() < 1E-3);
+    }
+
+    #[test]
+    fn enthalpy_potential() {
+        let potential: SpecificEnthalpy<JoulesPerKg> =
+            get_enthalpy_potential(&entering_air(), Temperature::<Celcius>::from(10)).unwrap();
+        assert_eq!(potential, SpecificEnthalpy::<JoulesPerKg>::from(42_663.65));
+        let potential: SpecificEnthalpy<JoulesPerKg> =
+            get_enthalpy_potential(&entering_air(), Temperature::<Celcius>::from(32)).unwrap();
+        assert_eq!(potential, SpecificEnthalpy::<JoulesPerKg>::from(-38_717.89));
+    }
+
+    #[test]
+    fn infeasible_approach() {
+        let performance = get_cooling_tower_performance::<Kelvin, KilojoulesPerKg, _, _, _>(
+            Temperature::<Celcius>::from(35),
+            Temperature::<Celcius>::from(23),
+            &entering_air(),
+            Temperature::<Celcius>::from(32),
+        );
+        assert!(matches!(performance, Err(PsychroLibErr::Range)));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::Temperature;
+use crate::state::MoistAirState;
+use crate::units::{Celcius, TemperatureUnit};
+
+/// Conditions at which a dehumidifier's water removal is rated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RatingCondition {
+    /// 26.7 °C (80 °F) and 60 % relative humidity, AHAM DH-1 (2008).
+    Aham,
+    /// 30 °C and 80 % relative humidity, the older rating still quoted by many manufacturers.
+    #[default]
+    Legacy,
+}
+
+impl RatingCondition {
+    /// Dry bulb temperature in °C and relative humidity [0-1].
+    fn conditions(self) -> (f64, f64) {
+        match self {
+            RatingCondition::Aham => ((80.0 - 32.0) / 1.8, 0.6),
+            RatingCondition::Legacy => (30.0, 0.8),
+        }
+    }
+}
+
+/// Return the water a refrigerant dehumidifier removes from air at `state`, from its rated
+/// removal. The coil is modeled with a fixed apparatus dew point and bypass factor and constant
+/// airflow, so removal follows the humidity ratio above that of saturation at the apparatus dew
+/// point. Zero when the air is drier than that. `Range` when the rating conditions are not above
+/// the apparatus dew point.
+/// Reference: AHAM DH-1 (2008), ASHRAE Handbook - HVAC Systems and Equipment (2020) ch. 24
+/// Returns: Water removal in the unit of `rated_removal`
+/// `rated_removal` Rated water removal, usually in L day⁻¹ or pint day⁻¹
+/// `tadp` Apparatus dew point of the evaporator coil in °F  or °C or K, about 5 °C for small
+/// household units
+pub fn get_dehumidifier_removal<T: TemperatureUnit>(
+    rated_removal: f64,
+    rating: RatingCondition,
+    tadp: Temperature<T>,
+    state: &MoistAirState,
+) -> Result<f64, PsychroLibErr> {
+    let pres = state.pres_ambient_pa();
+    let tadp = f64::from(&Temperature::<Celcius>::from(&tadp));
+    let wadp = si::sat_hum_ratio(tadp, pres)?;
+    let (trated, rel_hum_rated) = rating.conditions();
+    let wrated = si::hum_ratio_from_rel_hum(trated, rel_hum_rated, pres)?;
+    if wrated <= wadp {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(rated_removal * (state.hum_ratio() - wadp).max(0.0) / (wrated - wadp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removal() {
+        let tadp = || Temperature::<Celcius>::from(5);
+        let removal = |rating, state: &MoistAirState| {
+            get_dehumidifier_removal(20.0, rating, tadp(), state).unwrap()
+        };
+        assert!(
+            (removal(
+                RatingCondition::Legacy,
+                &MoistAirState::test_rel_hum(30.0, 0.8)
+            ) - 20.0)
+                .abs()
+                < 1E-3
+        );
+        assert!(
+            (removal(
+                RatingCondition::Legacy,
+                &MoistAirState::test_rel_hum(20.0, 0.6)
+            ) - 4.1215)
+                .abs()
+                < 1E-3
+        );
+        assert!(
+            (removal(
+                RatingCondition::Legacy,
+                &MoistAirState::test_rel_hum((80.0 - 32.0) / 1.8, 0.6)
+            ) - 9.5893)
+                .abs()
+                < 1E-3
+        );
+        assert!(
+            removal(
+                RatingCondition::Legacy,
+                &MoistAirState::test_rel_hum(10.0, 0.5)
+            )
+            .abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (removal(
+                RatingCondition::Aham,
+                &MoistAirState::test_rel_hum((80.0 - 32.0) / 1.8, 0.6)
+            ) - 20.0)
+                .abs()
+                < 1E-3
+        );
+    }
+}
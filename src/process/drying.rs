@@ -0,0 +1,145 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Pascal};
+
+/// How the air picks up water while drying a product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DryingProcess {
+    /// Adiabatic drying. The heat of evaporation comes from the air, which cools along a line of
+    /// constant enthalpy.
+    #[default]
+    ConstantEnthalpy,
+    /// Heated drying. The air is kept at its dry bulb temperature.
+    ConstantTemperature,
+}
+
+/// Water an air state can absorb before reaching the target relative humidity.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DryingPotential {
+    /// Water absorbed in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+    pub hum_ratio: f64,
+    /// Water absorbed per volume of entering air in kg_H₂O m⁻³
+    pub per_volume: f64,
+    /// State of the air when it reaches the target relative humidity.
+    pub final_state: MoistAirState,
+}
+
+/// Return how much water `state` can absorb before reaching `target_rel_hum`, 1 for saturation.
+/// The potential is zero when the air is already at or above the target.
+/// `target_rel_hum` Relative humidity [0-1]
+pub fn get_drying_potential(
+    state: &MoistAirState,
+    target_rel_hum: f64,
+    process: DryingProcess,
+) -> Result<DryingPotential, PsychroLibErr> {
+    if !(0.0..=1.0).contains(&target_rel_hum) {
+        return Err(PsychroLibErr::Range);
+    }
+    let tdb = state.tdry_bulb_c();
+    let pres = state.pres_ambient_pa();
+    let hum_ratio = state.hum_ratio();
+    let (tdb_final, hum_ratio_final) = if state.rel_hum()? >= target_rel_hum {
+        (tdb, hum_ratio)
+    } else {
+        match process {
+            DryingProcess::ConstantTemperature => {
+                (tdb, si::hum_ratio_from_rel_hum(tdb, target_rel_hum, pres)?)
+            }
+            DryingProcess::ConstantEnthalpy => {
+                constant_enthalpy_end_point(tdb, hum_ratio, pres, target_rel_hum)?
+            }
+        }
+    };
+    let absorbed = hum_ratio_final - hum_ratio;
+    Ok(DryingPotential {
+        hum_ratio: absorbed,
+        per_volume: absorbed / si::moist_air_volume(tdb, hum_ratio, pres)?,
+        final_state: MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(tdb_final),
+            hum_ratio_final,
+            Pressure::<Pascal>::from(pres),
+        )?,
+    })
+}
+
+/// Bisection along the line of constant enthalpy. At the dew point of the entering air the line
+/// is supersaturated so it bounds the end point from below.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+fn constant_enthalpy_end_point(
+    tdb: f64,
+    hum_ratio: f64,
+    pres: f64,
+    target_rel_hum: f64,
+) -> Result<(f64, f64), PsychroLibErr> {
+    let enthalpy = si::moist_air_enthalpy(tdb, hum_ratio);
+    let mut t_inf = si::tdew_point_from_hum_ratio(tdb, hum_ratio, pres)?;
+    let mut t_sup = tdb;
+    for iteration in 0..si::MAX_ITER_COUNT {
+        let t = (t_inf + t_sup) / 2.0;
+        let w = si::hum_ratio_from_enthalpy_and_tdry_bulb(enthalpy, t);
+        let rel_hum = si::rel_hum_from_hum_ratio(t, w, pres)?;
+        si::trace_iteration!(iteration, t, residual = rel_hum - target_rel_hum);
+        if rel_hum > target_rel_hum {
+            t_inf = t;
+        } else {
+            t_sup = t;
+        }
+        if t_sup - t_inf <= si::TOLERANCE {
+            let t = (t_inf + t_sup) / 2.0;
+            return Ok((t, si::hum_ratio_from_enthalpy_and_tdry_bulb(enthalpy, t)));
+        }
+    }
+    Err(PsychroLibErr::Convergence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_enthalpy() {
+        let potential = get_drying_potential(
+            &MoistAirState::test_rel_hum(30.0, 0.3),
+            1.0,
+            DryingProcess::ConstantEnthalpy,
+        )
+        .unwrap();
+        assert!((potential.hum_ratio - 0.004_893).abs() < 2E-6);
+        assert!((potential.per_volume - 0.005_626).abs() < 2E-6);
+        let tdb = f64::from(potential.final_state.tdry_bulb::<Celcius>());
+        assert!((tdb - 17.851).abs() < 0.001);
+        let potential = get_drying_potential(
+            &MoistAirState::test_rel_hum(30.0, 0.3),
+            0.8,
+            DryingProcess::ConstantEnthalpy,
+        )
+        .unwrap();
+        assert!((potential.hum_ratio - 0.003_937).abs() < 2E-6);
+    }
+
+    #[test]
+    fn constant_temperature() {
+        let potential = get_drying_potential(
+            &MoistAirState::test_rel_hum(30.0, 0.3),
+            0.8,
+            DryingProcess::ConstantTemperature,
+        )
+        .unwrap();
+        assert!((potential.hum_ratio - 0.013_655).abs() < 1E-6);
+        assert!((potential.per_volume - 0.015_700).abs() < 1E-6);
+    }
+
+    #[test]
+    fn already_humid() {
+        let potential = get_drying_potential(
+            &MoistAirState::test_rel_hum(30.0, 0.3),
+            0.2,
+            DryingProcess::ConstantEnthalpy,
+        )
+        .unwrap();
+        assert!(potential.hum_ratio.abs() < f64::EPSILON);
+    }
+}
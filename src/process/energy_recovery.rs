@@ -0,0 +1,204 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Power, Temperature, VolumetricFlowRate};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, CubicMetersPerSecond, PowerUnit, VolumetricFlowRateUnit, Watt};
+
+/// Exhaust side of an energy recovery ventilator or heat wheel in winter.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ErvFrosting {
+    /// Temperature of the exhaust air leaving the exchanger.
+    pub texhaust_leaving: Temperature<Celcius>,
+    /// Humidity ratio of the exhaust air leaving the exchanger in kg_H₂O kg_Air⁻¹. Above
+    /// saturation when water condenses or frosts in the exchanger.
+    pub hum_ratio_exhaust_leaving: f64,
+    /// True when the leaving exhaust air is past its dew point.
+    pub condensing: bool,
+    /// True when the leaving exhaust air is past its frost point.
+    pub frosting: bool,
+    /// Outdoor temperature below which the exchanger frosts. `None` when it doesn't frost above
+    /// -100 °C.
+    pub toutdoor_frost_threshold: Option<Temperature<Celcius>>,
+}
+
+/// Exhaust air leaving the exchanger in °C and kg_H₂O kg_Air⁻¹ for balanced flows.
+fn exhaust_leaving(
+    toutdoor: f64,
+    woutdoor: f64,
+    exhaust: &MoistAirState,
+    sensible_effectiveness: f64,
+    latent_effectiveness: f64,
+) -> (f64, f64) {
+    let (texhaust, wexhaust) = (exhaust.tdry_bulb_c(), exhaust.hum_ratio());
+    (
+        texhaust - sensible_effectiveness * (texhaust - toutdoor),
+        wexhaust - latent_effectiveness * (wexhaust - woutdoor),
+    )
+}
+
+/// Return the exhaust air leaving an energy recovery ventilator or heat wheel with balanced flows,
+/// whether it condenses or frosts, and the outdoor temperature at which frosting begins. The
+/// threshold keeps the outdoor humidity ratio, capped at saturation. `Range` when an effectiveness
+/// is outside [0-1].
+/// Reference: ASHRAE Handbook - HVAC Systems and Equipment (2020) ch. 26
+/// `sensible_effectiveness` Sensible effectiveness [0-1]
+/// `latent_effectiveness` Latent effectiveness [0-1], 0 for a sensible only exchanger
+pub fn get_erv_frosting(
+    outdoor: &MoistAirState,
+    exhaust: &MoistAirState,
+    sensible_effectiveness: f64,
+    latent_effectiveness: f64,
+) -> Result<ErvFrosting, PsychroLibErr> {
+    if !(0.0..=1.0).contains(&sensible_effectiveness)
+        || !(0.0..=1.0).contains(&latent_effectiveness)
+    {
+        return Err(PsychroLibErr::Range);
+    }
+    let pres = exhaust.pres_ambient_pa();
+    let woutdoor = outdoor.hum_ratio();
+    let leaving = |toutdoor: f64, woutdoor: f64| {
+        exhaust_leaving(
+            toutdoor,
+            woutdoor,
+            exhaust,
+            sensible_effectiveness,
+            latent_effectiveness,
+        )
+    };
+    let frosting_at = |toutdoor: f64| -> Result<bool, PsychroLibErr> {
+        let woutdoor = woutdoor.min(si::sat_hum_ratio(toutdoor, pres)?);
+        let (tleaving, wleaving) = leaving(toutdoor, woutdoor);
+        Ok(tleaving <= si::FREEZING_POINT_WATER_C && wleaving > si::sat_hum_ratio(tleaving, pres)?)
+    };
+
+    let (tleaving, wleaving) = leaving(outdoor.tdry_bulb_c(), woutdoor);
+    let condensing = wleaving > si::sat_hum_ratio(tleaving, pres)?;
+
+    let (mut tfrost_inf, mut tfrost_sup) = (si::TEMPERATURE_BOUNDS.0, exhaust.tdry_bulb_c());
+    let toutdoor_frost_threshold = if frosting_at(tfrost_inf)? && !frosting_at(tfrost_sup)? {
+        while tfrost_sup - tfrost_inf > si::TOLERANCE {
+            let toutdoor = (tfrost_inf + tfrost_sup) / 2.0;
+            if frosting_at(toutdoor)? {
+                tfrost_inf = toutdoor;
+            } else {
+                tfrost_sup = toutdoor;
+            }
+        }
+        Some(Temperature::<Celcius>::from(tfrost_inf))
+    } else {
+        None
+    };
+
+    Ok(ErvFrosting {
+        texhaust_leaving: Temperature::<Celcius>::from(tleaving),
+        hum_ratio_exhaust_leaving: wleaving,
+        condensing,
+        frosting: condensing && tleaving <= si::FREEZING_POINT_WATER_C,
+        toutdoor_frost_threshold,
+    })
+}
+
+/// Preheat coil ahead of an energy recovery ventilator or heat wheel.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct ErvPreheat<W: PowerUnit> {
+    /// Temperature of the outdoor air leaving the preheat coil. The outdoor temperature when no
+    /// preheat is needed.
+    pub tpreheat_leaving: Temperature<Celcius>,
+    /// Heat added by the preheat coil.
+    pub power: Power<W>,
+}
+
+/// Return the preheat that keeps the exhaust side of an energy recovery ventilator or heat wheel
+/// above its frost point. Outdoor air is heated at constant humidity ratio up to the frost
+/// threshold of `get_erv_frosting`. `Range` when an effectiveness is outside [0-1].
+/// Reference: ASHRAE Handbook - HVAC Systems and Equipment (2020) ch. 26
+/// `sensible_effectiveness` Sensible effectiveness [0-1]
+/// `latent_effectiveness` Latent effectiveness [0-1], 0 for a sensible only exchanger
+/// `outdoor_air_flow` Outdoor air flow in cfm or m³ h⁻¹ or L s⁻¹
+pub fn get_erv_preheat<V: VolumetricFlowRateUnit, W: PowerUnit>(
+    outdoor: &MoistAirState,
+    exhaust: &MoistAirState,
+    sensible_effectiveness: f64,
+    latent_effectiveness: f64,
+    outdoor_air_flow: VolumetricFlowRate<V>,
+) -> Result<ErvPreheat<W>, PsychroLibErr> {
+    let frosting = get_erv_frosting(
+        outdoor,
+        exhaust,
+        sensible_effectiveness,
+        latent_effectiveness,
+    )?;
+    let (toutdoor, woutdoor) = (outdoor.tdry_bulb_c(), outdoor.hum_ratio());
+    let tpreheat = frosting
+        .toutdoor_frost_threshold
+        .map_or(toutdoor, |threshold| f64::from(&threshold).max(toutdoor));
+    let flow = f64::from(&VolumetricFlowRate::<CubicMetersPerSecond>::from(
+        &outdoor_air_flow,
+    ));
+    let dry_air_flow = flow / si::moist_air_volume(toutdoor, woutdoor, outdoor.pres_ambient_pa())?;
+    let power = dry_air_flow * (si::CP_DA + si::CP_VAPOR * woutdoor) * (tpreheat - toutdoor);
+    Ok(ErvPreheat {
+        tpreheat_leaving: Temperature::<Celcius>::from(tpreheat),
+        power: Power::<W>::from(&Power::<Watt>::from(power)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::LitersPerSecond;
+
+    #[test]
+    fn frosting() {
+        let erv = get_erv_frosting(
+            &MoistAirState::test_hum_ratio(-10.0, 0.001_2),
+            &MoistAirState::test_hum_ratio(21.0, 0.007_5),
+            0.75,
+            0.6,
+        )
+        .unwrap();
+        assert_eq!(erv.texhaust_leaving, Temperature::<Celcius>::from(-2.25));
+        assert!((erv.hum_ratio_exhaust_leaving - 0.003_72).abs() < 1E-9);
+        assert!(erv.condensing && erv.frosting);
+        let threshold = f64::from(&erv.toutdoor_frost_threshold.unwrap());
+        assert!((threshold + 7.232).abs() < 2E-3);
+
+        let erv = get_erv_frosting(
+            &MoistAirState::test_hum_ratio(0.0, 0.002),
+            &MoistAirState::test_hum_ratio(21.0, 0.007_5),
+            0.75,
+            0.6,
+        )
+        .unwrap();
+        assert!(!erv.frosting);
+        assert!(erv.toutdoor_frost_threshold.is_some());
+    }
+
+    #[test]
+    fn preheat() {
+        let preheat: ErvPreheat<Watt> = get_erv_preheat(
+            &MoistAirState::test_hum_ratio(-10.0, 0.001_2),
+            &MoistAirState::test_hum_ratio(21.0, 0.007_5),
+            0.75,
+            0.6,
+            VolumetricFlowRate::<LitersPerSecond>::from(100),
+        )
+        .unwrap();
+        assert!((f64::from(&preheat.tpreheat_leaving) + 7.232).abs() < 2E-3);
+        assert!((f64::from(&preheat.power) - 373.59).abs() < 0.5);
+        let preheat: ErvPreheat<Watt> = get_erv_preheat(
+            &MoistAirState::test_hum_ratio(0.0, 0.002),
+            &MoistAirState::test_hum_ratio(21.0, 0.007_5),
+            0.75,
+            0.6,
+            VolumetricFlowRate::<LitersPerSecond>::from(100),
+        )
+        .unwrap();
+        assert_eq!(preheat.tpreheat_leaving, Temperature::<Celcius>::from(0));
+        assert!(f64::from(&preheat.power).abs() < 1E-6);
+    }
+}
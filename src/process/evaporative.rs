@@ -0,0 +1,108 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Power, Pressure, Temperature, VolumetricFlowRate};
+use crate::state::MoistAirState;
+use crate::units::{
+    Celcius, CubicMetersPerSecond, Pascal, PowerUnit, TemperatureUnit, VolumetricFlowRateUnit, Watt,
+};
+
+/// Direct evaporative cooler sized for a room.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct EvaporativeCooler<V: VolumetricFlowRateUnit> {
+    /// State of the air leaving the cooler.
+    pub supply: MoistAirState,
+    /// Supply airflow that removes the sensible gain, at the supply state.
+    pub airflow: VolumetricFlowRate<V>,
+    /// Water evaporated in kg_H₂O h⁻¹, or L h⁻¹.
+    pub water_use: f64,
+    /// Relative humidity of the room at the indoor temperature [0-1]. The cooler removes no
+    /// moisture so the room holds the supply humidity ratio.
+    pub indoor_rel_hum: f64,
+}
+
+/// Return the supply state, airflow and water use of a direct evaporative cooler that holds a room
+/// at `tindoor` against a sensible heat gain. The air cools along a line of constant enthalpy
+/// towards the outdoor wet bulb temperature. `Range` when the effectiveness is outside [0-1] or
+/// the supply air is not below `tindoor`.
+/// Reference: ASHRAE Handbook - HVAC Systems and Equipment (2020) ch. 41 eqn 1
+/// `tindoor` Indoor design temperature in °F  or °C or K
+/// `sensible_gain` Sensible heat gain of the room in W or kW or Btu h⁻¹
+/// `effectiveness` Saturation effectiveness of the cooler [0-1]
+pub fn get_evaporative_cooler<T: TemperatureUnit, W: PowerUnit, V: VolumetricFlowRateUnit>(
+    outdoor: &MoistAirState,
+    tindoor: Temperature<T>,
+    sensible_gain: Power<W>,
+    effectiveness: f64,
+) -> Result<EvaporativeCooler<V>, PsychroLibErr> {
+    if !(0.0..=1.0).contains(&effectiveness) {
+        return Err(PsychroLibErr::Range);
+    }
+    let (toutdoor, woutdoor) = (outdoor.tdry_bulb_c(), outdoor.hum_ratio());
+    let pres = outdoor.pres_ambient_pa();
+    let tindoor = f64::from(&Temperature::<Celcius>::from(&tindoor));
+    let twet_bulb = si::twet_bulb_from_hum_ratio(toutdoor, woutdoor, pres)?;
+    let tsupply = toutdoor - effectiveness * (toutdoor - twet_bulb);
+    if tsupply >= tindoor {
+        return Err(PsychroLibErr::Range);
+    }
+    let wsupply = si::hum_ratio_from_enthalpy_and_tdry_bulb(
+        si::moist_air_enthalpy(toutdoor, woutdoor),
+        tsupply,
+    )
+    .max(woutdoor);
+    let sensible_gain = f64::from(&Power::<Watt>::from(&sensible_gain));
+    let dry_air_flow = sensible_gain / ((si::CP_DA + si::CP_VAPOR * wsupply) * (tindoor - tsupply));
+    let airflow = dry_air_flow * si::moist_air_volume(tsupply, wsupply, pres)?;
+    Ok(EvaporativeCooler {
+        supply: MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(tsupply),
+            wsupply,
+            Pressure::<Pascal>::from(pres),
+        )?,
+        airflow: VolumetricFlowRate::<V>::from(&VolumetricFlowRate::<CubicMetersPerSecond>::from(
+            airflow,
+        )),
+        water_use: dry_air_flow * (wsupply - woutdoor) * 3600.0,
+        indoor_rel_hum: si::rel_hum_from_hum_ratio(tindoor, wsupply, pres)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Kilowatt, LitersPerSecond};
+
+    fn outdoor() -> MoistAirState {
+        MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(35),
+            0.2,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn sizing() {
+        let cooler: EvaporativeCooler<LitersPerSecond> = get_evaporative_cooler(
+            &outdoor(),
+            Temperature::<Celcius>::from(27),
+            Power::<Kilowatt>::from(5),
+            0.85,
+        )
+        .unwrap();
+        assert!((cooler.supply.tdry_bulb_c() - 21.29).abs() < 2E-3);
+        assert!((cooler.supply.hum_ratio() - 0.012_485).abs() < 1E-6);
+        assert!((f64::from(&cooler.airflow) - 723.88).abs() < 0.5);
+        assert!((cooler.water_use - 16.84).abs() < 0.02);
+        assert!((cooler.indoor_rel_hum - 0.559).abs() < 1E-3);
+        let cooler = get_evaporative_cooler::<_, _, LitersPerSecond>(
+            &outdoor(),
+            Temperature::<Celcius>::from(20),
+            Power::<Kilowatt>::from(5),
+            0.85,
+        );
+        assert!(matches!(cooler, Err(PsychroLibErr::Range)));
+    }
+}
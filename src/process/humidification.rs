@@ -0,0 +1,80 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Pascal, PressureUnit};
+
+/// Air leaving a steam humidifier.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SteamInjection {
+    /// State of the air after the steam is absorbed.
+    pub leaving: MoistAirState,
+    /// Rise of the dry bulb temperature in K.
+    pub temperature_rise: f64,
+}
+
+/// Return the air leaving a steam humidifier and its dry bulb temperature rise. The steam brings
+/// its own enthalpy so the process is only close to isothermal. `Value` for a negative amount of
+/// steam or superheat and `Range` when the leaving air would be supersaturated.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30 and 46
+/// `hum_ratio_added` Steam added in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `pres_steam` Pressure of the steam in Psi  or Pa or atm
+/// `superheat` Temperature of the steam above saturation in K
+pub fn get_steam_injection<P: PressureUnit>(
+    entering: &MoistAirState,
+    hum_ratio_added: f64,
+    pres_steam: Pressure<P>,
+    superheat: f64,
+) -> Result<SteamInjection, PsychroLibErr> {
+    if hum_ratio_added < 0.0 || superheat < 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    let (t1, w1) = (entering.tdry_bulb_c(), entering.hum_ratio());
+    let pres = entering.pres_ambient_pa();
+    let tsteam =
+        si::sat_temp_from_vap_pres(f64::from(&Pressure::<Pascal>::from(&pres_steam)))? + superheat;
+    let steam_enthalpy = si::LATENT_HEAT_VAPORIZATION + si::CP_VAPOR * tsteam;
+    let w2 = w1 + hum_ratio_added;
+    let h2 = si::moist_air_enthalpy(t1, w1) + hum_ratio_added * steam_enthalpy;
+    let t2 = (h2 - si::LATENT_HEAT_VAPORIZATION * w2) / (si::CP_DA + si::CP_VAPOR * w2);
+    if w2 > si::sat_hum_ratio(t2, pres)? {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(SteamInjection {
+        leaving: MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(t2),
+            w2,
+            Pressure::<Pascal>::from(pres),
+        )?,
+        temperature_rise: t2 - t1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Atmosphere;
+
+    fn entering() -> MoistAirState {
+        MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(20),
+            0.004,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn temperature_rise() {
+        let steam =
+            get_steam_injection(&entering(), 0.004, Pressure::<Atmosphere>::from(1), 0.0).unwrap();
+        assert!((steam.temperature_rise - 0.582_84).abs() < 1E-4);
+        assert!((steam.leaving.hum_ratio() - 0.008).abs() < 1E-9);
+        let steam =
+            get_steam_injection(&entering(), 0.004, Pressure::<Atmosphere>::from(1), 30.0).unwrap();
+        assert!((steam.temperature_rise - 0.801_47).abs() < 1E-4);
+        let fog = get_steam_injection(&entering(), 0.02, Pressure::<Atmosphere>::from(1), 0.0);
+        assert!(matches!(fog, Err(PsychroLibErr::Range)));
+    }
+}
@@ -0,0 +1,46 @@
+//! Air conditioning and drying processes built from moist air states.
+mod airflow;
+pub use airflow::{
+    get_actual_flow, get_density_correction_factor, get_standard_flow, StandardConditions,
+};
+
+mod coil;
+pub use coil::{
+    get_apparatus_dew_point, get_coil_leaving_from_adp, get_shr_line, ApparatusDewPoint,
+    CoilLeaving, ShrLine,
+};
+
+mod condensation;
+pub use condensation::{
+    get_cooled_air, get_min_insulation_thickness, get_surface_condensation, is_condensing,
+    CooledAir, Insulation, SurfaceCondensation,
+};
+
+mod cooling_tower;
+pub use cooling_tower::{
+    get_cooling_tower_performance, get_enthalpy_potential, CoolingTowerPerformance,
+};
+
+mod dehumidifier;
+pub use dehumidifier::{get_dehumidifier_removal, RatingCondition};
+
+mod drying;
+pub use drying::{get_drying_potential, DryingPotential, DryingProcess};
+
+mod energy_recovery;
+pub use energy_recovery::{get_erv_frosting, get_erv_preheat, ErvFrosting, ErvPreheat};
+
+mod evaporative;
+pub use evaporative::{get_evaporative_cooler, EvaporativeCooler};
+
+mod humidification;
+pub use humidification::{get_steam_injection, SteamInjection};
+
+mod moisture_content;
+pub use moisture_content::{get_equilibrium_moisture_content, HygroscopicMaterial};
+
+mod room;
+pub use room::{get_room_humidity_trajectory, Room};
+
+mod ventilation;
+pub use ventilation::{get_ventilation_load, Season, VentilationLoad};
@@ -0,0 +1,107 @@
+use crate::psychrolib::PsychroLibErr;
+use crate::state::MoistAirState;
+
+/// Hygroscopic material and the sorption isotherm used for it.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HygroscopicMaterial {
+    /// Wood, Hailwood-Horrobin isotherm fitted by Simpson (1973).
+    #[default]
+    Wood,
+    /// Yellow dent corn, modified Henderson isotherm.
+    Corn,
+    /// Hard red wheat, modified Henderson isotherm.
+    Wheat,
+    /// Rough rice, modified Henderson isotherm.
+    RoughRice,
+    /// Modified Henderson isotherm with coefficients `k`, `n` and `c` for moisture content in
+    /// percent dry basis and temperature in °C.
+    Henderson { k: f64, n: f64, c: f64 },
+}
+
+impl HygroscopicMaterial {
+    /// Modified Henderson coefficients from ASAE D245.6
+    fn henderson(self) -> Option<(f64, f64, f64)> {
+        match self {
+            HygroscopicMaterial::Wood => None,
+            HygroscopicMaterial::Corn => Some((8.6541E-5, 1.8634, 49.810)),
+            HygroscopicMaterial::Wheat => Some((2.3007E-5, 2.2857, 55.815)),
+            HygroscopicMaterial::RoughRice => Some((1.9187E-5, 2.4451, 51.161)),
+            HygroscopicMaterial::Henderson { k, n, c } => Some((k, n, c)),
+        }
+    }
+}
+
+/// Return the moisture content `material` reaches when stored long enough in air at `state`.
+/// Returns: Equilibrium moisture content in kg_H₂O kg_Material⁻¹, dry basis
+/// Reference: Wood Handbook (2010) ch. 4 eqn 4-5, ASAE D245.6 (2007)
+pub fn get_equilibrium_moisture_content(
+    state: &MoistAirState,
+    material: HygroscopicMaterial,
+) -> Result<f64, PsychroLibErr> {
+    let rel_hum = state.rel_hum()?;
+    if !(0.0..1.0).contains(&rel_hum) {
+        return Err(PsychroLibErr::Range);
+    }
+    let tdry_bulb = state.tdry_bulb_c();
+    let percent = match material.henderson() {
+        None => hailwood_horrobin(tdry_bulb * 1.8 + 32.0, rel_hum),
+        Some((k, n, c)) => {
+            if tdry_bulb + c <= 0.0 {
+                return Err(PsychroLibErr::Range);
+            }
+            ((1.0 - rel_hum).ln() / (-k * (tdry_bulb + c))).powf(1.0 / n)
+        }
+    };
+    Ok(percent / 100.0)
+}
+
+/// Moisture content of wood in percent with the temperature in °F.
+fn hailwood_horrobin(t_f: f64, rel_hum: f64) -> f64 {
+    let w = 330.0 + 0.452 * t_f + 0.00415 * t_f * t_f;
+    let k = 0.791 + 4.63E-4 * t_f - 8.44E-7 * t_f * t_f;
+    let k1 = 6.34 + 7.75E-4 * t_f - 9.35E-5 * t_f * t_f;
+    let k2 = 1.09 + 2.84E-2 * t_f - 9.04E-5 * t_f * t_f;
+    let kh = k * rel_hum;
+    1800.0 / w
+        * (kh / (1.0 - kh)
+            + (k1 * kh + 2.0 * k1 * k2 * kh * kh) / (1.0 + k1 * kh + k1 * k2 * kh * kh))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::{Pressure, Temperature};
+    use crate::units::{Celcius, Fahrenheit, Pascal};
+
+    #[test]
+    fn wood() {
+        let state = MoistAirState::from_rel_hum(
+            Temperature::<Fahrenheit>::from(70),
+            0.5,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        let emc = get_equilibrium_moisture_content(&state, HygroscopicMaterial::Wood).unwrap();
+        assert!((emc - 0.092_46).abs() < 1E-4);
+    }
+
+    #[test]
+    fn grains() {
+        let state = MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            0.65,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        let emc = |material| get_equilibrium_moisture_content(&state, material).unwrap();
+        assert!((emc(HygroscopicMaterial::Corn) - 0.153_45).abs() < 1E-4);
+        assert!((emc(HygroscopicMaterial::Wheat) - 0.159_92).abs() < 1E-4);
+        assert!((emc(HygroscopicMaterial::RoughRice) - 0.147_30).abs() < 1E-4);
+        let custom = HygroscopicMaterial::Henderson {
+            k: 8.6541E-5,
+            n: 1.8634,
+            c: 49.810,
+        };
+        assert!((emc(custom) - emc(HygroscopicMaterial::Corn)).abs() < 1E-12);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Pascal};
+
+/// A well mixed room ventilated with supply air.
+#[derive(Debug)]
+pub struct Room {
+    /// Volume of the room in m³.
+    pub volume: f64,
+    /// Supply air changes per hour in h⁻¹.
+    pub air_changes: f64,
+    /// Moisture released in the room by occupants and processes in kg_H₂O h⁻¹.
+    pub moisture_generation: f64,
+}
+
+/// Return the room air state every `time_step_h` hours, starting with `initial`, by integrating
+/// the moisture balance of the room air over `steps` time steps. The room is held at the dry bulb
+/// temperature and pressure of `initial` and condensation on room surfaces is not modeled.
+/// Inputs are constant over each step so the balance is solved exactly.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 16 eqn 9 written for moisture
+/// `supply` Air supplied to the room, outdoor air for natural ventilation
+/// `time_step_h` Time step in h
+pub fn get_room_humidity_trajectory(
+    room: &Room,
+    initial: &MoistAirState,
+    supply: &MoistAirState,
+    time_step_h: f64,
+    steps: usize,
+) -> Result<Vec<MoistAirState>, PsychroLibErr> {
+    if room.volume <= 0.0 || room.air_changes < 0.0 || time_step_h <= 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    let tdb = initial.tdry_bulb_c();
+    let pres = initial.pres_ambient_pa();
+    let dry_air_mass = room.volume / si::moist_air_volume(tdb, initial.hum_ratio(), pres)?;
+    let gain = room.moisture_generation / dry_air_mass;
+    let next = |hum_ratio: f64| {
+        if room.air_changes == 0.0 {
+            return hum_ratio + gain * time_step_h;
+        }
+        let steady = supply.hum_ratio() + gain / room.air_changes;
+        steady + (hum_ratio - steady) * (-room.air_changes * time_step_h).exp()
+    };
+    let mut hum_ratio = initial.hum_ratio();
+    let mut trajectory = Vec::with_capacity(steps + 1);
+    for step in 0..=steps {
+        if step > 0 {
+            hum_ratio = next(hum_ratio);
+        }
+        trajectory.push(MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(tdb),
+            hum_ratio,
+            Pressure::<Pascal>::from(pres),
+        )?);
+    }
+    Ok(trajectory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trajectory() {
+        let room = Room {
+            volume: 50.0,
+            air_changes: 0.5,
+            moisture_generation: 0.2,
+        };
+        let trajectory = get_room_humidity_trajectory(
+            &room,
+            &MoistAirState::test_hum_ratio(21.0, 0.006),
+            &MoistAirState::test_hum_ratio(5.0, 0.004),
+            1.0,
+            4,
+        )
+        .unwrap();
+        assert_eq!(trajectory.len(), 5);
+        assert!((trajectory[4].hum_ratio() - 0.010_090_43).abs() < 1E-8);
+        let settled = get_room_humidity_trajectory(
+            &room,
+            &MoistAirState::test_hum_ratio(21.0, 0.006),
+            &MoistAirState::test_hum_ratio(5.0, 0.004),
+            24.0,
+            4,
+        )
+        .unwrap();
+        assert!((settled[4].hum_ratio() - 0.010_730_65).abs() < 1E-8);
+        assert!(settled[4].rel_hum().unwrap() > trajectory[0].rel_hum().unwrap());
+    }
+}
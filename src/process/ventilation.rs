@@ -0,0 +1,93 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Density, MassFlowRate, Power, SpecificEnthalpy, VolumetricFlowRate};
+use crate::state::MoistAirState;
+use crate::units::{JoulesPerKg, KgPerCubicMeter, KgPerSecond, PowerUnit, VolumetricFlowRateUnit};
+
+/// Season that fixes the sign of loads. Loads are positive when the plant has to work to
+/// handle them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Season {
+    /// Positive loads are heat and moisture to remove.
+    #[default]
+    Cooling,
+    /// Positive loads are heat and moisture to add.
+    Heating,
+}
+
+/// Load imposed by bringing outdoor air to the indoor state.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct VentilationLoad<W: PowerUnit> {
+    /// Load from the dry bulb temperature difference.
+    pub sensible: Power<W>,
+    /// Load from the humidity ratio difference.
+    pub latent: Power<W>,
+    /// Sum of the sensible and latent loads.
+    pub total: Power<W>,
+}
+
+/// Return the sensible, latent and total load of conditioning `outdoor_air_flow` from the
+/// outdoor to the indoor state. The flow is taken at outdoor conditions.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 16 eqn 9, 10 and 11
+/// `outdoor_air_flow` Outdoor air flow in cfm or m³ h⁻¹ or L s⁻¹
+pub fn get_ventilation_load<V: VolumetricFlowRateUnit, W: PowerUnit>(
+    outdoor: &MoistAirState,
+    indoor: &MoistAirState,
+    outdoor_air_flow: VolumetricFlowRate<V>,
+    season: Season,
+) -> Result<VentilationLoad<W>, PsychroLibErr> {
+    let (tout, wout) = (outdoor.tdry_bulb_c(), outdoor.hum_ratio());
+    let (tin, win) = (indoor.tdry_bulb_c(), indoor.hum_ratio());
+    let dry_air_density = 1.0 / si::moist_air_volume(tout, wout, outdoor.pres_ambient_pa())?;
+    let dry_air_flow = outdoor_air_flow * Density::<KgPerCubicMeter>::from(dry_air_density);
+    let sign = match season {
+        Season::Cooling => 1.0,
+        Season::Heating => -1.0,
+    };
+    let sensible = sign * (si::CP_DA + si::CP_VAPOR * wout) * (tout - tin);
+    let latent = sign * (si::LATENT_HEAT_VAPORIZATION + si::CP_VAPOR * tin) * (wout - win);
+    let power = |jpkg: f64| {
+        let dry_air_flow = MassFlowRate::<KgPerSecond>::from(&dry_air_flow);
+        Power::<W>::from(&(dry_air_flow * SpecificEnthalpy::<JoulesPerKg>::from(jpkg)))
+    };
+    Ok(VentilationLoad {
+        sensible: power(sensible),
+        latent: power(latent),
+        total: power(sensible + latent),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{LitersPerSecond, Watt};
+
+    #[test]
+    fn cooling_load() {
+        let load: VentilationLoad<Watt> = get_ventilation_load(
+            &MoistAirState::test_rel_hum(35.0, 0.4),
+            &MoistAirState::test_rel_hum(24.0, 0.5),
+            VolumetricFlowRate::<LitersPerSecond>::from(1000),
+            Season::Cooling,
+        )
+        .unwrap();
+        assert!((f64::from(&load.sensible) - 12_718.73).abs() < 0.1);
+        assert!((f64::from(&load.latent) - 13_780.93).abs() < 0.1);
+        assert!((f64::from(&load.total) - 26_499.67).abs() < 0.1);
+    }
+
+    #[test]
+    fn heating_load() {
+        let load: VentilationLoad<Watt> = get_ventilation_load(
+            &MoistAirState::test_rel_hum(-10.0, 0.8),
+            &MoistAirState::test_rel_hum(21.0, 0.3),
+            VolumetricFlowRate::<LitersPerSecond>::from(100),
+            Season::Heating,
+        )
+        .unwrap();
+        assert!(f64::from(&load.sensible) > 0.0);
+        assert!(f64::from(&load.latent) > 0.0);
+    }
+}
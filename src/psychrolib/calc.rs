@@ -0,0 +1,180 @@
+use super::{si, PsychroLibErr};
+use crate::quantities::{Pressure, SpecificEnthalpy, SpecificVolume, Temperature};
+use crate::units::{Celcius, CubicMetersPerKg, JoulesPerKg, Pascal, PressureUnit, TemperatureUnit};
+
+/// Every property of a moist air state at once, like the `CalcPsychrometrics` functions of
+/// PsychroLib. Quantities are in SI units, convert them with `From` as needed.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Psychrometrics {
+    /// Humidity ratio in kg_H₂O kg_Air⁻¹
+    pub hum_ratio: f64,
+    /// Wet bulb temperature
+    pub twet_bulb: Temperature<Celcius>,
+    /// Dew point temperature
+    pub tdew_point: Temperature<Celcius>,
+    /// Relative humidity [0-1]
+    pub rel_hum: f64,
+    /// Partial pressure of water vapor
+    pub vap_pres: Pressure<Pascal>,
+    /// Moist air enthalpy per kg of dry air
+    pub moist_air_enthalpy: SpecificEnthalpy<JoulesPerKg>,
+    /// Specific volume of moist air per kg of dry air
+    pub moist_air_volume: SpecificVolume<CubicMetersPerKg>,
+    /// Degree of saturation [0-1]
+    pub degree_of_saturation: f64,
+}
+
+impl Psychrometrics {
+    /// Fill in the properties that follow from the humidity ratio. Temperatures in °C, pressures
+    /// in Pa.
+    fn new(
+        tdry_bulb: f64,
+        hum_ratio: f64,
+        twet_bulb: f64,
+        rel_hum: f64,
+        pres: f64,
+    ) -> Result<Self, PsychroLibErr> {
+        Ok(Psychrometrics {
+            hum_ratio,
+            twet_bulb: Temperature::<Celcius>::from(twet_bulb),
+            tdew_point: Temperature::<Celcius>::from(si::tdew_point_from_hum_ratio(
+                tdry_bulb, hum_ratio, pres,
+            )?),
+            rel_hum,
+            vap_pres: Pressure::<Pascal>::from(si::vap_pres_from_hum_ratio(hum_ratio, pres)?),
+            moist_air_enthalpy: SpecificEnthalpy::<JoulesPerKg>::from(si::moist_air_enthalpy(
+                tdry_bulb, hum_ratio,
+            )),
+            moist_air_volume: SpecificVolume::<CubicMetersPerKg>::from(si::moist_air_volume(
+                tdry_bulb, hum_ratio, pres,
+            )?),
+            degree_of_saturation: hum_ratio / si::sat_hum_ratio(tdry_bulb, pres)?,
+        })
+    }
+}
+
+/// Return every property of moist air given dry-bulb temperature, relative humidity and pressure.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Range` outside -100 °C to 200 °C, when the relative humidity is not in [0-1] and
+/// when water boils at this pressure.
+pub fn calc_psychrometrics_from_rel_hum<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<Psychrometrics, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    if !(0.0..=1.0).contains(&rel_hum) {
+        return Err(PsychroLibErr::Range);
+    }
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    let hum_ratio = si::hum_ratio_from_rel_hum(tdc, rel_hum, pres)?;
+    let twet_bulb = si::twet_bulb_from_hum_ratio(tdc, hum_ratio, pres)?;
+    Psychrometrics::new(tdc, hum_ratio, twet_bulb, rel_hum, pres)
+}
+
+/// Return every property of moist air given dry-bulb temperature, wet-bulb temperature and
+/// pressure, as read from a sling or aspirated psychrometer.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 33 and 35
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `twet_bulb` Wet bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Range` outside -100 °C to 200 °C, when the wet bulb is above the dry bulb
+/// temperature and when water boils at this pressure.
+pub fn calc_psychrometrics_from_twet_bulb<
+    T: TemperatureUnit,
+    TW: TemperatureUnit,
+    P: PressureUnit,
+>(
+    tdry_bulb: Temperature<T>,
+    twet_bulb: Temperature<TW>,
+    pres_ambient: Pressure<P>,
+) -> Result<Psychrometrics, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let twc = f64::from(&Temperature::<Celcius>::from(&twet_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    si::check_tdry_bulb_range(twc)?;
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    let hum_ratio = si::hum_ratio_from_twet_bulb(tdc, twc, pres)?;
+    let rel_hum = si::rel_hum_from_hum_ratio(tdc, hum_ratio, pres)?;
+    Psychrometrics::new(tdc, hum_ratio, twc, rel_hum, pres)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{CubicFeetPerPound, Fahrenheit, Psi};
+
+    #[test]
+    fn from_twet_bulb() {
+        let psychrometrics = calc_psychrometrics_from_twet_bulb(
+            Temperature::<Celcius>::from(25),
+            Temperature::<Celcius>::from(18),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((psychrometrics.hum_ratio - 0.010_018).abs() < 1E-6);
+        assert_eq!(psychrometrics.twet_bulb, Temperature::<Celcius>::from(18));
+        assert!((f64::from(&psychrometrics.tdew_point) - 14.072).abs() < 2E-3);
+        assert!((psychrometrics.rel_hum - 0.506_81).abs() < 1E-5);
+        assert!((f64::from(&psychrometrics.moist_air_enthalpy) - 50_670.0).abs() < 1.0);
+        assert!((f64::from(&psychrometrics.moist_air_volume) - 0.858_23).abs() < 1E-5);
+        assert!((psychrometrics.degree_of_saturation - 0.498_86).abs() < 1E-4);
+        // Agrees with the relative humidity route.
+        let from_rel_hum = calc_psychrometrics_from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            psychrometrics.rel_hum,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((f64::from(&from_rel_hum.twet_bulb) - 18.0).abs() < 2E-3);
+        assert!(matches!(
+            calc_psychrometrics_from_twet_bulb(
+                Temperature::<Celcius>::from(25),
+                Temperature::<Celcius>::from(26),
+                Pressure::<Pascal>::from(101_325),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn from_rel_hum() {
+        let psychrometrics = calc_psychrometrics_from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            0.5,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((psychrometrics.hum_ratio - 0.009_881).abs() < 1E-6);
+        assert!((f64::from(&psychrometrics.twet_bulb) - 17.889).abs() < 2E-3);
+        assert!((f64::from(&psychrometrics.tdew_point) - 13.864).abs() < 2E-3);
+        assert!((psychrometrics.rel_hum - 0.5).abs() < 1E-12);
+        assert!((f64::from(&psychrometrics.vap_pres) - 1584.6).abs() < 0.1);
+        assert!((f64::from(&psychrometrics.moist_air_enthalpy) - 50_322.0).abs() < 1.0);
+        assert!((f64::from(&psychrometrics.moist_air_volume) - 0.858_04).abs() < 1E-5);
+        assert!((psychrometrics.degree_of_saturation - 0.492_06).abs() < 1E-4);
+
+        let psychrometrics = calc_psychrometrics_from_rel_hum(
+            Temperature::<Fahrenheit>::from(77),
+            0.5,
+            Pressure::<Psi>::from(14.696),
+        )
+        .unwrap();
+        let volume = SpecificVolume::<CubicFeetPerPound>::from(&psychrometrics.moist_air_volume);
+        assert!((f64::from(&volume) - 13.745).abs() < 1E-2);
+        assert!(matches!(
+            calc_psychrometrics_from_rel_hum(
+                Temperature::<Celcius>::from(25),
+                1.2,
+                Pressure::<Pascal>::from(101_325),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+}
@@ -0,0 +1,233 @@
+use super::{si, PsychroLibErr};
+use crate::quantities::{Pressure, Temperature};
+use crate::units::{Celcius, Pascal, PressureUnit, TemperatureUnit};
+
+/// Chebyshev nodes per segment, the degree of the fit plus one.
+const NODES: usize = 10;
+/// Points per segment where the fit is checked against the ASHRAE formulae.
+const CHECKS: usize = 4 * NODES;
+/// Segments per side of the triple point above which the fit gives up.
+const MAX_SEGMENTS: usize = 4096;
+
+/// Saturation pressure in Pa over ice or over water, given the temperature in °C.
+type Formula = fn(f64) -> f64;
+
+/// Chebyshev polynomial over `from..=to` in °C.
+#[derive(Debug, Clone)]
+struct Segment {
+    from: f64,
+    to: f64,
+    coefficients: [f64; NODES],
+}
+
+impl Segment {
+    /// Fit `formula`, the saturation pressure over ice or over water, which differ at the triple
+    /// point shared by the last segment over ice and the first over water.
+    fn fit(from: f64, to: f64, formula: Formula) -> Self {
+        let n = NODES as f64;
+        let values: [f64; NODES] = core::array::from_fn(|k| {
+            let theta = core::f64::consts::PI * (k as f64 + 0.5) / n;
+            formula(0.5 * (from + to) + 0.5 * (to - from) * theta.cos())
+        });
+        let coefficients = core::array::from_fn(|j| {
+            let sum: f64 = values
+                .iter()
+                .enumerate()
+                .map(|(k, value)| {
+                    value * (core::f64::consts::PI * j as f64 * (k as f64 + 0.5) / n).cos()
+                })
+                .sum();
+            if j == 0 {
+                sum / n
+            } else {
+                2.0 * sum / n
+            }
+        });
+        Segment {
+            from,
+            to,
+            coefficients,
+        }
+    }
+
+    /// Clenshaw recurrence.
+    fn eval(&self, t_c: f64) -> f64 {
+        let x = (2.0 * t_c - self.from - self.to) / (self.to - self.from);
+        let (b1, b2) = self.coefficients[1..]
+            .iter()
+            .rev()
+            .fold((0.0, 0.0), |(b1, b2), c| (2.0 * x * b1 - b2 + c, b1));
+        self.coefficients[0] + x * b1 - b2
+    }
+
+    fn max_rel_error(&self, formula: Formula) -> f64 {
+        (0..=CHECKS)
+            .map(|i| {
+                let t_c = self.from + (self.to - self.from) * i as f64 / CHECKS as f64;
+                let exact = formula(t_c);
+                (self.eval(t_c) - exact).abs() / exact
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Saturation vapor pressure precomputed as piecewise Chebyshev polynomials over a chosen range,
+/// for batch and control loop use where the same formula is evaluated millions of times. The
+/// segments are halved until the fit reproduces `get_sat_vap_pres` within the requested relative
+/// tolerance. The triple point is always a segment boundary, as the formulae over ice and water
+/// differ there.
+#[derive(Debug, Clone)]
+pub struct SatVapPresFit {
+    segments: Vec<Segment>,
+}
+
+impl SatVapPresFit {
+    /// Fit over `from..=to`, within `rel_tolerance` of the ASHRAE formulae.
+    /// `from`, `to` Dry bulb temperature in °F  or °C or K
+    /// Errors with `Value` when `to` is not above `from` or the tolerance is not positive, with
+    /// `Range` outside -100 °C to 200 °C and with `Convergence` when the tolerance is too tight to
+    /// reach, below about 1E-13.
+    pub fn new<T: TemperatureUnit>(
+        from: Temperature<T>,
+        to: Temperature<T>,
+        rel_tolerance: f64,
+    ) -> Result<Self, PsychroLibErr> {
+        let from = f64::from(&Temperature::<Celcius>::from(&from));
+        let to = f64::from(&Temperature::<Celcius>::from(&to));
+        if to <= from || rel_tolerance.is_nan() || rel_tolerance <= 0.0 {
+            return Err(PsychroLibErr::Value);
+        }
+        si::check_tdry_bulb_range(from)?;
+        si::check_tdry_bulb_range(to)?;
+        let triple_point = si::TRIPLE_POINT_WATER_C;
+        let mut segments = Vec::new();
+        let ranges: [(f64, f64, Formula); 2] = [
+            (from, to.min(triple_point), si::sat_vap_pres_ice),
+            (from.max(triple_point), to, si::sat_vap_pres_water),
+        ];
+        for (lower, upper, formula) in ranges {
+            if upper > lower {
+                segments.extend(Self::fit_range(lower, upper, formula, rel_tolerance)?);
+            }
+        }
+        Ok(SatVapPresFit { segments })
+    }
+
+    fn fit_range(
+        from: f64,
+        to: f64,
+        formula: Formula,
+        rel_tolerance: f64,
+    ) -> Result<Vec<Segment>, PsychroLibErr> {
+        let mut count = 1;
+        while count <= MAX_SEGMENTS {
+            let width = (to - from) / count as f64;
+            let segments: Vec<Segment> = (0..count)
+                .map(|i| {
+                    let upper = if i + 1 == count {
+                        to
+                    } else {
+                        from + (i + 1) as f64 * width
+                    };
+                    Segment::fit(from + i as f64 * width, upper, formula)
+                })
+                .collect();
+            if segments
+                .iter()
+                .all(|segment| segment.max_rel_error(formula) <= rel_tolerance)
+            {
+                return Ok(segments);
+            }
+            count *= 2;
+        }
+        Err(PsychroLibErr::Convergence)
+    }
+
+    /// Number of polynomial segments, the memory used grows with it.
+    #[must_use]
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Return saturation vapor pressure given dry-bulb temperature.
+    /// Returns: Vapor Pressure of saturated air in Psi  or Pa  or atm
+    /// `tdry_bulb` Dry bulb temperature in °F  or °C  or K
+    /// Errors with `Range` outside of the fitted range.
+    pub fn sat_vap_pres<T: TemperatureUnit, P: PressureUnit>(
+        &self,
+        tdry_bulb: Temperature<T>,
+    ) -> Result<Pressure<P>, PsychroLibErr> {
+        let t_c = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+        let index = self.segments.partition_point(|segment| segment.to < t_c);
+        match self.segments.get(index) {
+            Some(segment) if t_c >= segment.from => Ok(Pressure::<P>::from(
+                &Pressure::<Pascal>::from(segment.eval(t_c)),
+            )),
+            _ => Err(PsychroLibErr::Range),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::psychrolib::get_sat_vap_pres;
+    use crate::units::{Fahrenheit, Psi};
+
+    #[test]
+    fn accuracy() {
+        let fit = SatVapPresFit::new(
+            Temperature::<Celcius>::from(-40),
+            Temperature::<Celcius>::from(60),
+            1E-9,
+        )
+        .unwrap();
+        assert!(fit.segment_count() >= 2);
+        for tenth in -400..=600 {
+            let tdry_bulb = f64::from(tenth) / 10.0;
+            let exact: Pressure<Pascal> =
+                get_sat_vap_pres(Temperature::<Celcius>::from(tdry_bulb)).unwrap();
+            let fitted: Pressure<Pascal> = fit
+                .sat_vap_pres(Temperature::<Celcius>::from(tdry_bulb))
+                .unwrap();
+            let (exact, fitted) = (f64::from(exact), f64::from(fitted));
+            // Pressures are stored to the nearest mPa.
+            assert!((fitted - exact).abs() <= exact * 1E-9 + 1E-3);
+        }
+        let fitted: Pressure<Psi> = fit
+            .sat_vap_pres(Temperature::<Fahrenheit>::from(68))
+            .unwrap();
+        assert!((f64::from(fitted) - 0.339_28).abs() < 1E-4);
+    }
+
+    #[test]
+    fn limits() {
+        let fit = SatVapPresFit::new(
+            Temperature::<Celcius>::from(10),
+            Temperature::<Celcius>::from(30),
+            1E-6,
+        )
+        .unwrap();
+        assert_eq!(fit.segment_count(), 1);
+        assert!(matches!(
+            fit.sat_vap_pres::<Celcius, Pascal>(Temperature::<Celcius>::from(31)),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            SatVapPresFit::new(
+                Temperature::<Celcius>::from(30),
+                Temperature::<Celcius>::from(10),
+                1E-6
+            ),
+            Err(PsychroLibErr::Value)
+        ));
+        assert!(matches!(
+            SatVapPresFit::new(
+                Temperature::<Celcius>::from(10),
+                Temperature::<Celcius>::from(30),
+                1E-18
+            ),
+            Err(PsychroLibErr::Convergence)
+        ));
+    }
+}
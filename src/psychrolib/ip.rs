@@ -0,0 +1,35 @@
+//! Plain `f64` versions of the PsychroLib formulas in IP units. Temperatures are in °F,
+//! pressures in Psi, humidity ratios in lb_H₂O lb_Air⁻¹ and enthalpies in Btu lb_Air⁻¹.
+//! ASHRAE publishes these coefficients separately from the SI ones. They agree to within
+//! rounding except for enthalpy, which is referenced to 0 °F instead of 0 °C.
+
+/// Triple point of water in °F.
+pub(crate) const TRIPLE_POINT_WATER_F: f64 = 32.018;
+/// Zero degree Fahrenheit in °R.
+pub(crate) const ZERO_FAHRENHEIT_R: f64 = 459.67;
+
+pub(crate) fn t_rankine(t_f: f64) -> f64 {
+    t_f + ZERO_FAHRENHEIT_R
+}
+
+/// Saturation vapor pressure. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5 & 6
+pub(crate) fn sat_vap_pres(t_f: f64) -> f64 {
+    let t_r = t_rankine(t_f);
+    let ln_pws = if t_f <= TRIPLE_POINT_WATER_F {
+        -1.0214165E+04 / t_r - 4.8932428 - 5.3765794E-03 * t_r
+            + 1.9202377E-07 * t_r * t_r
+            + 3.5575832E-10 * t_r.powi(3)
+            - 9.0344688E-14 * t_r.powi(4)
+            + 4.1635019 * t_r.ln()
+    } else {
+        -1.0440397E+04 / t_r - 1.1294650E+01 - 2.7022355E-02 * t_r + 1.2890360E-05 * t_r * t_r
+            - 2.4780681E-09 * t_r.powi(3)
+            + 6.5459673 * t_r.ln()
+    };
+    ln_pws.exp()
+}
+
+/// Moist air enthalpy. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 30
+pub(crate) fn moist_air_enthalpy(t_f: f64, hum_ratio: f64) -> f64 {
+    0.240 * t_f + hum_ratio * (1061. + 0.444 * t_f)
+}
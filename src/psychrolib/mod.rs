@@ -1,15 +1,30 @@
-use crate::quantities::{Pressure, SpecificEnthalpy, Temperature};
-use crate::units::{Celcius, JoulesPerKg, Kelvin, Pascal};
-use crate::units::{PressureUnit, SpecificEnthalpyUnit, TemperatureUnit};
-// TODO: Implement in quantities a default check for temperature range -100...200 celcius
-// TODO: Minimum humidity ratio should be 1E-7.
-// TODO: Partial pressure cannot be negative
+use crate::quantities::{
+    Density, Length, Pressure, QuantityErr, SpecificEnthalpy, SpecificEntropy, SpecificHeat,
+    SpecificVolume, Temperature,
+};
+use crate::state::ConsistencyReport;
+use crate::units::{
+    BtuPerPound, Celcius, CubicMetersPerKg, Fahrenheit, JoulesPerKg, JoulesPerKgKelvin,
+    KgPerCubicMeter, Meter, Pascal, Psi,
+};
+use crate::units::{
+    DensityUnit, LengthUnit, PressureUnit, SpecificEnthalpyUnit, SpecificEntropyUnit,
+    SpecificVolumeUnit, TemperatureUnit,
+};
 
-const TRIPLE_POINT_WATER: Temperature<Kelvin> = Temperature {
-    micro_kelvin: 273_160_000,
-    unit: core::marker::PhantomData,
+mod calc;
+pub use calc::{
+    calc_psychrometrics_from_rel_hum, calc_psychrometrics_from_twet_bulb, Psychrometrics,
 };
 
+mod fit;
+pub use fit::SatVapPresFit;
+
+mod ip;
+pub(crate) mod si;
+// TODO: Implement in quantities a default check for temperature range -100...200 celcius
+// TODO: Minimum humidity ratio should be 1E-7.
+
 #[derive(Debug)]
 /// All types of errors possible within psychrometry crate.
 pub enum PsychroLibErr {
@@ -19,6 +34,132 @@ pub enum PsychroLibErr {
     Range,
     /// When the solution doesn't converge for given conditions.
     Convergence,
+    /// When redundant inputs disagree with each other or are out of range. The report lists every
+    /// issue.
+    Inconsistent(ConsistencyReport),
+}
+
+impl core::fmt::Display for PsychroLibErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PsychroLibErr::Value => write!(f, "invalid value in parameters"),
+            PsychroLibErr::Range => write!(f, "parameter outside of acceptable limits"),
+            PsychroLibErr::Convergence => write!(f, "solution did not converge"),
+            PsychroLibErr::Inconsistent(report) => write!(
+                f,
+                "inputs are not consistent with each other ({} issues)",
+                report.issues.len()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PsychroLibErr {}
+
+impl From<QuantityErr> for PsychroLibErr {
+    fn from(err: QuantityErr) -> Self {
+        match err {
+            QuantityErr::Value => PsychroLibErr::Value,
+            QuantityErr::Range => PsychroLibErr::Range,
+        }
+    }
+}
+
+/// Coefficient set used by formulas that ASHRAE publishes separately for SI and IP units.
+/// Results are converted to the requested unit either way, except enthalpies which are always in
+/// Btu lb_Air⁻¹. `Ip` exactly reproduces references computed with the IP formulas. Enthalpies
+/// computed with `Ip` are referenced to 0 °F dry air, so they are only comparable with other IP
+/// enthalpies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Coefficients {
+    #[default]
+    Si,
+    Ip,
+}
+
+/// Equation of state used for the density of moist air.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GasModel {
+    /// Ideal gas mixture, as in PsychroLib. Within 0.1 % near atmospheric pressure.
+    #[default]
+    Ideal,
+    /// Ideal gas corrected by the compressibility factor of `get_compressibility_factor`, for
+    /// compressed air and hyperbaric work. Valid from -100 °C to 200 °C. The neglected third
+    /// virial term stays below 0.1 % of the density up to about 2 MPa and grows to about 0.5 %
+    /// at 5 MPa.
+    Virial,
+}
+
+/// Range of dry bulb temperature accepted by the `_with_range` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TemperatureRange {
+    /// -100 °C to 200 °C, the range of the ASHRAE saturation formulae.
+    #[default]
+    Standard,
+    /// -100 °C to 300 °C, for dryer exhaust and other hot process air. Saturation above 100 °C
+    /// comes from IAPWS-IF97, within 0.02 % of the steam tables. Enthalpies keep the constant
+    /// specific heat of water vapor of ASHRAE eqn. 30, which reads the vapor term low by about
+    /// 0.2 % at 200 °C and 0.6 % at 300 °C. Humidity ratios assume ideal gases, so keep the vapor
+    /// pressure well below the ambient pressure. Above 100 °C at atmospheric pressure saturation
+    /// is out of reach and only relative humidities below `pres_ambient / sat_vap_pres` are valid.
+    Extended,
+}
+
+/// Saturation vapor pressure in Pa at `tdc` in °C, `Range` outside of `range`.
+fn sat_vap_pres_in_range(tdc: f64, range: TemperatureRange) -> Result<f64, PsychroLibErr> {
+    match range {
+        TemperatureRange::Standard => {
+            si::check_tdry_bulb_range(tdc)?;
+            Ok(si::sat_vap_pres(tdc))
+        }
+        TemperatureRange::Extended => {
+            si::check_tdry_bulb_range_extended(tdc)?;
+            Ok(si::sat_vap_pres_extended(tdc))
+        }
+    }
+}
+
+/// What to do when the inputs to a calculation imply a relative humidity above 1.
+/// Metrology applications usually want to know about it while control loops prefer a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SupersaturationPolicy {
+    /// Return `PsychroLibErr::Range`.
+    #[default]
+    Error,
+    /// Clamp the result to saturation.
+    Clamp,
+    /// Return the supersaturated value as it is, flagged as supersaturated.
+    Allow,
+}
+
+/// Result of a calculation done under a `SupersaturationPolicy`.
+#[derive(Debug)]
+pub struct SaturationChecked<V> {
+    /// Calculated value after the policy has been applied.
+    pub value: V,
+    /// True when the inputs implied a relative humidity above 1.
+    pub supersaturated: bool,
+}
+
+fn apply_supersaturation_policy(
+    rel_hum: f64,
+    policy: SupersaturationPolicy,
+) -> Result<SaturationChecked<f64>, PsychroLibErr> {
+    let supersaturated = rel_hum > 1.0;
+    let value = match (supersaturated, policy) {
+        (true, SupersaturationPolicy::Error) => return Err(PsychroLibErr::Range),
+        (true, SupersaturationPolicy::Clamp) => 1.0,
+        _ => rel_hum,
+    };
+    Ok(SaturationChecked {
+        value,
+        supersaturated,
+    })
+}
+
+/// Absolute pressures have no meaning at or below zero, and moist air is not modelled below 1 Pa.
+fn check_pres_ambient<P: PressureUnit>(pres: &Pressure<P>) -> Result<(), PsychroLibErr> {
+    si::check_pres_ambient(f64::from(&Pressure::<Pascal>::from(pres)))
 }
 
 /// Return saturation vapor pressure given dry-bulb temperature.
@@ -31,32 +172,58 @@ pub enum PsychroLibErr {
 /// the freezing point.
 /// Returns: Vapor Pressure of saturated air in Psi  or Pa  or atm
 /// `tdry_bulb` in Dry bulb temperature in °F  or °C  or K
+/// Errors with `Range` outside of the range of validity of the formulae, -100 °C to 200 °C.
 pub fn get_sat_vap_pres<T, P>(tdry_bulb: Temperature<T>) -> Result<Pressure<P>, PsychroLibErr>
 where
     T: TemperatureUnit,
     P: PressureUnit,
 {
-    let tdry_k = Temperature::<Kelvin>::from(&tdry_bulb);
-    let t_k = f64::from(&tdry_k);
-
-    let ln_pws = if (tdry_k <= TRIPLE_POINT_WATER) {
-        -5.6745359E+03 / t_k + 6.3925247 - 9.677843E-03 * t_k
-            + 6.2215701E-07 * t_k * t_k
-            + 2.0747825E-09 * t_k.powi(3)
-            - 9.484024E-13 * t_k.powi(4)
-            + 4.1635019 * t_k.ln()
-    } else {
-        -5.8002206E+03 / t_k + 1.3914993 - 4.8640239E-02 * t_k + 4.1764768E-05 * t_k * t_k
-            - 1.4452093E-08 * t_k.powi(3)
-            + 6.5459673 * t_k.ln()
-    };
-    let sat_vap_pres = Pressure::<Pascal>::from(ln_pws.exp());
+    let tdry_c = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdry_c)?;
+    let sat_vap_pres = Pressure::<Pascal>::from(si::sat_vap_pres(tdry_c));
     Ok(Pressure::<P>::from(&sat_vap_pres))
 }
 
+/// Return saturation vapor pressure given dry-bulb temperature using the chosen coefficient set.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5 & 6
+/// Returns: Vapor Pressure of saturated air in Psi  or Pa  or atm
+/// `tdry_bulb` in Dry bulb temperature in °F  or °C  or K
+pub fn get_sat_vap_pres_with_coefficients<T, P>(
+    tdry_bulb: Temperature<T>,
+    coefficients: Coefficients,
+) -> Result<Pressure<P>, PsychroLibErr>
+where
+    T: TemperatureUnit,
+    P: PressureUnit,
+{
+    match coefficients {
+        Coefficients::Si => get_sat_vap_pres(tdry_bulb),
+        Coefficients::Ip => {
+            si::check_tdry_bulb_range(f64::from(&Temperature::<Celcius>::from(&tdry_bulb)))?;
+            let tdry_f = f64::from(&Temperature::<Fahrenheit>::from(&tdry_bulb));
+            let sat_vap_pres = Pressure::<Psi>::from(ip::sat_vap_pres(tdry_f));
+            Ok(Pressure::<P>::from(&sat_vap_pres))
+        }
+    }
+}
+
 fn enthalpy_in_jpkg(tdcf: f64, hum_ratio: f64) -> SpecificEnthalpy<JoulesPerKg> {
-    let ejpkgf = (1.006 * tdcf + hum_ratio * (2501. + 1.86 * tdcf)) * 1000.0;
-    SpecificEnthalpy::<JoulesPerKg>::from(ejpkgf)
+    SpecificEnthalpy::<JoulesPerKg>::from(si::moist_air_enthalpy(tdcf, hum_ratio))
+}
+
+/// Return dry air enthalpy given dry-bulb temperature, referenced to dry air at 0 °C.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 28
+/// Returns: Dry air enthalpy in J kg_Air⁻¹ or kJ kg_Air⁻¹ or Btu lb_Air⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// Errors with `Range` outside -100 °C to 200 °C.
+pub fn get_dry_air_enthalpy<T: TemperatureUnit, S: SpecificEnthalpyUnit>(
+    tdry_bulb: Temperature<T>,
+) -> Result<SpecificEnthalpy<S>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    Ok(SpecificEnthalpy::<S>::from(
+        &SpecificEnthalpy::<JoulesPerKg>::from(si::CP_DA * tdc),
+    ))
 }
 
 /// Return moist air enthalpy given dry-bulb temperature and humidity ratio.
@@ -74,6 +241,88 @@ pub fn get_moist_air_enthalpy_from_hum_ratio<T: TemperatureUnit, SPE: SpecificEn
     Ok(SpecificEnthalpy::<SPE>::from(&moist_air_enthalpy))
 }
 
+/// Return moist air enthalpy given dry-bulb temperature and humidity ratio using the chosen
+/// coefficient set, in Btu lb_Air⁻¹ either way. The type doesn't record the datum: `Si` results
+/// are referenced to 0 °C dry air and `Ip` results to 0 °F dry air, so the two must not be mixed.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 30
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// Returns Moist air enthalpy in Btu lb_Air⁻¹
+pub fn get_moist_air_enthalpy_from_hum_ratio_with_coefficients<T: TemperatureUnit>(
+    tdry_bulb: Temperature<T>,
+    hum_ratio: f64,
+    coefficients: Coefficients,
+) -> Result<SpecificEnthalpy<BtuPerPound>, PsychroLibErr> {
+    match coefficients {
+        Coefficients::Si => get_moist_air_enthalpy_from_hum_ratio(tdry_bulb, hum_ratio),
+        Coefficients::Ip => {
+            let tdry_f = f64::from(&Temperature::<Fahrenheit>::from(&tdry_bulb));
+            Ok(SpecificEnthalpy::<BtuPerPound>::from(
+                ip::moist_air_enthalpy(tdry_f, hum_ratio),
+            ))
+        }
+    }
+}
+
+/// Return dry-bulb temperature given moist air enthalpy and humidity ratio, the analytical inverse
+/// of `get_moist_air_enthalpy_from_hum_ratio`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30 solved for t
+/// Returns: Dry bulb temperature in °F  or °C or K
+/// `moist_air_enthalpy` Moist air enthalpy in J kg_Air⁻¹ or kJ kg_Air⁻¹ or Btu lb_Air⁻¹
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// Errors with `Range` when the humidity ratio is negative.
+pub fn get_tdry_bulb_from_enthalpy_and_hum_ratio<S: SpecificEnthalpyUnit, T: TemperatureUnit>(
+    moist_air_enthalpy: SpecificEnthalpy<S>,
+    hum_ratio: f64,
+) -> Result<Temperature<T>, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let enthalpy = f64::from(&SpecificEnthalpy::<JoulesPerKg>::from(&moist_air_enthalpy));
+    let tdry_bulb = si::tdry_bulb_from_enthalpy_and_hum_ratio(enthalpy, hum_ratio);
+    Ok(Temperature::<T>::from(&Temperature::<Celcius>::from(
+        tdry_bulb,
+    )))
+}
+
+/// Return humidity ratio given moist air enthalpy and dry-bulb temperature, the analytical
+/// inverse of `get_moist_air_enthalpy_from_hum_ratio`. An enthalpy below that of dry air at
+/// `tdry_bulb` gives the minimum humidity ratio of 1E-7.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30 solved for W
+/// Returns: Humidity Ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `moist_air_enthalpy` Moist air enthalpy in J kg_Air⁻¹ or kJ kg_Air⁻¹ or Btu lb_Air⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// Errors with `Range` outside -100 °C to 200 °C.
+/// Results below the 1E-7 floor are raised to it silently.
+pub fn get_hum_ratio_from_enthalpy_and_tdry_bulb<S: SpecificEnthalpyUnit, T: TemperatureUnit>(
+    moist_air_enthalpy: SpecificEnthalpy<S>,
+    tdry_bulb: Temperature<T>,
+) -> Result<f64, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let enthalpy = f64::from(&SpecificEnthalpy::<JoulesPerKg>::from(&moist_air_enthalpy));
+    Ok(si::hum_ratio_from_enthalpy_and_tdry_bulb(enthalpy, tdc))
+}
+
+/// Return saturated air enthalpy given dry-bulb temperature and pressure.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30
+/// Returns: Saturated air enthalpy in J Kg_Air⁻¹ or kJ Kg_Air⁻¹ or Btu lb_Air⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_sat_air_enthalpy<T: TemperatureUnit, P: PressureUnit, S: SpecificEnthalpyUnit>(
+    tdry_bulb: Temperature<T>,
+    pres_ambient: Pressure<P>,
+) -> Result<SpecificEnthalpy<S>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let sat_hum_ratio =
+        si::sat_hum_ratio(tdc, f64::from(&Pressure::<Pascal>::from(&pres_ambient)))?;
+    Ok(SpecificEnthalpy::<S>::from(&enthalpy_in_jpkg(
+        tdc,
+        sat_hum_ratio,
+    )))
+}
+
 /// Return moist air enthalpy given dry-bulb temperature and relative humidity.
 /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 30
 /// `tdry_bulb` Dry bulb temperature in °F  or °C or K
@@ -95,15 +344,381 @@ pub fn get_moist_air_enthalpy_from_rel_hum<
     Ok(SpecificEnthalpy::<S>::from(&moist_air_enthalpy))
 }
 
+/// Return the temperature at which water boils or frosts at the given pressure. The inverse of
+/// `get_sat_vap_pres`, solved with Newton-Raphson.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5 & 6
+/// Returns: Saturation temperature in °F  or °C or K
+/// `pres` Pressure of the water vapor in Psi  or Pa or atm
+pub fn get_sat_temp_from_pressure<P: PressureUnit, T: TemperatureUnit>(
+    pres: Pressure<P>,
+) -> Result<Temperature<T>, PsychroLibErr> {
+    let tsat = si::sat_temp_from_vap_pres(f64::from(&Pressure::<Pascal>::from(&pres)))?;
+    Ok(Temperature::<T>::from(&Temperature::<Celcius>::from(tsat)))
+}
+
+/// Return dew-point temperature given dry-bulb temperature and vapor pressure. The inverse of
+/// `get_sat_vap_pres`, solved with Newton-Raphson on the log of the vapor pressure. The iteration
+/// switches between the formulae over ice and water at the triple point, where they meet, so it
+/// converges on either side of it. The result is capped at the dry bulb temperature.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5 and 6
+/// Returns: Dew Point temperature in °F  or °C or K
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `vap_pres` Partial pressure of water vapor in moist air in Psi  or Pa or atm
+/// Errors with `Range` when the dry bulb temperature or the dew point leaves -100 °C to 200 °C.
+pub fn get_tdew_point_from_vap_pres<T: TemperatureUnit, PV: PressureUnit, TD: TemperatureUnit>(
+    tdry_bulb: Temperature<T>,
+    vap_pres: Pressure<PV>,
+) -> Result<Temperature<TD>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let tdew_point =
+        si::tdew_point_from_vap_pres(tdc, f64::from(&Pressure::<Pascal>::from(&vap_pres)))?;
+    Ok(Temperature::<TD>::from(&Temperature::<Celcius>::from(
+        tdew_point,
+    )))
+}
+
+/// Return dew-point temperature given dry-bulb temperature and relative humidity, as reported by
+/// most humidity sensors.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5, 6 and 22
+/// Returns: Dew Point temperature in °F  or °C or K
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// Errors with `Range` when the relative humidity is not in (0-1] or the temperatures leave
+/// -100 °C to 200 °C.
+pub fn get_tdew_point_from_rel_hum<T: TemperatureUnit, TD: TemperatureUnit>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+) -> Result<Temperature<TD>, PsychroLibErr> {
+    if rel_hum <= 0.0 || rel_hum > 1.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let tdew_point = si::tdew_point_from_vap_pres(tdc, rel_hum * si::sat_vap_pres(tdc))?;
+    Ok(Temperature::<TD>::from(&Temperature::<Celcius>::from(
+        tdew_point,
+    )))
+}
+
+/// Return dew-point temperature given humidity ratio and pressure. Without a dry bulb
+/// temperature the result is not capped, so a supersaturated humidity ratio gives a dew point
+/// above the air temperature.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5, 6 and 20
+/// Returns: Dew Point temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Value` when the pressure is not positive and with `Range` when the humidity ratio
+/// is negative or the dew point leaves -100 °C to 200 °C.
+pub fn get_tdew_point_from_hum_ratio<P: PressureUnit, TD: TemperatureUnit>(
+    hum_ratio: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<Temperature<TD>, PsychroLibErr> {
+    let vap_pres = si::vap_pres_from_hum_ratio(
+        hum_ratio,
+        f64::from(&Pressure::<Pascal>::from(&pres_ambient)),
+    )?;
+    let tdew_point = si::sat_temp_from_vap_pres(vap_pres)?;
+    Ok(Temperature::<TD>::from(&Temperature::<Celcius>::from(
+        tdew_point,
+    )))
+}
+
+/// Return wet-bulb temperature given dry-bulb temperature, dew-point temperature, and pressure,
+/// as for a chilled mirror hygrometer.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 33 and 35, solved by bisection
+/// Returns: Wet bulb temperature in °F  or °C or K
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `tdew_point` Dew point temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Range` when the dew point is above the dry bulb temperature or either leaves
+/// -100 °C to 200 °C, and with `Convergence` when the bisection fails.
+pub fn get_twet_bulb_from_tdew_point<
+    T: TemperatureUnit,
+    TD: TemperatureUnit,
+    P: PressureUnit,
+    TW: TemperatureUnit,
+>(
+    tdry_bulb: Temperature<T>,
+    tdew_point: Temperature<TD>,
+    pres_ambient: Pressure<P>,
+) -> Result<Temperature<TW>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let tdp = f64::from(&Temperature::<Celcius>::from(&tdew_point));
+    si::check_tdry_bulb_range(tdc)?;
+    si::check_tdry_bulb_range(tdp)?;
+    if tdp > tdc {
+        return Err(PsychroLibErr::Range);
+    }
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    let hum_ratio = si::hum_ratio_from_vap_pres(si::sat_vap_pres(tdp), pres)?;
+    let twet_bulb = si::twet_bulb_from_hum_ratio(tdc, hum_ratio, pres)?;
+    Ok(Temperature::<TW>::from(&Temperature::<Celcius>::from(
+        twet_bulb,
+    )))
+}
+
+/// Dew point in °C after the moist air goes from `pres_from` to `pres_to` with the same
+/// composition, so the vapor pressure scales with the total pressure.
+fn tdew_point_at_pressure(
+    tdew_point: f64,
+    pres_from: f64,
+    pres_to: f64,
+) -> Result<f64, PsychroLibErr> {
+    si::check_tdry_bulb_range(tdew_point)?;
+    si::check_pres_ambient(pres_from)?;
+    si::check_pres_ambient(pres_to)?;
+    let vap_pres = si::sat_vap_pres(tdew_point);
+    if vap_pres > si::MAX_VAP_PRES_FRACTION * pres_from {
+        return Err(PsychroLibErr::Range);
+    }
+    si::sat_temp_from_vap_pres(vap_pres * pres_to / pres_from)
+}
+
+/// Return the pressure dew point of compressed air, its dew point at line pressure, given the
+/// dew point of the same air expanded to atmospheric pressure. Compressing air raises its vapor
+/// pressure with the total pressure, so the pressure dew point is the higher of the two.
+/// Reference: ISO 8573-1 (2010), ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 5 & 6
+/// Returns: Pressure dew point in °F  or °C or K
+/// `tdew_point_atm` Dew point at atmospheric pressure in °F  or °C or K
+/// `pres_atm` Atmospheric pressure in Psi  or Pa or atm
+/// `pres_line` Absolute line pressure in Psi  or Pa or atm
+/// Errors with `Value` when a pressure is not positive and with `Range` when either dew point
+/// leaves -100 °C to 200 °C.
+pub fn get_pressure_dew_point<T: TemperatureUnit, P: PressureUnit, TP: TemperatureUnit>(
+    tdew_point_atm: Temperature<T>,
+    pres_atm: Pressure<P>,
+    pres_line: Pressure<P>,
+) -> Result<Temperature<TP>, PsychroLibErr> {
+    let tdew_point = tdew_point_at_pressure(
+        f64::from(&Temperature::<Celcius>::from(&tdew_point_atm)),
+        f64::from(&Pressure::<Pascal>::from(&pres_atm)),
+        f64::from(&Pressure::<Pascal>::from(&pres_line)),
+    )?;
+    Ok(Temperature::<TP>::from(&Temperature::<Celcius>::from(
+        tdew_point,
+    )))
+}
+
+/// Return the atmospheric dew point of compressed air, its dew point once expanded to
+/// atmospheric pressure, given its pressure dew point at line pressure. The inverse of
+/// `get_pressure_dew_point`.
+/// Reference: ISO 8573-1 (2010), ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 5 & 6
+/// Returns: Atmospheric dew point in °F  or °C or K
+/// `tdew_point_line` Pressure dew point in °F  or °C or K
+/// `pres_line` Absolute line pressure in Psi  or Pa or atm
+/// `pres_atm` Atmospheric pressure in Psi  or Pa or atm
+/// Errors like `get_pressure_dew_point`.
+pub fn get_atmospheric_dew_point<T: TemperatureUnit, P: PressureUnit, TA: TemperatureUnit>(
+    tdew_point_line: Temperature<T>,
+    pres_line: Pressure<P>,
+    pres_atm: Pressure<P>,
+) -> Result<Temperature<TA>, PsychroLibErr> {
+    let tdew_point = tdew_point_at_pressure(
+        f64::from(&Temperature::<Celcius>::from(&tdew_point_line)),
+        f64::from(&Pressure::<Pascal>::from(&pres_line)),
+        f64::from(&Pressure::<Pascal>::from(&pres_atm)),
+    )?;
+    Ok(Temperature::<TA>::from(&Temperature::<Celcius>::from(
+        tdew_point,
+    )))
+}
+
+/// Return the adiabatic saturation temperature given dry-bulb temperature, humidity ratio and
+/// pressure. It solves the energy balance of an adiabatic saturator, where water entering at the
+/// outlet temperature brings the air to saturation at that same temperature.
+/// The wet bulb temperature of `MoistAirState::twet_bulb` is ASHRAE's closed form of the same
+/// balance (eqn 33 & 35), with the enthalpies linearized. The two agree within the solver
+/// tolerance above freezing. Below freezing the closed form rounds the latent heat of fusion,
+/// which moves the result by a few thousandths of a kelvin. Neither is exactly what a
+/// psychrometer reads, which also depends on the ventilation of the wick.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 31
+/// Returns: Adiabatic saturation temperature in °F  or °C or K
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_tadiabatic_saturation<T: TemperatureUnit, P: PressureUnit, TS: TemperatureUnit>(
+    tdry_bulb: Temperature<T>,
+    hum_ratio: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<Temperature<TS>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    let tsat = si::tadiabatic_saturation_from_hum_ratio(tdc, hum_ratio, pres)?;
+    Ok(Temperature::<TS>::from(&Temperature::<Celcius>::from(tsat)))
+}
+
+/// Return the specific entropy of moist air given dry-bulb temperature, humidity ratio and pressure.
+/// Dry air and water vapor are treated as ideal gases, with the reference states of ASHRAE.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 table 2
+/// Returns: Moist air entropy in J kg_Air⁻¹ K⁻¹ or kJ kg_Air⁻¹ K⁻¹ or Btu lb_Air⁻¹ °R⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_moist_air_entropy<T: TemperatureUnit, P: PressureUnit, S: SpecificEntropyUnit>(
+    tdry_bulb: Temperature<T>,
+    hum_ratio: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<SpecificEntropy<S>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    let entropy = si::moist_air_entropy(tdc, hum_ratio, pres)?;
+    Ok(SpecificEntropy::<S>::from(&SpecificEntropy::<
+        JoulesPerKgKelvin,
+    >::from(entropy)))
+}
+
+/// Return the humid heat, the heat needed to warm the moist air holding 1 kg of dry air by 1 K.
+/// Unlike the specific heat of the mixture it is per mass of dry air, as used in drying.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30 differentiated for t
+/// Returns: Humid heat in J kg_Air⁻¹ K⁻¹ or kJ kg_Air⁻¹ K⁻¹ or Btu lb_Air⁻¹ °R⁻¹
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+pub fn get_humid_heat<S: SpecificEntropyUnit>(
+    hum_ratio: f64,
+) -> Result<SpecificHeat<S>, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let humid_heat = si::CP_DA + si::CP_VAPOR * hum_ratio.max(si::MIN_HUM_RATIO);
+    Ok(SpecificHeat::<S>::from(
+        &SpecificHeat::<JoulesPerKgKelvin>::from(humid_heat),
+    ))
+}
+
+/// Return the specific gas constant of moist air, per kg of the mixture, for `p = ρ R T`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 1 weighted by mass
+/// Returns: Gas constant in J kg⁻¹ K⁻¹ or kJ kg⁻¹ K⁻¹ or Btu lb⁻¹ °R⁻¹
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+pub fn get_moist_air_gas_constant<S: SpecificEntropyUnit>(
+    hum_ratio: f64,
+) -> Result<SpecificHeat<S>, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = hum_ratio.max(si::MIN_HUM_RATIO);
+    let gas_constant = (si::R_DA + si::R_W * hum_ratio) / (1.0 + hum_ratio);
+    Ok(SpecificHeat::<S>::from(
+        &SpecificHeat::<JoulesPerKgKelvin>::from(gas_constant),
+    ))
+}
+
+/// Return the compressibility factor Z of moist air, `p v = Z R T`, truncated after the second
+/// virial coefficient. 1 for an ideal gas, below 1 for air near ambient temperature.
+/// Reference: Hyland & Wexler (1983), ASHRAE Transactions 89(2A)
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_compressibility_factor<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    hum_ratio: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<f64, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    si::compressibility_factor(tdc, hum_ratio, pres)
+}
+
+/// Return the density of moist air given dry-bulb temperature, humidity ratio and pressure,
+/// under the equation of state `gas_model`. The specific volume per kg of dry air is
+/// `(1 + hum_ratio) / density`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 11 divided by Z
+/// Returns: Density in lb ft⁻³ or kg m⁻³ or g m⁻³
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+pub fn get_moist_air_density_with_gas_model<T: TemperatureUnit, P: PressureUnit, D: DensityUnit>(
+    tdry_bulb: Temperature<T>,
+    hum_ratio: f64,
+    pres_ambient: Pressure<P>,
+    gas_model: GasModel,
+) -> Result<Density<D>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    let density = si::moist_air_density(tdc, hum_ratio, pres)?;
+    let compressibility = match gas_model {
+        GasModel::Ideal => 1.0,
+        GasModel::Virial => si::compressibility_factor(tdc, hum_ratio, pres)?,
+    };
+    Ok(Density::<D>::from(&Density::<KgPerCubicMeter>::from(
+        density / compressibility,
+    )))
+}
+
+/// Return dry air density given dry-bulb temperature and pressure, from the ideal gas law with
+/// the gas constant of dry air.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+/// Returns: Dry air density in lb ft⁻³ or kg m⁻³ or g m⁻³
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Value` when the pressure is not positive and with `Range` outside -100 °C to
+/// 200 °C.
+pub fn get_dry_air_density<T: TemperatureUnit, P: PressureUnit, D: DensityUnit>(
+    tdry_bulb: Temperature<T>,
+    pres_ambient: Pressure<P>,
+) -> Result<Density<D>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    si::check_pres_positive(pres)?;
+    Ok(Density::<D>::from(&Density::<KgPerCubicMeter>::from(
+        pres / (si::R_DA * si::t_kelvin(tdc)),
+    )))
+}
+
+/// Return dry air volume given dry-bulb temperature and pressure, the inverse of
+/// `get_dry_air_density`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1
+/// Returns: Dry air volume in ft³ lb⁻¹ or m³ kg⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors like `get_dry_air_density`.
+pub fn get_dry_air_volume<T: TemperatureUnit, P: PressureUnit, V: SpecificVolumeUnit>(
+    tdry_bulb: Temperature<T>,
+    pres_ambient: Pressure<P>,
+) -> Result<SpecificVolume<V>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    si::check_pres_positive(pres)?;
+    Ok(SpecificVolume::<V>::from(
+        &SpecificVolume::<CubicMetersPerKg>::from(si::R_DA * si::t_kelvin(tdc) / pres),
+    ))
+}
+
+/// Return the apparent molecular weight of moist air.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 22 & 23
+/// Returns: Molecular weight in kg kmol⁻¹ or lb lbmol⁻¹
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+pub fn get_moist_air_molecular_weight(hum_ratio: f64) -> Result<f64, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = hum_ratio.max(si::MIN_HUM_RATIO);
+    Ok(
+        si::MOLECULAR_WEIGHT_DA * (1.0 + hum_ratio)
+            / (1.0 + hum_ratio / si::MOLECULAR_WEIGHT_RATIO),
+    )
+}
+
 /// Return vapor pressure given humidity ratio and pressure.
 /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 20 solved for pw
 /// Returns: Partial pressure of water vapor in moist air in Psi  or Pa or atm
 /// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
 /// `pressure` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Value` when the ambient pressure is not positive, and with `Range` below 1 Pa or
+/// when the humidity ratio is negative or above about 61.6.
 pub fn get_vap_pres_from_hum_ratio<PA: PressureUnit, PV: PressureUnit>(
     hum_ratio: f64,
     pres_ambient: Pressure<PA>,
 ) -> Result<Pressure<PV>, PsychroLibErr> {
+    check_pres_ambient(&pres_ambient)?;
+    if !(0.0..=si::MAX_HUM_RATIO).contains(&hum_ratio) {
+        return Err(PsychroLibErr::Range);
+    }
     // EFFICIENCY: Is it more efficient to have Pressure unit at the end? All operations as float till the pressure?
     let vap_pres = hum_ratio / (0.621945 + hum_ratio) * pres_ambient;
     Ok(Pressure::<PV>::from(&vap_pres))
@@ -134,9 +749,95 @@ pub fn get_rel_hum_from_vap_pres<T: TemperatureUnit, PV: PressureUnit>(
     Ok(vap_pres / sat_vap_pres)
 }
 
+/// Return relative humidity given dry-bulb temperature and dew-point temperature, the ratio of the
+/// saturation vapor pressures at the two temperatures. Below the triple point the saturation
+/// pressure is over ice, so a frost point below a subzero dry bulb gives the relative humidity with
+/// respect to ice, as in PsychroLib.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 22
+/// Returns: Relative humidity [0-1]
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `tdew_point` Dew point temperature in °F  or °C or K
+/// Errors with `Range` when the dew point is above the dry bulb temperature or either leaves
+/// -100 °C to 200 °C.
+pub fn get_rel_hum_from_tdew_point<T: TemperatureUnit, TD: TemperatureUnit>(
+    tdry_bulb: Temperature<T>,
+    tdew_point: Temperature<TD>,
+) -> Result<f64, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let tdp = f64::from(&Temperature::<Celcius>::from(&tdew_point));
+    si::check_tdry_bulb_range(tdc)?;
+    si::check_tdry_bulb_range(tdp)?;
+    if tdp > tdc {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(si::sat_vap_pres(tdp) / si::sat_vap_pres(tdc))
+}
+
+/// Return the vapor pressure deficit, how far the vapor pressure is below saturation, given
+/// dry-bulb temperature, humidity ratio and pressure. See
+/// `get_vapor_pressure_deficit_from_rel_hum` for a relative humidity.
+/// Reference: Oke (1987) Boundary Layer Climates, as in PsychroLib
+/// Returns: Vapor pressure deficit in Psi  or Pa or atm
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Range` outside -100 °C to 200 °C, when the humidity ratio is negative and when
+/// the air is supersaturated.
+pub fn get_vapor_pressure_deficit<T: TemperatureUnit, P: PressureUnit, PV: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    hum_ratio: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<Pressure<PV>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    let vap_pres = si::vap_pres_from_hum_ratio(hum_ratio, pres)?;
+    let sat_vap_pres = si::sat_vap_pres(tdc);
+    if vap_pres > sat_vap_pres {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(Pressure::<PV>::from(&Pressure::<Pascal>::from(
+        sat_vap_pres - vap_pres,
+    )))
+}
+
+/// Return the vapor pressure deficit given dry-bulb temperature, relative humidity and pressure,
+/// the saturation vapor pressure times `1 - rel_hum`.
+/// Reference: Oke (1987) Boundary Layer Climates, as in PsychroLib
+/// Returns: Vapor pressure deficit in Psi  or Pa or atm
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Range` outside -100 °C to 200 °C, when the relative humidity is outside [0-1]
+/// and when the vapor pressure is too close to the pressure for the air to hold it.
+pub fn get_vapor_pressure_deficit_from_rel_hum<
+    T: TemperatureUnit,
+    P: PressureUnit,
+    PV: PressureUnit,
+>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<Pressure<PV>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    if !(0.0..=1.0).contains(&rel_hum) {
+        return Err(PsychroLibErr::Range);
+    }
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    si::hum_ratio_from_rel_hum(tdc, rel_hum, pres)?;
+    Ok(Pressure::<PV>::from(&Pressure::<Pascal>::from(
+        si::sat_vap_pres(tdc) * (1.0 - rel_hum),
+    )))
+}
+
 /// Return humidity ratio given water vapor pressure and atmospheric pressure.
 /// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 20
 /// Returns Humidity Ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// Errors with `Value` when the ambient pressure is not positive or the vapor pressure is
+/// negative, and with `Range` when the ambient pressure is below 1 Pa or the vapor pressure is
+/// above 99 % of it. Near pure vapor, as in a freeze dryer chamber, the humidity ratio grows
+/// without bound and the vapor pressure is the meaningful measure.
 pub fn get_hum_ratio_from_vap_pres<PV: PressureUnit, P: PressureUnit>(
     vap_pres: Pressure<PV>,
     pres_ambient: Pressure<P>,
@@ -144,6 +845,13 @@ pub fn get_hum_ratio_from_vap_pres<PV: PressureUnit, P: PressureUnit>(
     let pres_ambient_vp = Pressure::<PV>::from(&pres_ambient);
     let vpf = f64::from(&vap_pres);
     let apf = f64::from(&pres_ambient_vp);
+    check_pres_ambient(&pres_ambient)?;
+    if vpf < 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    if vpf > si::MAX_VAP_PRES_FRACTION * apf {
+        return Err(PsychroLibErr::Range);
+    }
     let hum_ratio = 0.621945 * vpf / (apf - vpf);
     Ok(hum_ratio)
 }
@@ -165,8 +873,415 @@ pub fn get_hum_ratio_from_rel_hum<T: TemperatureUnit, P: PressureUnit>(
     Ok(hum_ratio)
 }
 
+/// Return the humidity ratio of saturated air given dry-bulb temperature and pressure, over ice
+/// below the triple point and over water above it.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 23
+/// Returns: Humidity ratio of saturated air in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Value` when the pressure is not positive and with `Range` outside -100 °C to
+/// 200 °C or when water boils at this pressure.
+/// Results below the 1E-7 floor are raised to it silently.
+pub fn get_sat_hum_ratio<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    pres_ambient: Pressure<P>,
+) -> Result<f64, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    si::sat_hum_ratio(tdc, f64::from(&Pressure::<Pascal>::from(&pres_ambient)))
+}
+
+/// Return the degree of saturation, the ratio of the humidity ratio to the humidity ratio of
+/// saturated air at the same temperature and pressure. Close to, but not equal to, the relative
+/// humidity.
+/// Reference: ASHRAE Handbook - Fundamentals (2009) ch. 1 eqn 12
+/// Returns: Degree of saturation [0-1]
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors like `get_sat_hum_ratio`, and with `Range` when the humidity ratio is negative.
+pub fn get_degree_of_saturation<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    hum_ratio: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<f64, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = hum_ratio.max(si::MIN_HUM_RATIO);
+    Ok(hum_ratio / get_sat_hum_ratio(tdry_bulb, pres_ambient)?)
+}
+
+/// Return the humidity ratio of air saturated with respect to ice. Only defined up to the triple
+/// point of water, `Range` above it.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 5 and 23
+/// Returns: Humidity ratio of saturated air over ice in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Results below the 1E-7 floor are raised to it silently.
+pub fn get_sat_hum_ratio_ice<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    pres_ambient: Pressure<P>,
+) -> Result<f64, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    if tdc > si::TRIPLE_POINT_WATER_C {
+        return Err(PsychroLibErr::Range);
+    }
+    si::hum_ratio_from_vap_pres(
+        si::sat_vap_pres_ice(tdc),
+        f64::from(&Pressure::<Pascal>::from(&pres_ambient)),
+    )
+}
+
+/// Return the specific humidity from humidity ratio (aka mixing ratio).
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 9b
+/// Returns: Specific humidity in lb_H₂O lb⁻¹  or kg_H₂O kg⁻¹
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+pub fn get_specific_hum_from_hum_ratio(hum_ratio: f64) -> Result<f64, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = hum_ratio.max(si::MIN_HUM_RATIO);
+    Ok(hum_ratio / (1.0 + hum_ratio))
+}
+
+/// Return the humidity ratio (aka mixing ratio) from specific humidity.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 9b (solved for humidity ratio)
+/// Returns: Humidity Ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `specific_hum` Specific humidity in lb_H₂O lb⁻¹  or kg_H₂O kg⁻¹
+/// Results below the 1E-7 floor are raised to it silently.
+pub fn get_hum_ratio_from_specific_hum(specific_hum: f64) -> Result<f64, PsychroLibErr> {
+    if !(0.0..1.0).contains(&specific_hum) {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = specific_hum / (1.0 - specific_hum);
+    Ok(hum_ratio.max(si::MIN_HUM_RATIO))
+}
+
+/// Parts per million in one.
+const PPM: f64 = 1E6;
+
+/// Return the moisture content in parts per million by volume, the mole fraction of water vapor
+/// in the moist gas, from humidity ratio. Unlike other humidity ratios it is not raised to 1E-7,
+/// so the traces measured in gloveboxes and dried gases convert exactly.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 20 & 22
+/// Returns: Moisture content in ppmv
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+pub fn get_ppmv_from_hum_ratio(hum_ratio: f64) -> Result<f64, PsychroLibErr> {
+    if !(0.0..=si::MAX_HUM_RATIO).contains(&hum_ratio) {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(PPM * hum_ratio / (si::MOLECULAR_WEIGHT_RATIO + hum_ratio))
+}
+
+/// Return the humidity ratio from the moisture content in parts per million by volume.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 20 & 22
+/// Returns: Humidity Ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `ppmv` Moisture content in ppmv
+pub fn get_hum_ratio_from_ppmv(ppmv: f64) -> Result<f64, PsychroLibErr> {
+    let mole_fraction = ppmv / PPM;
+    if !(0.0..=si::MAX_VAP_PRES_FRACTION).contains(&mole_fraction) {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(si::MOLECULAR_WEIGHT_RATIO * mole_fraction / (1.0 - mole_fraction))
+}
+
+/// Return the moisture content in parts per million by volume from vapor pressure.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 22
+/// Returns: Moisture content in ppmv
+/// `vap_pres` Partial pressure of water vapor in moist air in Psi  or Pa or atm
+/// `pres_ambient` Atmospheric or line pressure in Psi  or Pa or atm
+pub fn get_ppmv_from_vap_pres<PV: PressureUnit, P: PressureUnit>(
+    vap_pres: Pressure<PV>,
+    pres_ambient: Pressure<P>,
+) -> Result<f64, PsychroLibErr> {
+    check_pres_ambient(&pres_ambient)?;
+    let vpf = f64::from(&Pressure::<Pascal>::from(&vap_pres));
+    let apf = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    if vpf < 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    if vpf > si::MAX_VAP_PRES_FRACTION * apf {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(PPM * vpf / apf)
+}
+
+/// Return vapor pressure from the moisture content in parts per million by volume.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 22
+/// Returns: Partial pressure of water vapor in moist air in Psi  or Pa or atm
+/// `ppmv` Moisture content in ppmv
+/// `pres_ambient` Atmospheric or line pressure in Psi  or Pa or atm
+pub fn get_vap_pres_from_ppmv<P: PressureUnit, PV: PressureUnit>(
+    ppmv: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<Pressure<PV>, PsychroLibErr> {
+    check_pres_ambient(&pres_ambient)?;
+    let mole_fraction = ppmv / PPM;
+    if !(0.0..=si::MAX_VAP_PRES_FRACTION).contains(&mole_fraction) {
+        return Err(PsychroLibErr::Range);
+    }
+    let vap_pres = mole_fraction * f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    Ok(Pressure::<PV>::from(&Pressure::<Pascal>::from(vap_pres)))
+}
+
+/// Return the moisture content in parts per million by weight, the mass fraction of water in the
+/// moist gas, from humidity ratio. Not raised to 1E-7, like `get_ppmv_from_hum_ratio`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 9b
+/// Returns: Moisture content in ppmw
+/// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+pub fn get_ppmw_from_hum_ratio(hum_ratio: f64) -> Result<f64, PsychroLibErr> {
+    if !(0.0..=si::MAX_HUM_RATIO).contains(&hum_ratio) {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(PPM * hum_ratio / (1.0 + hum_ratio))
+}
+
+/// Return the humidity ratio from the moisture content in parts per million by weight.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 9b (solved for humidity ratio)
+/// Returns: Humidity Ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `ppmw` Moisture content in ppmw
+pub fn get_hum_ratio_from_ppmw(ppmw: f64) -> Result<f64, PsychroLibErr> {
+    if !(0.0..PPM).contains(&ppmw) {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = ppmw / (PPM - ppmw);
+    if hum_ratio > si::MAX_HUM_RATIO {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(hum_ratio)
+}
+
+/// Return absolute humidity, the mass of water vapor per volume of moist air, given dry-bulb
+/// temperature and vapor pressure. Water vapor is treated as an ideal gas.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 1 for water vapor
+/// Returns: Absolute humidity in lb ft⁻³ or kg m⁻³ or g m⁻³
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `vap_pres` Partial pressure of water vapor in moist air in Psi  or Pa or atm
+/// Errors with `Value` when the vapor pressure is negative and with `Range` outside -100 °C to
+/// 200 °C.
+pub fn get_absolute_hum_from_vap_pres<T: TemperatureUnit, PV: PressureUnit, D: DensityUnit>(
+    tdry_bulb: Temperature<T>,
+    vap_pres: Pressure<PV>,
+) -> Result<Density<D>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let vap_pres = f64::from(&Pressure::<Pascal>::from(&vap_pres));
+    if vap_pres < 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    Ok(Density::<D>::from(&Density::<KgPerCubicMeter>::from(
+        vap_pres / (si::R_W * si::t_kelvin(tdc)),
+    )))
+}
+
+/// Return absolute humidity given dry-bulb temperature and relative humidity.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 1 for water vapor and eqn 22
+/// Returns: Absolute humidity in lb ft⁻³ or kg m⁻³ or g m⁻³
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// Errors with `Range` when the relative humidity is negative or outside -100 °C to 200 °C.
+pub fn get_absolute_hum_from_rel_hum<T: TemperatureUnit, D: DensityUnit>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+) -> Result<Density<D>, PsychroLibErr> {
+    if rel_hum < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    get_absolute_hum_from_vap_pres(
+        tdry_bulb,
+        Pressure::<Pascal>::from(rel_hum * si::sat_vap_pres(tdc)),
+    )
+}
+
+/// Return vapor pressure given dry-bulb temperature and absolute humidity, the inverse of
+/// `get_absolute_hum_from_vap_pres`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 1 for water vapor
+/// Returns: Partial pressure of water vapor in moist air in Psi  or Pa or atm
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `absolute_hum` Absolute humidity in lb ft⁻³ or kg m⁻³ or g m⁻³
+/// Errors with `Value` when the absolute humidity is negative and with `Range` outside -100 °C
+/// to 200 °C.
+pub fn get_vap_pres_from_absolute_hum<T: TemperatureUnit, D: DensityUnit, PV: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    absolute_hum: Density<D>,
+) -> Result<Pressure<PV>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    si::check_tdry_bulb_range(tdc)?;
+    let absolute_hum = f64::from(&Density::<KgPerCubicMeter>::from(&absolute_hum));
+    if absolute_hum < 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    Ok(Pressure::<PV>::from(&Pressure::<Pascal>::from(
+        absolute_hum * si::R_W * si::t_kelvin(tdc),
+    )))
+}
+
+/// Return relative humidity given dry-bulb temperature and absolute humidity.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 1 for water vapor and eqn 22
+/// Returns: Relative humidity [0-1]
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `absolute_hum` Absolute humidity in lb ft⁻³ or kg m⁻³ or g m⁻³
+/// Errors like `get_vap_pres_from_absolute_hum`.
+pub fn get_rel_hum_from_absolute_hum<T: TemperatureUnit, D: DensityUnit>(
+    tdry_bulb: Temperature<T>,
+    absolute_hum: Density<D>,
+) -> Result<f64, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let vap_pres: Pressure<Pascal> = get_vap_pres_from_absolute_hum(tdry_bulb, absolute_hum)?;
+    Ok(f64::from(&vap_pres) / si::sat_vap_pres(tdc))
+}
+
+/// Return standard atmosphere barometric pressure, given the elevation (altitude).
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 3
+/// Returns: Standard atmosphere barometric pressure in Psi  or Pa or atm
+/// `altitude` Altitude in ft  or m
+pub fn get_standard_atm_pressure<L: LengthUnit, P: PressureUnit>(
+    altitude: Length<L>,
+) -> Result<Pressure<P>, PsychroLibErr> {
+    let altitude_m = f64::from(&Length::<Meter>::from(&altitude));
+    let pres = 101325. * (1. - 2.25577E-05 * altitude_m).powf(5.2559);
+    Ok(Pressure::<P>::from(&Pressure::<Pascal>::from(pres)))
+}
+
+/// Return standard atmosphere temperature, given the elevation (altitude).
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 4
+/// Returns: Standard atmosphere dry-bulb temperature in °F  or °C or K
+/// `altitude` Altitude in ft  or m
+pub fn get_standard_atm_temperature<L: LengthUnit, T: TemperatureUnit>(
+    altitude: Length<L>,
+) -> Result<Temperature<T>, PsychroLibErr> {
+    let altitude_m = f64::from(&Length::<Meter>::from(&altitude));
+    let temperature = 15. - 0.0065 * altitude_m;
+    Ok(Temperature::<T>::from(&Temperature::<Celcius>::from(
+        temperature,
+    )))
+}
+
+/// Return relative humidity given dry-bulb temperature and vapor pressure, applying `policy`
+/// when the vapor pressure is above saturation.
+/// Returns: Relative humidity [0-1] and whether the inputs were supersaturated
+/// `t_dry_bulb` Dry bulb temperature in °F  or °C or K
+/// `vap_pres` Partial pressure of water vapor in moist air in Psi  or Pa or atm
+pub fn get_rel_hum_from_vap_pres_with_policy<T: TemperatureUnit, PV: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    vap_pres: Pressure<PV>,
+    policy: SupersaturationPolicy,
+) -> Result<SaturationChecked<f64>, PsychroLibErr> {
+    let rel_hum = get_rel_hum_from_vap_pres(tdry_bulb, vap_pres)?;
+    apply_supersaturation_policy(rel_hum, policy)
+}
+
+/// Return humidity ratio given dry-bulb temperature, relative humidity, and pressure, applying
+/// `policy` when the relative humidity is above 1.
+/// Returns: Humidity Ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹ and whether the inputs were
+/// supersaturated
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// `pressure`  Atmospheric pressure in Psi  or Pa or atm
+pub fn get_hum_ratio_from_rel_hum_with_policy<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+    pres_ambient: Pressure<P>,
+    policy: SupersaturationPolicy,
+) -> Result<SaturationChecked<f64>, PsychroLibErr> {
+    let checked = apply_supersaturation_policy(rel_hum, policy)?;
+    let hum_ratio = get_hum_ratio_from_rel_hum(tdry_bulb, checked.value, pres_ambient)?;
+    Ok(SaturationChecked {
+        value: hum_ratio,
+        supersaturated: checked.supersaturated,
+    })
+}
+
+/// Return saturation vapor pressure given dry-bulb temperature, accepting temperatures over
+/// `range`. See `TemperatureRange` for the formulae and their accuracy.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5 & 6, IAPWS-IF97 (2007) eqn. 30
+/// Returns: Vapor Pressure of saturated air in Psi  or Pa  or atm
+/// `tdry_bulb` in Dry bulb temperature in °F  or °C  or K
+/// Errors with `Range` outside of `range`.
+pub fn get_sat_vap_pres_with_range<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    range: TemperatureRange,
+) -> Result<Pressure<P>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let sat_vap_pres = Pressure::<Pascal>::from(sat_vap_pres_in_range(tdc, range)?);
+    Ok(Pressure::<P>::from(&sat_vap_pres))
+}
+
+/// Return humidity ratio given dry-bulb temperature, relative humidity, and pressure, accepting
+/// temperatures over `range`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 12 & 20
+/// Returns: Humidity Ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Range` outside of `range` and when the vapor pressure reaches the ambient pressure.
+/// Results below the 1E-7 floor are raised to it silently.
+pub fn get_hum_ratio_from_rel_hum_with_range<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+    pres_ambient: Pressure<P>,
+    range: TemperatureRange,
+) -> Result<f64, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    if rel_hum < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    si::hum_ratio_from_vap_pres(
+        rel_hum * sat_vap_pres_in_range(tdc, range)?,
+        f64::from(&Pressure::<Pascal>::from(&pres_ambient)),
+    )
+}
+
+/// Return moist air enthalpy given dry-bulb temperature, relative humidity and pressure,
+/// accepting temperatures over `range`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 30
+/// Returns: Moist air enthalpy in J Kg_Air⁻¹ or kJ Kg_Air⁻¹ or Btu lb_Air⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `rel_hum` Relative humidity [0-1]
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors like `get_hum_ratio_from_rel_hum_with_range`.
+pub fn get_moist_air_enthalpy_from_rel_hum_with_range<
+    T: TemperatureUnit,
+    S: SpecificEnthalpyUnit,
+    P: PressureUnit,
+>(
+    tdry_bulb: Temperature<T>,
+    rel_hum: f64,
+    pres_ambient: Pressure<P>,
+    range: TemperatureRange,
+) -> Result<SpecificEnthalpy<S>, PsychroLibErr> {
+    let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+    let hum_ratio = get_hum_ratio_from_rel_hum_with_range(tdry_bulb, rel_hum, pres_ambient, range)?;
+    Ok(SpecificEnthalpy::<S>::from(&enthalpy_in_jpkg(
+        tdc, hum_ratio,
+    )))
+}
+
+/// Return saturated air enthalpy given dry-bulb temperature and pressure, accepting temperatures
+/// over `range`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30
+/// Returns: Saturated air enthalpy in J Kg_Air⁻¹ or kJ Kg_Air⁻¹ or Btu lb_Air⁻¹
+/// `tdry_bulb` Dry bulb temperature in °F  or °C or K
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors with `Range` outside of `range` and when water boils at this pressure.
+pub fn get_sat_air_enthalpy_with_range<
+    T: TemperatureUnit,
+    P: PressureUnit,
+    S: SpecificEnthalpyUnit,
+>(
+    tdry_bulb: Temperature<T>,
+    pres_ambient: Pressure<P>,
+    range: TemperatureRange,
+) -> Result<SpecificEnthalpy<S>, PsychroLibErr> {
+    get_moist_air_enthalpy_from_rel_hum_with_range(tdry_bulb, 1.0, pres_ambient, range)
+}
+
 mod tests {
-    use crate::units::{Atmosphere, Fahrenheit, Psi};
+    use crate::units::{Atmosphere, CubicFeetPerPound, Kelvin, PoundsPerCubicFoot, Rankine};
 
     use super::*;
 
@@ -196,6 +1311,517 @@ mod tests {
         assert_eq!(enthalpy_exp, enthalpy_calc);
     }
 
+    #[test]
+    fn get_dry_air_enthalpy_normal() {
+        use crate::units::KilojoulesPerKg;
+        let enthalpy: SpecificEnthalpy<KilojoulesPerKg> =
+            get_dry_air_enthalpy(Temperature::<Celcius>::from(20)).unwrap();
+        assert_eq!(enthalpy, SpecificEnthalpy::<KilojoulesPerKg>::from(20.12));
+        // Equal to the moist air enthalpy of bone dry air.
+        let moist: SpecificEnthalpy<JoulesPerKg> =
+            get_moist_air_enthalpy_from_hum_ratio(Temperature::<Celcius>::from(-20), 0.0).unwrap();
+        let dry: SpecificEnthalpy<JoulesPerKg> =
+            get_dry_air_enthalpy(Temperature::<Celcius>::from(-20)).unwrap();
+        assert!((f64::from(&moist) - f64::from(&dry)).abs() < 0.5);
+        assert!(matches!(
+            get_dry_air_enthalpy::<Celcius, JoulesPerKg>(Temperature::<Celcius>::from(250)),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_tdry_bulb_from_enthalpy_and_hum_ratio_normal() {
+        use crate::units::KilojoulesPerKg;
+        let tdry_bulb: Temperature<Celcius> = get_tdry_bulb_from_enthalpy_and_hum_ratio(
+            SpecificEnthalpy::<KilojoulesPerKg>::from(50.625),
+            0.01,
+        )
+        .unwrap();
+        assert_eq!(tdry_bulb, Temperature::<Celcius>::from(25));
+        let enthalpy: SpecificEnthalpy<BtuPerPound> =
+            get_moist_air_enthalpy_from_hum_ratio(Temperature::<Fahrenheit>::from(86), 0.012)
+                .unwrap();
+        let tdry_bulb: Temperature<Fahrenheit> =
+            get_tdry_bulb_from_enthalpy_and_hum_ratio(enthalpy, 0.012).unwrap();
+        assert!((f64::from(&tdry_bulb) - 86.0).abs() < 1E-2);
+        assert!(matches!(
+            get_tdry_bulb_from_enthalpy_and_hum_ratio::<JoulesPerKg, Celcius>(
+                SpecificEnthalpy::<JoulesPerKg>::from(50_000),
+                -0.01,
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_hum_ratio_from_enthalpy_and_tdry_bulb_normal() {
+        use crate::units::KilojoulesPerKg;
+        let hum_ratio = get_hum_ratio_from_enthalpy_and_tdry_bulb(
+            SpecificEnthalpy::<KilojoulesPerKg>::from(50.625),
+            Temperature::<Celcius>::from(25),
+        )
+        .unwrap();
+        assert!((hum_ratio - 0.01).abs() < 1E-6);
+        // Below the enthalpy of dry air the humidity ratio is clamped to the minimum.
+        let hum_ratio = get_hum_ratio_from_enthalpy_and_tdry_bulb(
+            SpecificEnthalpy::<KilojoulesPerKg>::from(20),
+            Temperature::<Celcius>::from(25),
+        )
+        .unwrap();
+        assert!((hum_ratio - 1E-7).abs() < 1E-12);
+        assert!(matches!(
+            get_hum_ratio_from_enthalpy_and_tdry_bulb(
+                SpecificEnthalpy::<KilojoulesPerKg>::from(20),
+                Temperature::<Celcius>::from(250),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_sat_air_enthalpy_normal() {
+        use crate::units::KilojoulesPerKg;
+        let enthalpy_calc: SpecificEnthalpy<KilojoulesPerKg> = get_sat_air_enthalpy(
+            Temperature::<Celcius>::from(25),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert_eq!(
+            enthalpy_calc,
+            SpecificEnthalpy::<KilojoulesPerKg>::from(76.306_66)
+        );
+    }
+
+    #[test]
+    fn get_sat_temp_from_pressure_normal() {
+        let tsat: Temperature<Celcius> =
+            get_sat_temp_from_pressure(Pressure::<Atmosphere>::from(1)).unwrap();
+        assert_eq!(tsat, Temperature::<Celcius>::from(99.974_1));
+        let tsat: Temperature<Celcius> =
+            get_sat_temp_from_pressure(Pressure::<Pascal>::from(100)).unwrap();
+        assert_eq!(tsat, Temperature::<Celcius>::from(-20.333_9));
+        let tsat: Result<Temperature<Celcius>, _> =
+            get_sat_temp_from_pressure(Pressure::<Pascal>::from(0));
+        assert!(matches!(tsat, Err(PsychroLibErr::Value)));
+    }
+
+    #[test]
+    fn get_tdew_point_from_vap_pres_normal() {
+        let tdew_point: Temperature<Celcius> = get_tdew_point_from_vap_pres(
+            Temperature::<Celcius>::from(18.826),
+            Pressure::<Pascal>::from(1180.5643),
+        )
+        .unwrap();
+        assert!((f64::from(&tdew_point) - 9.413).abs() < 2E-3);
+        // Frost point, below the triple point.
+        let tdew_point: Temperature<Celcius> = get_tdew_point_from_vap_pres(
+            Temperature::<Celcius>::from(5),
+            Pressure::<Pascal>::from(300),
+        )
+        .unwrap();
+        assert!((f64::from(&tdew_point) + 8.374).abs() < 2E-3);
+        // Supersaturated vapor is capped at the dry bulb temperature.
+        let tdew_point: Temperature<Fahrenheit> = get_tdew_point_from_vap_pres(
+            Temperature::<Fahrenheit>::from(50),
+            Pressure::<Psi>::from(0.5),
+        )
+        .unwrap();
+        assert_eq!(tdew_point, Temperature::<Fahrenheit>::from(50));
+        assert!(matches!(
+            get_tdew_point_from_vap_pres::<Celcius, Pascal, Celcius>(
+                Temperature::<Celcius>::from(20),
+                Pressure::<Pascal>::from(0),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_tdew_point_from_rel_hum_normal() {
+        let tdew_point: Temperature<Celcius> =
+            get_tdew_point_from_rel_hum(Temperature::<Celcius>::from(25), 0.5).unwrap();
+        assert!((f64::from(&tdew_point) - 13.864).abs() < 2E-3);
+        let tdew_point: Temperature<Celcius> =
+            get_tdew_point_from_rel_hum(Temperature::<Celcius>::from(-5), 0.8).unwrap();
+        assert!((f64::from(&tdew_point) + 7.585).abs() < 2E-3);
+        let tdew_point: Temperature<Kelvin> =
+            get_tdew_point_from_rel_hum(Temperature::<Kelvin>::from(300), 1.0).unwrap();
+        assert!((f64::from(&tdew_point) - 300.0).abs() < 2E-3);
+        for rel_hum in [0.0, -0.1, 1.1] {
+            assert!(matches!(
+                get_tdew_point_from_rel_hum::<Celcius, Celcius>(
+                    Temperature::<Celcius>::from(25),
+                    rel_hum
+                ),
+                Err(PsychroLibErr::Range)
+            ));
+        }
+    }
+
+    #[test]
+    fn get_tdew_point_from_hum_ratio_normal() {
+        let hum_ratio = get_hum_ratio_from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            0.5,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        let tdew_point: Temperature<Celcius> =
+            get_tdew_point_from_hum_ratio(hum_ratio, Pressure::<Pascal>::from(101_325)).unwrap();
+        assert!((f64::from(&tdew_point) - 13.864).abs() < 2E-3);
+        // Same moisture content at 8 bar absolute has a higher dew point.
+        let tdew_point: Temperature<Celcius> =
+            get_tdew_point_from_hum_ratio(hum_ratio, Pressure::<Pascal>::from(800_000)).unwrap();
+        assert!(f64::from(&tdew_point) > 40.0);
+        assert!(matches!(
+            get_tdew_point_from_hum_ratio::<Pascal, Celcius>(
+                -0.01,
+                Pressure::<Pascal>::from(101_325)
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_tdew_point_from_hum_ratio::<Pascal, Celcius>(0.01, Pressure::<Pascal>::from(0)),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn get_twet_bulb_from_tdew_point_normal() {
+        let twet_bulb: Temperature<Celcius> = get_twet_bulb_from_tdew_point(
+            Temperature::<Celcius>::from(25),
+            Temperature::<Celcius>::from(15),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((f64::from(&twet_bulb) - 18.504).abs() < 2E-3);
+        let twet_bulb: Temperature<Celcius> = get_twet_bulb_from_tdew_point(
+            Temperature::<Celcius>::from(-5),
+            Temperature::<Celcius>::from(-10),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((f64::from(&twet_bulb) + 6.576).abs() < 2E-3);
+        let twet_bulb: Temperature<Celcius> = get_twet_bulb_from_tdew_point(
+            Temperature::<Celcius>::from(30),
+            Temperature::<Celcius>::from(20),
+            Pressure::<Pascal>::from(80_000),
+        )
+        .unwrap();
+        assert!((f64::from(&twet_bulb) - 22.485).abs() < 2E-3);
+        assert!(matches!(
+            get_twet_bulb_from_tdew_point::<Celcius, Celcius, Pascal, Celcius>(
+                Temperature::<Celcius>::from(20),
+                Temperature::<Celcius>::from(21),
+                Pressure::<Pascal>::from(101_325),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_rel_hum_from_tdew_point_normal() {
+        let rel_hum = get_rel_hum_from_tdew_point(
+            Temperature::<Celcius>::from(25),
+            Temperature::<Celcius>::from(15),
+        )
+        .unwrap();
+        assert!((rel_hum - 0.538_13).abs() < 1E-4);
+        let rel_hum = get_rel_hum_from_tdew_point(
+            Temperature::<Celcius>::from(-5),
+            Temperature::<Celcius>::from(-10),
+        )
+        .unwrap();
+        assert!((rel_hum - 0.646_90).abs() < 1E-4);
+        // Frost point below the triple point with the dry bulb above it.
+        let rel_hum = get_rel_hum_from_tdew_point(
+            Temperature::<Celcius>::from(3),
+            Temperature::<Celcius>::from(-2),
+        )
+        .unwrap();
+        assert!((rel_hum - 0.682_98).abs() < 1E-4);
+        let rel_hum = get_rel_hum_from_tdew_point(
+            Temperature::<Fahrenheit>::from(68),
+            Temperature::<Fahrenheit>::from(68),
+        )
+        .unwrap();
+        assert!((rel_hum - 1.0).abs() < 1E-9);
+        assert!(matches!(
+            get_rel_hum_from_tdew_point(
+                Temperature::<Celcius>::from(20),
+                Temperature::<Celcius>::from(21),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_vapor_pressure_deficit_normal() {
+        let pres_ambient = || Pressure::<Pascal>::from(101_325);
+        let hum_ratio =
+            get_hum_ratio_from_rel_hum(Temperature::<Celcius>::from(25), 0.5, pres_ambient())
+                .unwrap();
+        let vpd: Pressure<Pascal> =
+            get_vapor_pressure_deficit(Temperature::<Celcius>::from(25), hum_ratio, pres_ambient())
+                .unwrap();
+        assert!((f64::from(&vpd) - 1584.6).abs() < 0.1);
+        let sat_hum_ratio =
+            get_sat_hum_ratio(Temperature::<Celcius>::from(25), pres_ambient()).unwrap();
+        let vpd: Pressure<Pascal> = get_vapor_pressure_deficit(
+            Temperature::<Celcius>::from(25),
+            sat_hum_ratio,
+            pres_ambient(),
+        )
+        .unwrap();
+        assert!(f64::from(&vpd).abs() < 0.01);
+        assert!(matches!(
+            get_vapor_pressure_deficit::<Celcius, Pascal, Pascal>(
+                Temperature::<Celcius>::from(25),
+                0.03,
+                pres_ambient(),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_vapor_pressure_deficit_from_rel_hum_normal() {
+        let pres_ambient = || Pressure::<Pascal>::from(101_325);
+        let vpd: Pressure<Pascal> = get_vapor_pressure_deficit_from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            0.5,
+            pres_ambient(),
+        )
+        .unwrap();
+        assert!((f64::from(&vpd) - 1584.6).abs() < 0.1);
+        let vpd: Pressure<Pascal> = get_vapor_pressure_deficit_from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            1.0,
+            pres_ambient(),
+        )
+        .unwrap();
+        assert!(f64::from(&vpd).abs() < f64::EPSILON);
+        assert!(matches!(
+            get_vapor_pressure_deficit_from_rel_hum::<Celcius, Pascal, Pascal>(
+                Temperature::<Celcius>::from(25),
+                1.1,
+                pres_ambient(),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn pressure_dew_point() {
+        // Air from a refrigerated dryer at 8 bar absolute.
+        let tdew_point: Temperature<Celcius> = get_atmospheric_dew_point(
+            Temperature::<Celcius>::from(3),
+            Pressure::<Pascal>::from(800_000),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((f64::from(&tdew_point) + 20.756).abs() < 2E-3);
+        let tdew_point_line: Temperature<Celcius> = get_pressure_dew_point(
+            tdew_point,
+            Pressure::<Pascal>::from(101_325),
+            Pressure::<Pascal>::from(800_000),
+        )
+        .unwrap();
+        assert!((f64::from(&tdew_point_line) - 3.0).abs() < 2E-3);
+        // Desiccant dryer class 2 of ISO 8573-1, -40 °C pressure dew point.
+        let tdew_point: Temperature<Celcius> = get_atmospheric_dew_point(
+            Temperature::<Celcius>::from(-40),
+            Pressure::<Pascal>::from(800_000),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!(f64::from(&tdew_point) < -55.0);
+        assert!(matches!(
+            get_pressure_dew_point::<Celcius, Pascal, Celcius>(
+                Temperature::<Celcius>::from(20),
+                Pressure::<Pascal>::from(101_325),
+                Pressure::<Pascal>::from(0),
+            ),
+            Err(PsychroLibErr::Value)
+        ));
+        assert!(matches!(
+            get_pressure_dew_point::<Celcius, Pascal, Celcius>(
+                Temperature::<Celcius>::from(150),
+                Pressure::<Pascal>::from(101_325),
+                Pressure::<Pascal>::from(800_000),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_sat_hum_ratio_normal() {
+        let sat_hum_ratio = get_sat_hum_ratio(
+            Temperature::<Celcius>::from(25),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((sat_hum_ratio - 0.020_081).abs() < 1E-6);
+        let sat_hum_ratio = get_sat_hum_ratio(
+            Temperature::<Celcius>::from(-10),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        let sat_hum_ratio_ice = get_sat_hum_ratio_ice(
+            Temperature::<Celcius>::from(-10),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((sat_hum_ratio - sat_hum_ratio_ice).abs() < 1E-12);
+        assert!(matches!(
+            get_sat_hum_ratio(
+                Temperature::<Celcius>::from(110),
+                Pressure::<Pascal>::from(101_325)
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_degree_of_saturation_normal() {
+        let pres_ambient = || Pressure::<Pascal>::from(101_325);
+        let hum_ratio =
+            get_hum_ratio_from_rel_hum(Temperature::<Celcius>::from(25), 0.5, pres_ambient())
+                .unwrap();
+        let degree_of_saturation =
+            get_degree_of_saturation(Temperature::<Celcius>::from(25), hum_ratio, pres_ambient())
+                .unwrap();
+        // Slightly below the relative humidity, as the vapor also displaces dry air.
+        assert!((degree_of_saturation - 0.492_06).abs() < 1E-4);
+        assert!(matches!(
+            get_degree_of_saturation(Temperature::<Celcius>::from(25), -0.01, pres_ambient()),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_sat_hum_ratio_ice_normal() {
+        let pres_ambient = Pressure::<Pascal>::from(101_325);
+        let hum_ratio = get_sat_hum_ratio_ice(Temperature::<Celcius>::from(-10), pres_ambient);
+        assert!((hum_ratio.unwrap() - 0.001_599_4).abs() < 1E-7);
+        let pres_ambient = Pressure::<Pascal>::from(101_325);
+        let hum_ratio = get_sat_hum_ratio_ice(Temperature::<Celcius>::from(5), pres_ambient);
+        assert!(matches!(hum_ratio, Err(PsychroLibErr::Range)));
+    }
+
+    #[test]
+    fn get_moist_air_entropy_normal() {
+        use crate::units::KilojoulesPerKgKelvin;
+        let entropy_calc: SpecificEntropy<KilojoulesPerKgKelvin> = get_moist_air_entropy(
+            Temperature::<Celcius>::from(0),
+            0.003_789,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert_eq!(
+            entropy_calc,
+            SpecificEntropy::<KilojoulesPerKgKelvin>::from(0.036_43)
+        );
+    }
+
+    #[test]
+    fn compressibility() {
+        // Dry air at 300 K has Z = 0.9974 at 1 MPa and Z = 0.9950 at 2 MPa.
+        // Lemmon et al. (2000), J. Phys. Chem. Ref. Data 29(3), equation of state for air
+        let tdry_bulb = || Temperature::<Kelvin>::from(300);
+        for (pres, z_ref) in [(1E6, 0.9974), (2E6, 0.9950)] {
+            let z = get_compressibility_factor(tdry_bulb(), 0.0, Pressure::<Pascal>::from(pres))
+                .unwrap();
+            assert!((z / z_ref - 1.0).abs() < 1E-3);
+            let real: Density<KgPerCubicMeter> = get_moist_air_density_with_gas_model(
+                tdry_bulb(),
+                0.0,
+                Pressure::<Pascal>::from(pres),
+                GasModel::Virial,
+            )
+            .unwrap();
+            let density_ref = pres * 0.028_964_5 / (z_ref * 8.314_462 * 300.0);
+            assert!((f64::from(&real) / density_ref - 1.0).abs() < 1E-3);
+        }
+        let z = get_compressibility_factor(tdry_bulb(), 0.01, Pressure::<Pascal>::from(101_325))
+            .unwrap();
+        assert!(z < 1.0 && z > 0.999);
+        let ideal: Density<KgPerCubicMeter> = get_moist_air_density_with_gas_model(
+            tdry_bulb(),
+            0.0,
+            Pressure::<Pascal>::from(1E6),
+            GasModel::Ideal,
+        )
+        .unwrap();
+        assert!((f64::from(&ideal) - 11.613).abs() < 0.01);
+    }
+
+    #[test]
+    fn dry_air_density_and_volume() {
+        use crate::units::CubicFeetPerPound;
+        // 20 °C row of ASHRAE Handbook - Fundamentals (2017) ch. 1 table 2.
+        let volume: SpecificVolume<CubicMetersPerKg> = get_dry_air_volume(
+            Temperature::<Celcius>::from(20),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((f64::from(&volume) - 0.8301).abs() < 5E-4);
+        let density: Density<KgPerCubicMeter> = get_dry_air_density(
+            Temperature::<Celcius>::from(20),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((f64::from(&density) * f64::from(&volume) - 1.0).abs() < 1E-6);
+        let volume: SpecificVolume<CubicFeetPerPound> = get_dry_air_volume(
+            Temperature::<Fahrenheit>::from(68),
+            Pressure::<Psi>::from(14.696),
+        )
+        .unwrap();
+        assert!((f64::from(&volume) - 13.30).abs() < 0.01);
+        assert!(matches!(
+            get_dry_air_density::<Celcius, Pascal, KgPerCubicMeter>(
+                Temperature::<Celcius>::from(20),
+                Pressure::<Pascal>::from(0),
+            ),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn moist_air_gas_properties() {
+        let gas_constant: SpecificHeat<JoulesPerKgKelvin> =
+            get_moist_air_gas_constant(0.0).unwrap();
+        assert_eq!(
+            gas_constant,
+            SpecificHeat::<JoulesPerKgKelvin>::from(287.042)
+        );
+        let gas_constant: SpecificHeat<JoulesPerKgKelvin> =
+            get_moist_air_gas_constant(0.01).unwrap();
+        assert!((f64::from(&gas_constant) - 288.770).abs() < 1E-3);
+        let molecular_weight = get_moist_air_molecular_weight(0.01).unwrap();
+        assert!((molecular_weight - 28.792).abs() < 1E-3);
+        // Both describe the same mixture.
+        let universal = f64::from(&gas_constant) * molecular_weight;
+        assert!((universal - 8314.3).abs() < 1.0);
+        assert!(matches!(
+            get_moist_air_molecular_weight(-0.01),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_humid_heat_normal() {
+        use crate::units::BtuPerPoundRankine;
+        let humid_heat: SpecificHeat<JoulesPerKgKelvin> = get_humid_heat(0.01).unwrap();
+        assert_eq!(humid_heat, SpecificHeat::<JoulesPerKgKelvin>::from(1024.6));
+        let humid_heat: SpecificHeat<BtuPerPoundRankine> = get_humid_heat(0.01).unwrap();
+        assert_eq!(humid_heat, SpecificHeat::<JoulesPerKgKelvin>::from(1024.6));
+        assert!(matches!(
+            get_humid_heat::<JoulesPerKgKelvin>(-0.01),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
     #[test]
     fn get_vap_pres_from_hum_ratio_normal() {
         let hum_ratio = 0.005;
@@ -231,4 +1857,405 @@ mod tests {
         let hum_ratio = get_hum_ratio_from_rel_hum(tdry_bulb, rel_hum, pres_ambient).unwrap();
         assert!((hum_ratio - 0.0065).abs() < 0.0001);
     }
+
+    #[test]
+    fn standard_atmosphere() {
+        use crate::units::Foot;
+        let pres: Pressure<Pascal> =
+            get_standard_atm_pressure(Length::<Meter>::from(1500)).unwrap();
+        assert_eq!(pres, Pressure::<Pascal>::from(84_555.932));
+        let pres: Pressure<Pascal> =
+            get_standard_atm_pressure(Length::<Meter>::from(-500)).unwrap();
+        assert_eq!(pres, Pressure::<Pascal>::from(107_477.537));
+        let tdry_bulb: Temperature<Celcius> =
+            get_standard_atm_temperature(Length::<Foot>::from(5000)).unwrap();
+        assert_eq!(tdry_bulb, Temperature::<Celcius>::from(5.094));
+    }
+
+    #[test]
+    fn specific_hum_round_trip() {
+        let specific_hum = get_specific_hum_from_hum_ratio(0.006698).unwrap();
+        assert!((specific_hum - 0.006653).abs() < 1E-6);
+        let hum_ratio = get_hum_ratio_from_specific_hum(specific_hum).unwrap();
+        assert!((hum_ratio - 0.006698).abs() < 1E-9);
+        assert!(matches!(
+            get_hum_ratio_from_specific_hum(1.0),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn absolute_hum() {
+        use crate::units::GramsPerCubicMeter;
+        let absolute_hum: Density<GramsPerCubicMeter> =
+            get_absolute_hum_from_rel_hum(Temperature::<Celcius>::from(25), 0.5).unwrap();
+        assert!((f64::from(&absolute_hum) - 11.516).abs() < 1E-3);
+        let vap_pres: Pressure<Pascal> = get_vap_pres_from_absolute_hum(
+            Temperature::<Celcius>::from(25),
+            Density::<GramsPerCubicMeter>::from(&absolute_hum),
+        )
+        .unwrap();
+        assert!((f64::from(&vap_pres) - 1584.6).abs() < 0.1);
+        let rel_hum =
+            get_rel_hum_from_absolute_hum(Temperature::<Celcius>::from(25), absolute_hum).unwrap();
+        assert!((rel_hum - 0.5).abs() < 1E-4);
+        let absolute_hum: Density<KgPerCubicMeter> =
+            get_absolute_hum_from_vap_pres(Temperature::<Celcius>::from(25), vap_pres).unwrap();
+        assert!((f64::from(&absolute_hum) - 0.011_516).abs() < 1E-6);
+        assert!(matches!(
+            get_absolute_hum_from_rel_hum::<Celcius, GramsPerCubicMeter>(
+                Temperature::<Celcius>::from(25),
+                -0.1
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_vap_pres_from_absolute_hum::<Celcius, GramsPerCubicMeter, Pascal>(
+                Temperature::<Celcius>::from(25),
+                Density::<GramsPerCubicMeter>::from(-1),
+            ),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn trace_moisture() {
+        // 1 ppmv of water in air is 0.622 ppmw, the ratio of the molecular weights.
+        let hum_ratio = get_hum_ratio_from_ppmv(1.0).unwrap();
+        assert!((hum_ratio - 6.219_45E-7).abs() < 1E-12);
+        assert!((get_ppmv_from_hum_ratio(hum_ratio).unwrap() - 1.0).abs() < 1E-9);
+        assert!((get_ppmw_from_hum_ratio(hum_ratio).unwrap() - 0.621_945).abs() < 1E-6);
+        let ppmw = get_ppmw_from_hum_ratio(0.01).unwrap();
+        assert!((get_hum_ratio_from_ppmw(ppmw).unwrap() - 0.01).abs() < 1E-12);
+        // Traces below the humidity ratio floor convert exactly.
+        let hum_ratio = get_hum_ratio_from_ppmv(0.05).unwrap();
+        assert!(hum_ratio < si::MIN_HUM_RATIO);
+        // 10 ppmv at 8 bar absolute.
+        let vap_pres: Pressure<Pascal> =
+            get_vap_pres_from_ppmv(10.0, Pressure::<Pascal>::from(800_000)).unwrap();
+        assert_eq!(vap_pres, Pressure::<Pascal>::from(8));
+        let ppmv = get_ppmv_from_vap_pres(vap_pres, Pressure::<Pascal>::from(800_000)).unwrap();
+        assert!((ppmv - 10.0).abs() < 1E-9);
+        assert!(matches!(
+            get_hum_ratio_from_ppmv(-1.0),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_hum_ratio_from_ppmw(1E6),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_vap_pres_from_ppmv::<Pascal, Pascal>(10.0, Pressure::<Pascal>::from(0)),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn get_hum_ratio_from_vap_pres_invalid_pressures() {
+        let pres_ambient = Pressure::<Atmosphere>::from(1);
+        let vap_pres = Pressure::<Pascal>::from(101_325);
+        assert!(matches!(
+            get_hum_ratio_from_vap_pres(vap_pres, pres_ambient),
+            Err(PsychroLibErr::Range)
+        ));
+        let pres_ambient = Pressure::<Pascal>::from(0);
+        let vap_pres = Pressure::<Pascal>::from(1000);
+        assert!(matches!(
+            get_hum_ratio_from_vap_pres(vap_pres, pres_ambient),
+            Err(PsychroLibErr::Value)
+        ));
+        let pres_ambient = Pressure::<Pascal>::from(-100);
+        assert!(matches!(
+            get_vap_pres_from_hum_ratio::<Pascal, Pascal>(0.01, pres_ambient),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn get_hum_ratio_from_rel_hum_vacuum() {
+        // Saturation pressure at 90 °C is above 0.5 atm so no humidity ratio exists.
+        let tdry_bulb = Temperature::<Celcius>::from(90);
+        let pres_ambient = Pressure::<Atmosphere>::from(0.5);
+        assert!(matches!(
+            get_hum_ratio_from_rel_hum(tdry_bulb, 1.0, pres_ambient),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn low_pressure() {
+        // Freeze dryer at 10 Pa, ice at -30 °C and 38 Pa is above saturation.
+        let pres_ambient = Pressure::<Pascal>::from(10);
+        let hum_ratio =
+            get_hum_ratio_from_vap_pres(Pressure::<Pascal>::from(5), pres_ambient).unwrap();
+        assert!((hum_ratio - 0.621_945).abs() < 1E-6);
+        let vap_pres: Pressure<Pascal> =
+            get_vap_pres_from_hum_ratio(hum_ratio, Pressure::<Pascal>::from(10)).unwrap();
+        assert_eq!(vap_pres, Pressure::<Pascal>::from(5));
+        assert!(matches!(
+            get_hum_ratio_from_rel_hum(
+                Temperature::<Celcius>::from(-30),
+                1.0,
+                Pressure::<Pascal>::from(10)
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+        // Nearly pure vapor has no meaningful humidity ratio.
+        assert!(matches!(
+            get_hum_ratio_from_vap_pres(
+                Pressure::<Pascal>::from(9.95),
+                Pressure::<Pascal>::from(10)
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_vap_pres_from_hum_ratio::<Pascal, Pascal>(100.0, Pressure::<Pascal>::from(10)),
+            Err(PsychroLibErr::Range)
+        ));
+        // Below the 1 Pa limit.
+        assert!(matches!(
+            get_hum_ratio_from_vap_pres(
+                Pressure::<Pascal>::from(0.1),
+                Pressure::<Pascal>::from(0.5)
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_rel_hum_from_vap_pres_supersaturated() {
+        let vap_pres = || Pressure::<Pascal>::from(3500);
+        let tdry_bulb = || Temperature::<Celcius>::from(23.525);
+        assert!(matches!(
+            get_rel_hum_from_vap_pres_with_policy(
+                tdry_bulb(),
+                vap_pres(),
+                SupersaturationPolicy::Error
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+        let clamped = get_rel_hum_from_vap_pres_with_policy(
+            tdry_bulb(),
+            vap_pres(),
+            SupersaturationPolicy::Clamp,
+        )
+        .unwrap();
+        assert!(clamped.supersaturated);
+        assert!((clamped.value - 1.0).abs() < f64::EPSILON);
+        let allowed = get_rel_hum_from_vap_pres_with_policy(
+            tdry_bulb(),
+            vap_pres(),
+            SupersaturationPolicy::Allow,
+        )
+        .unwrap();
+        assert!(allowed.supersaturated);
+        assert!((allowed.value - 3500.0 / 2901.087).abs() < 0.0001);
+    }
+
+    #[test]
+    fn get_hum_ratio_from_rel_hum_clamped() {
+        let tdry_bulb = Temperature::<Fahrenheit>::from(86);
+        let pres_ambient = Pressure::<Psi>::from(14.6959);
+        let sat = get_hum_ratio_from_rel_hum(
+            Temperature::<Fahrenheit>::from(86),
+            1.0,
+            Pressure::<Psi>::from(14.6959),
+        )
+        .unwrap();
+        let clamped = get_hum_ratio_from_rel_hum_with_policy(
+            tdry_bulb,
+            1.2,
+            pres_ambient,
+            SupersaturationPolicy::Clamp,
+        )
+        .unwrap();
+        assert!(clamped.supersaturated);
+        assert!((clamped.value - sat).abs() < 1E-9);
+    }
+
+    #[test]
+    fn get_sat_vap_pres_out_of_range() {
+        let tdry_bulb = Temperature::<Celcius>::from(-300);
+        let sat_pres: Result<Pressure<Pascal>, _> = get_sat_vap_pres(tdry_bulb);
+        assert!(matches!(sat_pres, Err(PsychroLibErr::Range)));
+        let tdry_bulb = Temperature::<Fahrenheit>::from(400);
+        let sat_pres: Result<Pressure<Psi>, _> =
+            get_sat_vap_pres_with_coefficients(tdry_bulb, Coefficients::Ip);
+        assert!(matches!(sat_pres, Err(PsychroLibErr::Range)));
+    }
+
+    #[test]
+    fn extended_range() {
+        use crate::units::KilojoulesPerKg;
+        // IAPWS-IF97 (2007) table 35 verification value at 500 K.
+        let sat_vap_pres: Pressure<Pascal> = get_sat_vap_pres_with_range(
+            Temperature::<Kelvin>::from(500),
+            TemperatureRange::Extended,
+        )
+        .unwrap();
+        assert!((f64::from(sat_vap_pres) - 2_638_897.76).abs() < 1.0);
+        // Both formulae agree where they meet.
+        let below: Pressure<Pascal> = get_sat_vap_pres_with_range(
+            Temperature::<Celcius>::from(100),
+            TemperatureRange::Extended,
+        )
+        .unwrap();
+        let above: Pressure<Pascal> = get_sat_vap_pres_with_range(
+            Temperature::<Celcius>::from(100.001),
+            TemperatureRange::Extended,
+        )
+        .unwrap();
+        assert!((f64::from(above) - f64::from(below)).abs() < 5.0);
+        // Standard matches `get_sat_vap_pres`.
+        let standard: Pressure<Pascal> = get_sat_vap_pres_with_range(
+            Temperature::<Celcius>::from(150),
+            TemperatureRange::Standard,
+        )
+        .unwrap();
+        assert_eq!(
+            standard,
+            get_sat_vap_pres::<Celcius, Pascal>(Temperature::<Celcius>::from(150)).unwrap()
+        );
+
+        // Dryer exhaust at 250 °C and 1 % relative humidity.
+        let tdry_bulb = Temperature::<Celcius>::from(250);
+        let pres_ambient = Pressure::<Pascal>::from(101_325);
+        let hum_ratio = get_hum_ratio_from_rel_hum_with_range(
+            Temperature::<Celcius>::from(&tdry_bulb),
+            0.01,
+            Pressure::<Pascal>::from(&pres_ambient),
+            TemperatureRange::Extended,
+        )
+        .unwrap();
+        assert!((hum_ratio - 0.4019).abs() < 1E-3);
+        let enthalpy: SpecificEnthalpy<KilojoulesPerKg> =
+            get_moist_air_enthalpy_from_rel_hum_with_range(
+                Temperature::<Celcius>::from(&tdry_bulb),
+                0.01,
+                Pressure::<Pascal>::from(&pres_ambient),
+                TemperatureRange::Extended,
+            )
+            .unwrap();
+        assert!((f64::from(enthalpy) - 1_443.6).abs() < 1.0);
+
+        assert!(matches!(
+            get_hum_ratio_from_rel_hum_with_range(
+                Temperature::<Celcius>::from(&tdry_bulb),
+                0.01,
+                Pressure::<Pascal>::from(&pres_ambient),
+                TemperatureRange::Standard,
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_sat_air_enthalpy_with_range::<Celcius, Pascal, KilojoulesPerKg>(
+                tdry_bulb,
+                pres_ambient,
+                TemperatureRange::Extended,
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+        assert!(matches!(
+            get_sat_vap_pres_with_range::<Celcius, Pascal>(
+                Temperature::<Celcius>::from(310),
+                TemperatureRange::Extended,
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn get_sat_vap_pres_ip() {
+        let tdry_bulb = || Temperature::<Fahrenheit>::from(73.0);
+        let sat_pres_exp = Pressure::<Psi>::from(0.402169);
+        let sat_pres_si: Pressure<Psi> = get_sat_vap_pres(tdry_bulb()).unwrap();
+        let sat_pres_ip: Pressure<Psi> =
+            get_sat_vap_pres_with_coefficients(tdry_bulb(), Coefficients::Ip).unwrap();
+        assert_eq!(sat_pres_exp, sat_pres_si);
+        assert_eq!(sat_pres_exp, sat_pres_ip);
+        let tdry_bulb = Temperature::<Rankine>::from(455.67);
+        let sat_pres_exp = Pressure::<Psi>::from(0.014_976_6);
+        let sat_pres_ip: Pressure<Psi> =
+            get_sat_vap_pres_with_coefficients(tdry_bulb, Coefficients::Ip).unwrap();
+        assert_eq!(sat_pres_exp, sat_pres_ip);
+    }
+
+    #[test]
+    fn get_moist_air_enthalpy_ip() {
+        let tdry_bulb = Temperature::<Fahrenheit>::from(86);
+        let enthalpy_exp = SpecificEnthalpy::<BtuPerPound>::from(31.63184);
+        let enthalpy_calc: SpecificEnthalpy<BtuPerPound> =
+            get_moist_air_enthalpy_from_hum_ratio_with_coefficients(
+                tdry_bulb,
+                0.01,
+                Coefficients::Ip,
+            )
+            .unwrap();
+        assert_eq!(enthalpy_exp, enthalpy_calc);
+    }
+
+    #[test]
+    fn ip_units_in_and_out() {
+        let tdry_bulb = || Temperature::<Fahrenheit>::from(86);
+        let pres_ambient = || Pressure::<Psi>::from(14.695_95);
+        let hum_ratio = get_hum_ratio_from_rel_hum(tdry_bulb(), 0.25, pres_ambient()).unwrap();
+        assert!((hum_ratio - 0.006_584_6).abs() < 1E-6);
+        let vap_pres: Pressure<Psi> =
+            get_vap_pres_from_hum_ratio(hum_ratio, pres_ambient()).unwrap();
+        let rel_hum = get_rel_hum_from_vap_pres(tdry_bulb(), vap_pres).unwrap();
+        assert!((rel_hum - 0.25).abs() < 1E-5);
+        let sp_enthalpy: SpecificEnthalpy<BtuPerPound> =
+            get_moist_air_enthalpy_from_rel_hum(tdry_bulb(), 0.25, pres_ambient()).unwrap();
+        assert_eq!(sp_enthalpy, SpecificEnthalpy::<JoulesPerKg>::from(47015.61));
+        assert!((f64::from(sp_enthalpy) - 20.213_07).abs() < 1E-4);
+    }
+
+    #[test]
+    fn typed_functions_in_ip_units() {
+        let tdry_bulb = || Temperature::<Fahrenheit>::from(86);
+        let pres_ambient = || Pressure::<Psi>::from(14.695_95);
+        let hum_ratio = 0.006_584_6;
+        let tdew_point: Temperature<Fahrenheit> =
+            get_tdew_point_from_hum_ratio(hum_ratio, pres_ambient()).unwrap();
+        assert!((f64::from(&tdew_point) - 46.119_4).abs() < 1E-3);
+        let twet_bulb: Temperature<Fahrenheit> =
+            get_twet_bulb_from_tdew_point(tdry_bulb(), tdew_point, pres_ambient()).unwrap();
+        assert!((f64::from(&twet_bulb) - 62.350_7).abs() < 1E-3);
+        let sat_hum_ratio = get_sat_hum_ratio(tdry_bulb(), pres_ambient()).unwrap();
+        assert!((sat_hum_ratio - 0.027_202_6).abs() < 1E-6);
+        let sat_air_enthalpy: SpecificEnthalpy<BtuPerPound> =
+            get_sat_air_enthalpy(tdry_bulb(), pres_ambient()).unwrap();
+        assert!((f64::from(sat_air_enthalpy) - 42.876_8).abs() < 1E-3);
+        let density: Density<PoundsPerCubicFoot> =
+            get_dry_air_density(tdry_bulb(), pres_ambient()).unwrap();
+        assert!((f64::from(&density) - 0.072_693).abs() < 1E-5);
+        let volume: SpecificVolume<CubicFeetPerPound> =
+            get_dry_air_volume(tdry_bulb(), pres_ambient()).unwrap();
+        assert!((f64::from(&volume) - 13.756_5).abs() < 1E-3);
+    }
+
+    #[test]
+    fn get_tadiabatic_saturation_agrees_with_twet_bulb() {
+        let pres_ambient = || Pressure::<Pascal>::from(101_325);
+        // Within the solver tolerance above freezing, a few thousandths of a kelvin below.
+        for (tdry_bulb, hum_ratio, tolerance) in [
+            (25.0, 0.01, si::TOLERANCE),
+            (40.0, 0.0065, si::TOLERANCE),
+            (-5.0, 0.001, 0.005),
+        ] {
+            let tsat: Temperature<Celcius> = get_tadiabatic_saturation(
+                Temperature::<Celcius>::from(tdry_bulb),
+                hum_ratio,
+                pres_ambient(),
+            )
+            .unwrap();
+            let twet_bulb = si::twet_bulb_from_hum_ratio(tdry_bulb, hum_ratio, 101_325.0).unwrap();
+            assert!((f64::from(tsat) - twet_bulb).abs() < tolerance);
+        }
+        // Example 1 of ASHRAE Handbook - Fundamentals (2017) ch. 1
+        let tsat: Temperature<Celcius> =
+            get_tadiabatic_saturation(Temperature::<Celcius>::from(40), 0.0065, pres_ambient())
+                .unwrap();
+        assert!((f64::from(tsat) - 20.0).abs() < 0.1);
+    }
 }
@@ -0,0 +1,305 @@
+//! Plain `f64` versions of the PsychroLib formulas in SI units. Temperatures are in °C,
+//! pressures in Pa, humidity ratios in kg_H₂O kg_Air⁻¹ and enthalpies in J kg_Air⁻¹.
+//! Iterative solvers work on these so the resolution of the typed quantities doesn't
+//! interfere with convergence.
+use super::PsychroLibErr;
+
+/// Event for one step of an iterative solver, at trace level with the `tracing` feature and
+/// compiled out without it.
+macro_rules! trace_iteration {
+    ($($field:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($field)*);
+    };
+}
+pub(crate) use trace_iteration;
+
+mod solvers;
+pub(crate) use solvers::{
+    hum_ratio_from_twet_bulb, sat_temp_from_vap_pres, tadiabatic_saturation_from_hum_ratio,
+    tdew_point_from_hum_ratio, tdew_point_from_vap_pres, twet_bulb_from_hum_ratio,
+};
+
+/// Minimum acceptable humidity ratio used/returned by any functions.
+pub(crate) const MIN_HUM_RATIO: f64 = 1E-7;
+/// Lowest ambient pressure in Pa, that of the secondary drying stage of a freeze dryer.
+pub(crate) const MIN_PRES_AMBIENT: f64 = 1.0;
+/// Largest vapor pressure accepted for humidity ratios, as a fraction of the ambient pressure.
+/// Closer to pure vapor the dry air is a trace and the humidity ratio grows without bound.
+pub(crate) const MAX_VAP_PRES_FRACTION: f64 = 0.99;
+/// Humidity ratio at `MAX_VAP_PRES_FRACTION`, about 61.6 kg_H₂O kg_Air⁻¹.
+pub(crate) const MAX_HUM_RATIO: f64 =
+    MOLECULAR_WEIGHT_RATIO * MAX_VAP_PRES_FRACTION / (1.0 - MAX_VAP_PRES_FRACTION);
+/// Convergence tolerance of the iterative solvers in °C.
+pub(crate) const TOLERANCE: f64 = 0.001;
+/// Maximum number of iterations before giving up.
+pub(crate) const MAX_ITER_COUNT: usize = 100;
+/// Triple point of water in °C.
+pub(crate) const TRIPLE_POINT_WATER_C: f64 = 0.01;
+/// Freezing point of water in °C.
+pub(crate) const FREEZING_POINT_WATER_C: f64 = 0.0;
+/// Zero degree Celcius in K.
+pub(crate) const ZERO_CELCIUS_K: f64 = 273.15;
+/// Universal gas constant for dry air in J kg_Air⁻¹ K⁻¹.
+pub(crate) const R_DA: f64 = 287.042;
+/// Ratio of the molecular weights of water vapor and dry air.
+pub(crate) const MOLECULAR_WEIGHT_RATIO: f64 = 0.621945;
+/// Universal gas constant in J mol⁻¹ K⁻¹.
+pub(crate) const R_UNIVERSAL: f64 = 8.314_472;
+/// Molecular weight of dry air in kg kmol⁻¹.
+pub(crate) const MOLECULAR_WEIGHT_DA: f64 = 28.966;
+/// Specific heat of dry air in J kg_Air⁻¹ K⁻¹, as used by the enthalpy formula.
+pub(crate) const CP_DA: f64 = 1006.0;
+/// Specific heat of liquid water in J kg⁻¹ K⁻¹, as used by the wet bulb formula.
+pub(crate) const CP_WATER: f64 = 4186.0;
+/// Gas constant of water vapor in J kg_H₂O⁻¹ K⁻¹.
+pub(crate) const R_W: f64 = 461.52;
+/// Specific heat of water vapor in J kg_H₂O⁻¹ K⁻¹, as used by the enthalpy formula.
+pub(crate) const CP_VAPOR: f64 = 1860.0;
+/// Latent heat of vaporization of water at 0 °C in J kg_H₂O⁻¹, as used by the enthalpy formula.
+pub(crate) const LATENT_HEAT_VAPORIZATION: f64 = 2_501_000.0;
+/// Range of validity of the saturation formulas in °C. Also the dew point search domain.
+pub(crate) const TEMPERATURE_BOUNDS: (f64, f64) = (-100.0, 200.0);
+/// Range of `TemperatureRange::Extended` in °C, for dryer exhaust.
+pub(crate) const EXTENDED_TEMPERATURE_BOUNDS: (f64, f64) = (-100.0, 300.0);
+/// Temperature in °C above which the extended range uses IAPWS-IF97 for saturation.
+const IAPWS_FROM_C: f64 = 100.0;
+
+pub(crate) fn t_kelvin(t_c: f64) -> f64 {
+    t_c + ZERO_CELCIUS_K
+}
+
+pub(crate) fn check_pres_positive(pres: f64) -> Result<(), PsychroLibErr> {
+    if pres <= 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    Ok(())
+}
+
+/// `Value` when the ambient pressure is not positive, `Range` below `MIN_PRES_AMBIENT`.
+pub(crate) fn check_pres_ambient(pres: f64) -> Result<(), PsychroLibErr> {
+    check_pres_positive(pres)?;
+    if pres < MIN_PRES_AMBIENT {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(())
+}
+
+pub(crate) fn check_tdry_bulb_range(t_c: f64) -> Result<(), PsychroLibErr> {
+    let (lower, upper) = TEMPERATURE_BOUNDS;
+    if !(lower..=upper).contains(&t_c) {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(())
+}
+
+pub(crate) fn check_tdry_bulb_range_extended(t_c: f64) -> Result<(), PsychroLibErr> {
+    let (lower, upper) = EXTENDED_TEMPERATURE_BOUNDS;
+    if !(lower..=upper).contains(&t_c) {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok(())
+}
+
+/// Saturation vapor pressure. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5 & 6
+pub(crate) fn sat_vap_pres(t_c: f64) -> f64 {
+    if t_c <= TRIPLE_POINT_WATER_C {
+        return sat_vap_pres_ice(t_c);
+    }
+    sat_vap_pres_water(t_c)
+}
+
+/// Saturation vapor pressure over liquid water. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 6
+pub(crate) fn sat_vap_pres_water(t_c: f64) -> f64 {
+    let t_k = t_kelvin(t_c);
+    let ln_pws = -5.8002206E+03 / t_k + 1.3914993 - 4.8640239E-02 * t_k + 4.1764768E-05 * t_k * t_k
+        - 1.4452093E-08 * t_k.powi(3)
+        + 6.5459673 * t_k.ln();
+    ln_pws.exp()
+}
+
+/// Saturation vapor pressure over the extended range, ASHRAE up to 100 °C and IAPWS-IF97 above.
+/// The two differ by less than 1 Pa at 100 °C.
+pub(crate) fn sat_vap_pres_extended(t_c: f64) -> f64 {
+    if t_c <= IAPWS_FROM_C {
+        return sat_vap_pres(t_c);
+    }
+    sat_vap_pres_iapws(t_c)
+}
+
+/// Saturation pressure of water from the triple point to the critical point.
+/// IAPWS-IF97 (2007) region 4 eqn. 30
+pub(crate) fn sat_vap_pres_iapws(t_c: f64) -> f64 {
+    const N: [f64; 10] = [
+        0.116_705_214_527_67E4,
+        -0.724_213_167_032_06E6,
+        -0.170_738_469_400_92E2,
+        0.120_208_247_024_70E5,
+        -0.323_255_503_223_33E7,
+        0.149_151_086_135_30E2,
+        -0.482_326_573_615_91E4,
+        0.405_113_405_420_57E6,
+        -0.238_555_575_678_49,
+        0.650_175_348_447_98E3,
+    ];
+    let t_k = t_kelvin(t_c);
+    let theta = t_k + N[8] / (t_k - N[9]);
+    let a = theta * theta + N[0] * theta + N[1];
+    let b = N[2] * theta * theta + N[3] * theta + N[4];
+    let c = N[5] * theta * theta + N[6] * theta + N[7];
+    // Eqn. 30 gives MPa.
+    (2.0 * c / (-b + (b * b - 4.0 * a * c).sqrt())).powi(4) * 1E6
+}
+
+/// Saturation vapor pressure over ice. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn. 5
+pub(crate) fn sat_vap_pres_ice(t_c: f64) -> f64 {
+    let t_k = t_kelvin(t_c);
+    let ln_pws = -5.6745359E+03 / t_k + 6.3925247 - 9.677843E-03 * t_k
+        + 6.2215701E-07 * t_k * t_k
+        + 2.0747825E-09 * t_k.powi(3)
+        - 9.484024E-13 * t_k.powi(4)
+        + 4.1635019 * t_k.ln();
+    ln_pws.exp()
+}
+
+pub(crate) fn vap_pres_from_hum_ratio(hum_ratio: f64, pres: f64) -> Result<f64, PsychroLibErr> {
+    check_pres_ambient(pres)?;
+    if !(0.0..=MAX_HUM_RATIO).contains(&hum_ratio) {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = hum_ratio.max(MIN_HUM_RATIO);
+    Ok(pres * hum_ratio / (MOLECULAR_WEIGHT_RATIO + hum_ratio))
+}
+
+pub(crate) fn hum_ratio_from_vap_pres(vap_pres: f64, pres: f64) -> Result<f64, PsychroLibErr> {
+    check_pres_ambient(pres)?;
+    if vap_pres < 0.0 {
+        return Err(PsychroLibErr::Value);
+    }
+    if vap_pres > MAX_VAP_PRES_FRACTION * pres {
+        return Err(PsychroLibErr::Range);
+    }
+    Ok((MOLECULAR_WEIGHT_RATIO * vap_pres / (pres - vap_pres)).max(MIN_HUM_RATIO))
+}
+
+pub(crate) fn sat_hum_ratio(tdry_bulb: f64, pres: f64) -> Result<f64, PsychroLibErr> {
+    hum_ratio_from_vap_pres(sat_vap_pres(tdry_bulb), pres)
+}
+
+pub(crate) fn hum_ratio_from_rel_hum(
+    tdry_bulb: f64,
+    rel_hum: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    if rel_hum < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    hum_ratio_from_vap_pres(rel_hum * sat_vap_pres(tdry_bulb), pres)
+}
+
+pub(crate) fn rel_hum_from_hum_ratio(
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    Ok(vap_pres_from_hum_ratio(hum_ratio, pres)? / sat_vap_pres(tdry_bulb))
+}
+
+/// Enthalpy of liquid water above freezing and of ice below, in J kg_H₂O⁻¹.
+/// ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 32 & 34
+pub(crate) fn condensed_water_enthalpy(t_c: f64) -> f64 {
+    if t_c >= FREEZING_POINT_WATER_C {
+        CP_WATER * t_c
+    } else {
+        -333_400.0 + 2100.0 * t_c
+    }
+}
+
+/// Moist air enthalpy. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30
+pub(crate) fn moist_air_enthalpy(tdry_bulb: f64, hum_ratio: f64) -> f64 {
+    (1.006 * tdry_bulb + hum_ratio * (2501. + 1.86 * tdry_bulb)) * 1000.0
+}
+
+/// Humidity ratio from enthalpy. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30 solved for W
+pub(crate) fn hum_ratio_from_enthalpy_and_tdry_bulb(
+    moist_air_enthalpy: f64,
+    tdry_bulb: f64,
+) -> f64 {
+    let hum_ratio = (moist_air_enthalpy / 1000.0 - 1.006 * tdry_bulb) / (2501.0 + 1.86 * tdry_bulb);
+    hum_ratio.max(MIN_HUM_RATIO)
+}
+
+/// Dry bulb temperature from enthalpy. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 30 solved
+/// for t
+pub(crate) fn tdry_bulb_from_enthalpy_and_hum_ratio(
+    moist_air_enthalpy: f64,
+    hum_ratio: f64,
+) -> f64 {
+    let hum_ratio = hum_ratio.max(MIN_HUM_RATIO);
+    (moist_air_enthalpy / 1000.0 - 2501.0 * hum_ratio) / (1.006 + 1.86 * hum_ratio)
+}
+
+/// Specific volume of moist air in m³ kg_Air⁻¹. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 26
+pub(crate) fn moist_air_volume(
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    check_pres_positive(pres)?;
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = hum_ratio.max(MIN_HUM_RATIO);
+    Ok(R_DA * t_kelvin(tdry_bulb) * (1.0 + 1.607858 * hum_ratio) / pres)
+}
+
+/// Specific entropy of moist air in J kg_Air⁻¹ K⁻¹ as an ideal mixture of dry air and water vapor.
+/// Dry air is referenced to 0 °C and 101325 Pa and water vapor to saturated liquid water at the
+/// triple point, as in ASHRAE Handbook - Fundamentals (2017) ch. 1 table 2.
+pub(crate) fn moist_air_entropy(
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    /// Entropy of saturated water vapor at 0 °C in J kg_H₂O⁻¹ K⁻¹.
+    const S_VAPOR_0C: f64 = 9156.2;
+    let vap_pres = vap_pres_from_hum_ratio(hum_ratio, pres)?;
+    let hum_ratio = hum_ratio.max(MIN_HUM_RATIO);
+    let ln_t = (t_kelvin(tdry_bulb) / ZERO_CELCIUS_K).ln();
+    let dry_air = CP_DA * ln_t - R_DA * ((pres - vap_pres) / 101_325.0).ln();
+    let vapor = S_VAPOR_0C + CP_VAPOR * ln_t - R_W * (vap_pres / sat_vap_pres(0.0)).ln();
+    Ok(dry_air + hum_ratio * vapor)
+}
+
+/// Compressibility factor of moist air from the second virial coefficients of the mixture.
+/// Hyland & Wexler (1983), ASHRAE Transactions 89(2A) eqn 15, 17 & 18
+pub(crate) fn compressibility_factor(
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    let vap_pres = vap_pres_from_hum_ratio(hum_ratio, pres)?;
+    let t_k = t_kelvin(tdry_bulb);
+    // Second virial coefficients in m³ mol⁻¹ of air, water vapor and their interaction.
+    let b_aa =
+        0.349_568E-4 - 0.668_772E-2 / t_k - 0.210_141E1 / t_k.powi(2) + 0.924_746E2 / t_k.powi(3);
+    let b_ww = R_UNIVERSAL * t_k * (0.70E-8 - 0.147_184E-8 * (1734.29 / t_k).exp());
+    let b_aw = 0.323_660_97E-4
+        - 0.141_138E-1 / t_k
+        - 0.124_453_5E1 / t_k.powi(2)
+        - 0.234_878_9E4 / t_k.powi(4);
+    let x_w = vap_pres / pres;
+    let x_a = 1.0 - x_w;
+    let b_mix = x_a * x_a * b_aa + 2.0 * x_a * x_w * b_aw + x_w * x_w * b_ww;
+    Ok(1.0 + b_mix * pres / (R_UNIVERSAL * t_k))
+}
+
+/// Density of moist air in kg m⁻³. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 11
+pub(crate) fn moist_air_density(
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    let hum_ratio = hum_ratio.max(MIN_HUM_RATIO);
+    Ok((1.0 + hum_ratio) / moist_air_volume(tdry_bulb, hum_ratio, pres)?)
+}
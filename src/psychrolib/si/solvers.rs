@@ -0,0 +1,207 @@
+//! Iterative solvers of the SI formulas, the inverses PsychroLib has no closed form for. The dew
+//! point inverts `sat_vap_pres` by Newton-Raphson, with the ice and water branches meeting at the
+//! triple point so the iteration doesn't jump between them. The wet bulb and the adiabatic
+//! saturation temperatures are found by bisection between the dew point and the dry bulb
+//! temperature, which always bracket them.
+use super::{
+    check_pres_positive, condensed_water_enthalpy, moist_air_enthalpy, sat_hum_ratio, sat_vap_pres,
+    t_kelvin, vap_pres_from_hum_ratio, PsychroLibErr, FREEZING_POINT_WATER_C, MAX_ITER_COUNT,
+    MIN_HUM_RATIO, TEMPERATURE_BOUNDS, TOLERANCE, TRIPLE_POINT_WATER_C,
+};
+
+/// Derivative of the natural log of the saturation vapor pressure with respect to temperature.
+fn dln_pws(t_c: f64) -> f64 {
+    let t_k = t_kelvin(t_c);
+    if t_c <= TRIPLE_POINT_WATER_C {
+        5.6745359E+03 / t_k.powi(2) - 9.677843E-03
+            + 2.0 * 6.2215701E-07 * t_k
+            + 3.0 * 2.0747825E-09 * t_k.powi(2)
+            - 4.0 * 9.484024E-13 * t_k.powi(3)
+            + 4.1635019 / t_k
+    } else {
+        5.8002206E+03 / t_k.powi(2) - 4.8640239E-02 + 2.0 * 4.1764768E-05 * t_k
+            - 3.0 * 1.4452093E-08 * t_k.powi(2)
+            + 6.5459673 / t_k
+    }
+}
+
+/// Dew point by Newton-Raphson inversion of `sat_vap_pres`, capped at the dry bulb temperature.
+pub(crate) fn tdew_point_from_vap_pres(
+    tdry_bulb: f64,
+    vap_pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    Ok(invert_sat_vap_pres(vap_pres, tdry_bulb)?.min(tdry_bulb))
+}
+
+/// Temperature at which water saturates at `vap_pres`, by Newton-Raphson inversion of
+/// `sat_vap_pres`.
+pub(crate) fn sat_temp_from_vap_pres(vap_pres: f64) -> Result<f64, PsychroLibErr> {
+    check_pres_positive(vap_pres)?;
+    invert_sat_vap_pres(vap_pres, 20.0)
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+fn invert_sat_vap_pres(vap_pres: f64, tguess: f64) -> Result<f64, PsychroLibErr> {
+    let (lower, upper) = TEMPERATURE_BOUNDS;
+    if vap_pres < sat_vap_pres(lower) || vap_pres > sat_vap_pres(upper) {
+        return Err(PsychroLibErr::Range);
+    }
+    let ln_vp = vap_pres.ln();
+    let mut tsat = tguess;
+    for iteration in 0..MAX_ITER_COUNT {
+        let tsat_iter = tsat;
+        let ln_vp_iter = sat_vap_pres(tsat_iter).ln();
+        tsat = (tsat_iter - (ln_vp_iter - ln_vp) / dln_pws(tsat_iter)).clamp(lower, upper);
+        trace_iteration!(iteration, tsat, residual = ln_vp_iter - ln_vp);
+        if (tsat - tsat_iter).abs() <= TOLERANCE {
+            return Ok(tsat);
+        }
+    }
+    Err(PsychroLibErr::Convergence)
+}
+
+/// Dew point of air with `hum_ratio` at `pres`, capped at the dry bulb temperature.
+pub(crate) fn tdew_point_from_hum_ratio(
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    tdew_point_from_vap_pres(tdry_bulb, vap_pres_from_hum_ratio(hum_ratio, pres)?)
+}
+
+/// Humidity ratio from wet bulb. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 33 and 35
+pub(crate) fn hum_ratio_from_twet_bulb(
+    tdry_bulb: f64,
+    twet_bulb: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    if twet_bulb > tdry_bulb {
+        return Err(PsychroLibErr::Range);
+    }
+    let ws_star = sat_hum_ratio(twet_bulb, pres)?;
+    let hum_ratio = if twet_bulb >= FREEZING_POINT_WATER_C {
+        ((2501. - 2.326 * twet_bulb) * ws_star - 1.006 * (tdry_bulb - twet_bulb))
+            / (2501. + 1.86 * tdry_bulb - 4.186 * twet_bulb)
+    } else {
+        ((2830. - 0.24 * twet_bulb) * ws_star - 1.006 * (tdry_bulb - twet_bulb))
+            / (2830. + 1.86 * tdry_bulb - 2.1 * twet_bulb)
+    };
+    Ok(hum_ratio.max(MIN_HUM_RATIO))
+}
+
+/// Wet bulb by bisection between the dew point and the dry bulb temperature.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub(crate) fn twet_bulb_from_hum_ratio(
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = hum_ratio.max(MIN_HUM_RATIO);
+    let mut twet_bulb_sup = tdry_bulb;
+    let mut twet_bulb_inf = tdew_point_from_hum_ratio(tdry_bulb, hum_ratio, pres)?;
+    let mut twet_bulb = (twet_bulb_inf + twet_bulb_sup) / 2.0;
+    let mut index = 1;
+    while twet_bulb_sup - twet_bulb_inf > TOLERANCE {
+        let hum_ratio_iter = hum_ratio_from_twet_bulb(tdry_bulb, twet_bulb, pres)?;
+        trace_iteration!(
+            iteration = index,
+            twet_bulb,
+            residual = hum_ratio_iter - hum_ratio
+        );
+        if hum_ratio_iter > hum_ratio {
+            twet_bulb_sup = twet_bulb;
+        } else {
+            twet_bulb_inf = twet_bulb;
+        }
+        twet_bulb = (twet_bulb_sup + twet_bulb_inf) / 2.0;
+        if index >= MAX_ITER_COUNT {
+            return Err(PsychroLibErr::Convergence);
+        }
+        index += 1;
+    }
+    Ok(twet_bulb)
+}
+
+/// Adiabatic saturation temperature by bisection of the energy balance between the dew point
+/// and the dry bulb temperature. ASHRAE Handbook - Fundamentals (2017) ch. 1 eqn 31
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub(crate) fn tadiabatic_saturation_from_hum_ratio(
+    tdry_bulb: f64,
+    hum_ratio: f64,
+    pres: f64,
+) -> Result<f64, PsychroLibErr> {
+    if hum_ratio < 0.0 {
+        return Err(PsychroLibErr::Range);
+    }
+    let hum_ratio = hum_ratio.max(MIN_HUM_RATIO);
+    let enthalpy = moist_air_enthalpy(tdry_bulb, hum_ratio);
+    let mut tsat_sup = tdry_bulb;
+    let mut tsat_inf = tdew_point_from_hum_ratio(tdry_bulb, hum_ratio, pres)?;
+    let mut tsat = (tsat_inf + tsat_sup) / 2.0;
+    let mut index = 1;
+    while tsat_sup - tsat_inf > TOLERANCE {
+        let sat_hum_ratio = sat_hum_ratio(tsat, pres)?;
+        // Enthalpy in minus enthalpy out of the saturator per kg of dry air.
+        let residual = enthalpy + (sat_hum_ratio - hum_ratio) * condensed_water_enthalpy(tsat)
+            - moist_air_enthalpy(tsat, sat_hum_ratio);
+        trace_iteration!(iteration = index, tsat, residual);
+        if residual < 0.0 {
+            tsat_sup = tsat;
+        } else {
+            tsat_inf = tsat;
+        }
+        tsat = (tsat_sup + tsat_inf) / 2.0;
+        if index >= MAX_ITER_COUNT {
+            return Err(PsychroLibErr::Convergence);
+        }
+        index += 1;
+    }
+    Ok(tsat)
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Counts spans and events.
+    #[derive(Default, Clone)]
+    struct Counter {
+        spans: Arc<AtomicUsize>,
+        events: Arc<AtomicUsize>,
+    }
+
+    impl Subscriber for Counter {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(self.spans.fetch_add(1, Ordering::SeqCst) as u64 + 1)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {
+            self.events.fetch_add(1, Ordering::SeqCst);
+        }
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn solver_events() {
+        let counter = Counter::default();
+        tracing::subscriber::with_default(counter.clone(), || {
+            twet_bulb_from_hum_ratio(25.0, 0.01, 101_325.0).unwrap();
+        });
+        // The wet bulb solver and the dew point solver it starts from.
+        assert_eq!(counter.spans.load(Ordering::SeqCst), 2);
+        // One event per iteration of each plus the two results.
+        assert!(counter.events.load(Ordering::SeqCst) > 10);
+    }
+}
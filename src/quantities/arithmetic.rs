@@ -0,0 +1,106 @@
+//! Products and quotients between quantities of different dimensions. Results are in SI units
+//! and convert to any other unit with `From`.
+use core::ops;
+
+use super::{
+    Density, Duration, Energy, MassFlowRate, Power, Quantity, SpecificEnthalpy, VolumetricFlowRate,
+};
+use crate::units::{dimension, Unit};
+use crate::units::{
+    CubicMetersPerSecond, Joule, JoulesPerKg, KgPerCubicMeter, KgPerSecond, Second, Watt,
+};
+
+/// `$a * $b = $c`, along with `$b * $a`, `$c / $a` and `$c / $b`. Each quantity is taken in the
+/// SI unit given after it.
+macro_rules! ImplProduct {
+    ($a:ident in $ua:ident, $b:ident in $ub:ident => $c:ident in $uc:ident) => {
+        impl<UA, UB> ops::Mul<Quantity<dimension::$b, UB>> for Quantity<dimension::$a, UA>
+        where
+            UA: Unit<Dimension = dimension::$a>,
+            UB: Unit<Dimension = dimension::$b>,
+        {
+            type Output = $c<$uc>;
+            fn mul(self, rhs: Quantity<dimension::$b, UB>) -> Self::Output {
+                $c::<$uc>::from(
+                    f64::from(&$a::<$ua>::from(&self)) * f64::from(&$b::<$ub>::from(&rhs)),
+                )
+            }
+        }
+
+        impl<UA, UB> ops::Mul<Quantity<dimension::$a, UA>> for Quantity<dimension::$b, UB>
+        where
+            UA: Unit<Dimension = dimension::$a>,
+            UB: Unit<Dimension = dimension::$b>,
+        {
+            type Output = $c<$uc>;
+            fn mul(self, rhs: Quantity<dimension::$a, UA>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl<UC, UA> ops::Div<Quantity<dimension::$a, UA>> for Quantity<dimension::$c, UC>
+        where
+            UC: Unit<Dimension = dimension::$c>,
+            UA: Unit<Dimension = dimension::$a>,
+        {
+            type Output = $b<$ub>;
+            fn div(self, rhs: Quantity<dimension::$a, UA>) -> Self::Output {
+                $b::<$ub>::from(
+                    f64::from(&$c::<$uc>::from(&self)) / f64::from(&$a::<$ua>::from(&rhs)),
+                )
+            }
+        }
+
+        impl<UC, UB> ops::Div<Quantity<dimension::$b, UB>> for Quantity<dimension::$c, UC>
+        where
+            UC: Unit<Dimension = dimension::$c>,
+            UB: Unit<Dimension = dimension::$b>,
+        {
+            type Output = $a<$ua>;
+            fn div(self, rhs: Quantity<dimension::$b, UB>) -> Self::Output {
+                $a::<$ua>::from(
+                    f64::from(&$c::<$uc>::from(&self)) / f64::from(&$b::<$ub>::from(&rhs)),
+                )
+            }
+        }
+    };
+}
+
+ImplProduct!(MassFlowRate in KgPerSecond, SpecificEnthalpy in JoulesPerKg => Power in Watt);
+ImplProduct!(VolumetricFlowRate in CubicMetersPerSecond, Density in KgPerCubicMeter => MassFlowRate in KgPerSecond);
+ImplProduct!(Power in Watt, Duration in Second => Energy in Joule);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{
+        BtuPerHour, Hour, KgPerHour, KilojoulesPerKg, Kilowatt, KilowattHour, LitersPerSecond,
+    };
+
+    #[test]
+    fn products() {
+        let power =
+            MassFlowRate::<KgPerSecond>::from(2) * SpecificEnthalpy::<KilojoulesPerKg>::from(10);
+        assert_eq!(power, Power::<Kilowatt>::from(20));
+        let power =
+            SpecificEnthalpy::<KilojoulesPerKg>::from(10) * MassFlowRate::<KgPerSecond>::from(2);
+        assert_eq!(power, Power::<Kilowatt>::from(20));
+        let mass_flow = VolumetricFlowRate::<LitersPerSecond>::from(1000)
+            * Density::<KgPerCubicMeter>::from(1.2);
+        assert_eq!(mass_flow, MassFlowRate::<KgPerSecond>::from(1.2));
+        let energy = Power::<Kilowatt>::from(3) * Duration::<Hour>::from(2);
+        assert_eq!(energy, Energy::<KilowattHour>::from(6));
+    }
+
+    #[test]
+    fn quotients() {
+        let mass_flow = Power::<Kilowatt>::from(20) / SpecificEnthalpy::<KilojoulesPerKg>::from(10);
+        assert_eq!(mass_flow, MassFlowRate::<KgPerHour>::from(7200));
+        let enthalpy = Power::<BtuPerHour>::from(68_242.8) / MassFlowRate::<KgPerSecond>::from(2);
+        assert_eq!(enthalpy, SpecificEnthalpy::<KilojoulesPerKg>::from(10));
+        let power = Energy::<KilowattHour>::from(6) / Duration::<Hour>::from(2);
+        assert_eq!(power, Power::<Kilowatt>::from(3));
+        let flow = MassFlowRate::<KgPerSecond>::from(1.2) / Density::<KgPerCubicMeter>::from(1.2);
+        assert_eq!(flow, VolumetricFlowRate::<LitersPerSecond>::from(1000));
+    }
+}
@@ -0,0 +1,22 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type Density<U> = Quantity<dimension::Density, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{GramsPerCubicMeter, KgPerCubicMeter, PoundsPerCubicFoot};
+
+    #[test]
+    fn create() {
+        let a = 1.2; //kg/m³
+        let b = 0.074_913; //lb/ft³
+        let c = 1200; //g/m³
+        let da = Density::<KgPerCubicMeter>::from(a);
+        let db = Density::<PoundsPerCubicFoot>::from(b);
+        let dc = Density::<GramsPerCubicMeter>::from(c);
+        assert!((f64::from(Density::<KgPerCubicMeter>::from(&db)) - a).abs() < 1E-5);
+        assert_eq!(da, dc);
+    }
+}
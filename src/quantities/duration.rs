@@ -0,0 +1,23 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type Duration<U> = Quantity<dimension::Duration, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Day, Hour, Minute, Second};
+
+    #[test]
+    fn create() {
+        let a = 1.5; //h
+        let b = 90; //min
+        let c = 5400; //s
+        let da = Duration::<Hour>::from(a);
+        let db = Duration::<Minute>::from(b);
+        let dc = Duration::<Second>::from(c);
+        assert_eq!(da, db);
+        assert_eq!(db, dc);
+        assert!((f64::from(Duration::<Day>::from(&da)) - 0.0625).abs() < 1E-9);
+    }
+}
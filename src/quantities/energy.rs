@@ -0,0 +1,43 @@
+use super::Quantity;
+use crate::units::{dimension, EnergyUnit};
+
+pub type Energy<U> = Quantity<dimension::Energy, U>;
+
+impl<U: EnergyUnit> Energy<U> {
+    /// Energy from its exact internal value in mJ.
+    #[must_use]
+    pub const fn from_milli_joule(milli_joule: i64) -> Self {
+        Self::from_base_units(milli_joule)
+    }
+
+    /// Exact internal value in mJ whatever the unit `U`.
+    #[must_use]
+    pub const fn as_milli_joule(&self) -> i64 {
+        self.as_base_units()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Btu, Joule, Kilojoule, KilowattHour};
+
+    #[test]
+    fn create() {
+        let a = 1; //kWh
+        let b = 3600; //kJ
+        let c = 3_412.141; //Btu
+        let ea = Energy::<KilowattHour>::from(a);
+        let eb = Energy::<Kilojoule>::from(b);
+        let ec = Energy::<Btu>::from(c);
+        assert_eq!(ea, eb);
+        assert!((f64::from(Energy::<Joule>::from(&ec)) - 3_600_000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn raw_value() {
+        let energy = Energy::<Btu>::from(2);
+        assert_eq!(energy.as_milli_joule(), 2_110_112);
+        assert_eq!(Energy::<Joule>::from_milli_joule(2_110_112), energy);
+    }
+}
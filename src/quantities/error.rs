@@ -0,0 +1,19 @@
+/// Errors of the checked constructors and parsers of quantities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantityErr {
+    /// When the value is not a number or the text can't be read.
+    Value,
+    /// When the value can't be stored or has no physical meaning.
+    Range,
+}
+
+impl core::fmt::Display for QuantityErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            QuantityErr::Value => write!(f, "invalid quantity value"),
+            QuantityErr::Range => write!(f, "quantity outside of acceptable limits"),
+        }
+    }
+}
+
+impl std::error::Error for QuantityErr {}
@@ -0,0 +1,360 @@
+//! String formatting of quantities for dashboards and reports.
+use core::fmt::{self, Write};
+
+use super::{Quantity, Temperature};
+use crate::units::{Dimension, TemperatureUnit, Unit};
+
+/// Number of digits shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// Fixed number of digits after the decimal point.
+    Decimals(usize),
+    /// Number of significant figures, at least 1.
+    SignificantFigures(usize),
+}
+
+/// How the unit follows the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitStyle {
+    /// Abbreviation such as "Pa".
+    #[default]
+    Abbreviation,
+    /// Full singular name such as "pascal".
+    Name,
+    /// Value only.
+    Hidden,
+}
+
+/// Options of `format_with`. Defaults to 2 decimals, the unit abbreviation and no thousands
+/// separator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub precision: Precision,
+    pub unit_style: UnitStyle,
+    /// Character put between groups of three digits of the integer part.
+    pub thousands_separator: Option<char>,
+}
+
+/// Longest number `write_with` can group with a thousands separator, in bytes.
+const NUMBER_BUFFER_LEN: usize = 64;
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            precision: Precision::Decimals(2),
+            unit_style: UnitStyle::Abbreviation,
+            thousands_separator: None,
+        }
+    }
+}
+
+/// Writer into a byte buffer, failing once the buffer is full.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    fn new(buf: &'a mut [u8]) -> Self {
+        SliceWriter { buf, len: 0 }
+    }
+
+    fn into_str(self) -> &'a str {
+        // Only whole `str`s are ever copied in so the bytes are valid UTF-8.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn write_number<W: fmt::Write>(w: &mut W, value: f64, precision: Precision) -> fmt::Result {
+    match precision {
+        Precision::Decimals(decimals) => write!(w, "{value:.decimals$}"),
+        Precision::SignificantFigures(figures) => {
+            let figures = figures.max(1) as i32;
+            if value == 0.0 || !value.is_finite() {
+                return write!(w, "{:.*}", (figures - 1) as usize, value);
+            }
+            let decimals = figures - 1 - value.abs().log10().floor() as i32;
+            if decimals >= 0 {
+                write!(w, "{:.*}", decimals as usize, value)
+            } else {
+                let scale = 10_f64.powi(-decimals);
+                write!(w, "{:.0}", (value / scale).round() * scale)
+            }
+        }
+    }
+}
+
+fn write_grouped<W: fmt::Write>(w: &mut W, number: &str, separator: char) -> fmt::Result {
+    let (sign, unsigned) = match number.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", number),
+    };
+    let (integer, fraction) = match unsigned.find('.') {
+        Some(point) => unsigned.split_at(point),
+        None => (unsigned, ""),
+    };
+    w.write_str(sign)?;
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            w.write_char(separator)?;
+        }
+        w.write_char(digit)?;
+    }
+    w.write_str(fraction)
+}
+
+fn write_unit<W: fmt::Write>(
+    w: &mut W,
+    name: &str,
+    abbreviation: &str,
+    unit_style: UnitStyle,
+) -> fmt::Result {
+    match unit_style {
+        UnitStyle::Abbreviation => write!(w, " {abbreviation}"),
+        UnitStyle::Name => write!(w, " {name}"),
+        UnitStyle::Hidden => Ok(()),
+    }
+}
+
+/// Without a thousands separator the number goes straight into `w`. Grouping needs the whole
+/// number first, so it goes through a `NUMBER_BUFFER_LEN` buffer and errors on longer numbers.
+pub(super) fn write_value<W: fmt::Write>(
+    w: &mut W,
+    value: f64,
+    name: &str,
+    abbreviation: &str,
+    options: &FormatOptions,
+) -> fmt::Result {
+    match options.thousands_separator {
+        Some(separator) => {
+            let mut digits = [0_u8; NUMBER_BUFFER_LEN];
+            let mut number = SliceWriter::new(&mut digits);
+            write_number(&mut number, value, options.precision)?;
+            write_grouped(w, number.into_str(), separator)?;
+        }
+        None => write_number(w, value, options.precision)?,
+    }
+    write_unit(w, name, abbreviation, options.unit_style)
+}
+
+/// Like `write_value` into a `String`, with no limit on the length of the number.
+pub(super) fn format_value(
+    value: f64,
+    name: &str,
+    abbreviation: &str,
+    options: &FormatOptions,
+) -> String {
+    let mut number = String::new();
+    let mut formatted = String::new();
+    // Writing to a String never errors, whatever the precision.
+    let _ = write_number(&mut number, value, options.precision);
+    let _ = match options.thousands_separator {
+        Some(separator) => write_grouped(&mut formatted, &number, separator),
+        None => formatted.write_str(&number),
+    };
+    let _ = write_unit(&mut formatted, name, abbreviation, options.unit_style);
+    formatted
+}
+
+fn to_fixed_buf<'a>(
+    buf: &'a mut [u8],
+    write: impl FnOnce(&mut SliceWriter<'a>) -> fmt::Result,
+) -> Result<&'a str, fmt::Error> {
+    let mut writer = SliceWriter::new(buf);
+    write(&mut writer)?;
+    Ok(writer.into_str())
+}
+
+impl<D, U> Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    /// Value and unit as a string, following `options`.
+    #[must_use]
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format_value(
+            f64::from(self),
+            U::singular_name(),
+            U::abbreviation(),
+            options,
+        )
+    }
+
+    /// Write value and unit to `w` without allocating, following `options`. Errors when `w`
+    /// does, or when a grouped number is longer than 64 bytes.
+    pub fn write_with<W: fmt::Write>(&self, w: &mut W, options: &FormatOptions) -> fmt::Result {
+        write_value(
+            w,
+            f64::from(self),
+            U::singular_name(),
+            U::abbreviation(),
+            options,
+        )
+    }
+
+    /// Write value and unit into `buf` and return them as a `str`, following `options`. Errors
+    /// when `buf` is too small.
+    pub fn to_fixed_buf<'a>(
+        &self,
+        buf: &'a mut [u8],
+        options: &FormatOptions,
+    ) -> Result<&'a str, fmt::Error> {
+        to_fixed_buf(buf, |w| self.write_with(w, options))
+    }
+}
+
+impl<T: TemperatureUnit> Temperature<T> {
+    /// Value and unit as a string, following `options`.
+    #[must_use]
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format_value(
+            f64::from(self),
+            T::singular_name(),
+            T::abbreviation(),
+            options,
+        )
+    }
+
+    /// Write value and unit to `w` without allocating, following `options`. Errors when `w`
+    /// does, or when a grouped number is longer than 64 bytes.
+    pub fn write_with<W: fmt::Write>(&self, w: &mut W, options: &FormatOptions) -> fmt::Result {
+        write_value(
+            w,
+            f64::from(self),
+            T::singular_name(),
+            T::abbreviation(),
+            options,
+        )
+    }
+
+    /// Write value and unit into `buf` and return them as a `str`, following `options`. Errors
+    /// when `buf` is too small.
+    pub fn to_fixed_buf<'a>(
+        &self,
+        buf: &'a mut [u8],
+        options: &FormatOptions,
+    ) -> Result<&'a str, fmt::Error> {
+        to_fixed_buf(buf, |w| self.write_with(w, options))
+    }
+}
+
+/// Value in the shortest form that reads back exactly, or with the precision of the format
+/// string, followed by the unit abbreviation.
+pub(super) fn display(f: &mut fmt::Formatter<'_>, value: f64, abbreviation: &str) -> fmt::Result {
+    match f.precision() {
+        Some(decimals) => write!(f, "{value:.decimals$} {abbreviation}"),
+        None => write!(f, "{value} {abbreviation}"),
+    }
+}
+
+impl<D, U> fmt::Display for Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display(f, f64::from(self), U::abbreviation())
+    }
+}
+
+impl<T: TemperatureUnit> fmt::Display for Temperature<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display(f, f64::from(self), T::abbreviation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Pressure;
+    use crate::units::{Celcius, Pascal};
+
+    #[test]
+    fn options() {
+        let pres = Pressure::<Pascal>::from(101_325.456);
+        assert_eq!(pres.format_with(&FormatOptions::default()), "101325.46 Pa");
+        let options = FormatOptions {
+            precision: Precision::SignificantFigures(4),
+            unit_style: UnitStyle::Name,
+            thousands_separator: Some(','),
+        };
+        assert_eq!(pres.format_with(&options), "101,300 pascal");
+        let options = FormatOptions {
+            precision: Precision::SignificantFigures(3),
+            unit_style: UnitStyle::Hidden,
+            thousands_separator: None,
+        };
+        assert_eq!(
+            Temperature::<Celcius>::from(-0.012_34).format_with(&options),
+            "-0.0123"
+        );
+        let options = FormatOptions {
+            precision: Precision::Decimals(1),
+            thousands_separator: Some(' '),
+            ..FormatOptions::default()
+        };
+        assert_eq!(
+            Pressure::<Pascal>::from(-1_234_567.89).format_with(&options),
+            "-1 234 567.9 Pa"
+        );
+    }
+
+    #[test]
+    fn large_precision() {
+        let pres = Pressure::<Pascal>::from(101_325.5);
+        let options = FormatOptions {
+            precision: Precision::Decimals(70),
+            ..FormatOptions::default()
+        };
+        assert_eq!(
+            pres.format_with(&options),
+            format!("101325.5{} Pa", "0".repeat(69))
+        );
+        let options = FormatOptions {
+            thousands_separator: Some(','),
+            ..options
+        };
+        assert_eq!(
+            pres.format_with(&options),
+            format!("101,325.5{} Pa", "0".repeat(69))
+        );
+        let mut text = String::new();
+        assert!(pres.write_with(&mut text, &options).is_err());
+    }
+
+    #[test]
+    fn fixed_buffer() {
+        let mut buf = [0_u8; 16];
+        let tdry_bulb = Temperature::<Celcius>::from(21.456);
+        let text = tdry_bulb.to_fixed_buf(&mut buf, &FormatOptions::default());
+        assert_eq!(text, Ok("21.46 C"));
+        let mut buf = [0_u8; 8];
+        let pres = Pressure::<Pascal>::from(101_325);
+        assert!(pres
+            .to_fixed_buf(&mut buf, &FormatOptions::default())
+            .is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Pressure::<Pascal>::from(101_325.5).to_string(),
+            "101325.5 Pa"
+        );
+        let tdry_bulb = Temperature::<Celcius>::from(23.456);
+        assert_eq!(format!("{tdry_bulb:.1}"), "23.5 C");
+    }
+}
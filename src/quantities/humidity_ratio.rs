@@ -0,0 +1,23 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type HumidityRatio<U> = Quantity<dimension::HumidityRatio, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{GrainsPerPound, GramsPerKg, KgPerKg};
+
+    #[test]
+    fn create() {
+        let a = 0.0105; //kg/kg
+        let b = 10.5; //g/kg
+        let c = 73.5; //gr/lb
+        let ha = HumidityRatio::<KgPerKg>::from(a);
+        let hb = HumidityRatio::<GramsPerKg>::from(b);
+        let hc = HumidityRatio::<GrainsPerPound>::from(c);
+        assert_eq!(ha, hb);
+        assert_eq!(hb, hc);
+        assert!((f64::from(HumidityRatio::<GramsPerKg>::from(&ha)) - b).abs() < 1E-6);
+    }
+}
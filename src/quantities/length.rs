@@ -0,0 +1,22 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type Length<U> = Quantity<dimension::Length, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Foot, Inch, Meter};
+
+    #[test]
+    fn create() {
+        let a = 1609.344; //m
+        let b = 5280; //ft
+        let c = 63_360; //in
+        let la = Length::<Meter>::from(a);
+        let lb = Length::<Foot>::from(b);
+        let lc = Length::<Inch>::from(c);
+        assert_eq!(la, lb);
+        assert_eq!(lb, lc);
+    }
+}
@@ -0,0 +1,22 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type MassFlowRate<U> = Quantity<dimension::MassFlowRate, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{KgPerHour, KgPerSecond, PoundsPerHour};
+
+    #[test]
+    fn create() {
+        let a = 0.5; //kg/s
+        let b = 1800; //kg/h
+        let c = 3_968.32; //lb/h
+        let ma = MassFlowRate::<KgPerSecond>::from(a);
+        let mb = MassFlowRate::<KgPerHour>::from(b);
+        let mc = MassFlowRate::<PoundsPerHour>::from(c);
+        assert_eq!(ma, mb);
+        assert!((f64::from(MassFlowRate::<KgPerHour>::from(&mc)) - 1800.0).abs() < 1E-2);
+    }
+}
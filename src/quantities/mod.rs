@@ -1,6 +1,41 @@
 // TODO: Implement limits to quantities. Temperature and pressure specifically has no meaning when it is negative.
 // Relative humidity cannot be outside 0...1
 mod quantities_base;
+pub use quantities_base::Quantity;
+
+mod arithmetic;
+
+mod density;
+pub use density::Density;
+
+mod duration;
+pub use duration::Duration;
+
+mod energy;
+pub use energy::Energy;
+
+mod error;
+pub use error::QuantityErr;
+
+mod format;
+pub use format::{FormatOptions, Precision, UnitStyle};
+
+mod humidity_ratio;
+pub use humidity_ratio::HumidityRatio;
+
+mod length;
+pub use length::Length;
+
+mod mass_flow_rate;
+pub use mass_flow_rate::MassFlowRate;
+
+mod parse;
+
+mod power;
+pub use power::Power;
+
+mod preferences;
+pub use preferences::{Preferred, PreferredValue, UnitPreferences};
 
 mod pressure;
 pub use pressure::Pressure;
@@ -8,5 +43,31 @@ pub use pressure::Pressure;
 mod temperature;
 pub use temperature::Temperature;
 
+#[cfg(feature = "schemars")]
+mod schema;
+
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "serde")]
+pub use serialize::{as_string, deserialize_in, serialize_in, InUnit, SerializeIn};
+
 mod specific_enthalpy;
 pub use specific_enthalpy::SpecificEnthalpy;
+
+mod specific_entropy;
+pub use specific_entropy::SpecificEntropy;
+
+mod specific_heat;
+pub use specific_heat::SpecificHeat;
+
+mod specific_volume;
+pub use specific_volume::SpecificVolume;
+
+mod value;
+pub use value::QuantityValue;
+
+mod volumetric_flow_rate;
+pub use volumetric_flow_rate::VolumetricFlowRate;
+
+mod relative_humidity;
+pub use relative_humidity::RelativeHumidity;
@@ -0,0 +1,77 @@
+//! Parsing of quantities from text such as "23.5 °C" or "101325 Pa", the form `Display` writes.
+use core::str::FromStr;
+
+use super::{Quantity, QuantityErr, Temperature};
+use crate::units::{Dimension, TemperatureUnit, Unit};
+
+/// Value of `text`, a number followed by the abbreviation or the name of the unit, or by nothing.
+/// A degree sign before the unit is ignored. Errors with `Value` when the number doesn't parse or
+/// the unit is not the expected one. Other units of the same dimension are not converted.
+fn parse_value(text: &str, name: &str, abbreviation: &str) -> Result<f64, QuantityErr> {
+    let text = text.trim();
+    let number_end = text
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')))
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(number_end);
+    let unit = unit.trim_start();
+    let unit = unit.strip_prefix('°').unwrap_or(unit).trim_start();
+    if !(unit.is_empty() || unit == abbreviation || unit.eq_ignore_ascii_case(name)) {
+        return Err(QuantityErr::Value);
+    }
+    let value = number.parse::<f64>().map_err(|_| QuantityErr::Value)?;
+    if !value.is_finite() {
+        return Err(QuantityErr::Value);
+    }
+    Ok(value)
+}
+
+impl<D, U> FromStr for Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    type Err = QuantityErr;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_value(text, U::singular_name(), U::abbreviation()).map(Self::from)
+    }
+}
+
+impl<T: TemperatureUnit> FromStr for Temperature<T> {
+    type Err = QuantityErr;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_new(parse_value(text, T::singular_name(), T::abbreviation())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::{Pressure, SpecificEnthalpy};
+    use crate::units::{Celcius, Fahrenheit, KilojoulesPerKg, Pascal, Psi};
+
+    #[test]
+    fn parse() {
+        let tdry_bulb: Temperature<Celcius> = "23.5 °C".parse().unwrap();
+        assert_eq!(tdry_bulb, Temperature::<Celcius>::from(23.5));
+        let tdry_bulb: Temperature<Fahrenheit> = "-40F".parse().unwrap();
+        assert_eq!(tdry_bulb, Temperature::<Celcius>::from(-40));
+        let pres: Pressure<Pascal> = " 101325 pascal ".parse().unwrap();
+        assert_eq!(pres, Pressure::<Pascal>::from(101_325));
+        let pres: Pressure<Psi> = "1.47e1".parse().unwrap();
+        assert_eq!(pres, Pressure::<Psi>::from(14.7));
+        let enthalpy: SpecificEnthalpy<KilojoulesPerKg> = "47.02 kj kg⁻¹".parse().unwrap();
+        assert_eq!(enthalpy, SpecificEnthalpy::<KilojoulesPerKg>::from(47.02));
+        assert!(matches!(
+            "101.3 kPa".parse::<Pressure<Pascal>>(),
+            Err(QuantityErr::Value)
+        ));
+        assert!(matches!(
+            "-300 C".parse::<Temperature<Celcius>>(),
+            Err(QuantityErr::Range)
+        ));
+        let pres = Pressure::<Psi>::from(14.696);
+        assert_eq!(pres.to_string().parse::<Pressure<Psi>>().unwrap(), pres);
+    }
+}
@@ -0,0 +1,21 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type Power<U> = Quantity<dimension::Power, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{BtuPerHour, Kilowatt, TonOfRefrigeration};
+
+    #[test]
+    fn create() {
+        let a = 1; //TR
+        let b = 12_000; //Btu/h
+        let c = 3.516_852_842; //kW
+        let pa = Power::<TonOfRefrigeration>::from(a);
+        let pc = Power::<Kilowatt>::from(c);
+        assert_eq!(pa, pc);
+        assert!((f64::from(Power::<BtuPerHour>::from(&pa)) - f64::from(b)).abs() < 0.01);
+    }
+}
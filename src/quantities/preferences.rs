@@ -0,0 +1,213 @@
+//! Display units picked at runtime. One `UnitPreferences` switches a whole dashboard between SI
+//! and IP units, with overrides for single quantities such as humidity ratio in g/kg.
+//! ```
+//! use psychrometry::quantities::{Pressure, Temperature, UnitPreferences};
+//! use psychrometry::units::{Atmosphere, Celcius, Pascal, UnitSystem};
+//!
+//! let preferences = UnitPreferences::new(UnitSystem::IP).with_unit::<Atmosphere>();
+//! let tdry_bulb = Temperature::<Celcius>::from(25);
+//! assert_eq!(format!("{:.1}", preferences.show(&tdry_bulb)), "77.0 F");
+//! let pres = Pressure::<Pascal>::from(101_325);
+//! assert_eq!(format!("{:.3}", preferences.show(&pres)), "1.000 atm");
+//! ```
+use core::fmt;
+
+use super::format::{display, format_value, write_value};
+use super::{FormatOptions, Quantity, Temperature};
+use crate::units::{SystemUnits, TemperatureUnit, Unit, UnitKind, UnitSystem};
+
+/// System of units results are shown in, with the unit of some quantities overridden.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnitPreferences {
+    system: UnitSystem,
+    temperature: Option<UnitKind>,
+    /// Chosen unit keyed by the SI unit of its dimension.
+    overrides: Vec<(UnitKind, UnitKind)>,
+}
+
+impl From<UnitSystem> for UnitPreferences {
+    fn from(system: UnitSystem) -> Self {
+        Self::new(system)
+    }
+}
+
+impl UnitPreferences {
+    /// Every quantity in the units of `system`.
+    #[must_use]
+    pub fn new(system: UnitSystem) -> Self {
+        UnitPreferences {
+            system,
+            temperature: None,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Show quantities of the dimension of `U` in `U` whatever the system.
+    #[must_use]
+    pub fn with_unit<U>(mut self) -> Self
+    where
+        U: Unit,
+        U::Dimension: SystemUnits,
+    {
+        let key = <U::Dimension as SystemUnits>::SI;
+        self.overrides.retain(|(dimension, _)| *dimension != key);
+        self.overrides.push((key, U::kind()));
+        self
+    }
+
+    /// Show temperatures in `T` whatever the system.
+    #[must_use]
+    pub fn with_temperature_unit<T: TemperatureUnit>(mut self) -> Self {
+        self.temperature = Some(T::kind());
+        self
+    }
+
+    /// System of units of the quantities without an override.
+    #[must_use]
+    pub fn system(&self) -> UnitSystem {
+        self.system
+    }
+
+    /// Return the unit quantities of dimension `D` are shown in.
+    #[must_use]
+    pub fn unit<D: SystemUnits>(&self) -> UnitKind {
+        self.overrides
+            .iter()
+            .find(|(dimension, _)| *dimension == D::SI)
+            .map_or_else(|| self.system.unit::<D>(), |(_, unit)| *unit)
+    }
+
+    /// Return the unit temperatures are shown in.
+    #[must_use]
+    pub fn temperature_unit(&self) -> UnitKind {
+        self.temperature
+            .unwrap_or_else(|| self.system.temperature_unit())
+    }
+
+    /// Return `quantity` converted to its preferred unit, ready to display or serialize.
+    #[must_use]
+    pub fn show<Q: Preferred>(&self, quantity: &Q) -> PreferredValue {
+        quantity.preferred(self)
+    }
+}
+
+/// Quantities that can be shown in the unit picked by `UnitPreferences`.
+pub trait Preferred {
+    /// Return the value in the unit `preferences` picks for it.
+    fn preferred(&self, preferences: &UnitPreferences) -> PreferredValue;
+}
+
+impl<D: SystemUnits, U: Unit<Dimension = D>> Preferred for Quantity<D, U> {
+    fn preferred(&self, preferences: &UnitPreferences) -> PreferredValue {
+        let unit = preferences.unit::<D>();
+        PreferredValue {
+            value: self.as_base_units() as f64 / unit.conv_factor() as f64,
+            unit,
+        }
+    }
+}
+
+impl<T: TemperatureUnit> Preferred for Temperature<T> {
+    fn preferred(&self, preferences: &UnitPreferences) -> PreferredValue {
+        let unit = preferences.temperature_unit();
+        PreferredValue {
+            value: (self.as_micro_kelvin() - unit.conv_offset()) as f64 / unit.conv_factor() as f64,
+            unit,
+        }
+    }
+}
+
+/// Value in the unit picked by `UnitPreferences`. Displays like a quantity and serializes as
+/// `{"value": 77.0, "unit": "F"}`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PreferredValue {
+    /// Value in `unit`
+    pub value: f64,
+    /// Unit of the value
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_abbreviation"))]
+    pub unit: UnitKind,
+}
+
+#[cfg(feature = "serde")]
+fn serialize_abbreviation<S: serde::Serializer>(
+    unit: &UnitKind,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(unit.abbreviation())
+}
+
+impl PreferredValue {
+    /// Value and unit as a string, following `options`.
+    #[must_use]
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format_value(
+            self.value,
+            self.unit.singular_name(),
+            self.unit.abbreviation(),
+            options,
+        )
+    }
+
+    /// Write value and unit to `w` without allocating, following `options`. Errors when `w`
+    /// does, or when a grouped number is longer than 64 bytes.
+    pub fn write_with<W: fmt::Write>(&self, w: &mut W, options: &FormatOptions) -> fmt::Result {
+        write_value(
+            w,
+            self.value,
+            self.unit.singular_name(),
+            self.unit.abbreviation(),
+            options,
+        )
+    }
+}
+
+impl fmt::Display for PreferredValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display(f, self.value, self.unit.abbreviation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::{HumidityRatio, SpecificEnthalpy};
+    use crate::units::{Celcius, GramsPerKg, JoulesPerKg, Kelvin, KgPerKg};
+
+    #[test]
+    fn systems() {
+        let enthalpy = SpecificEnthalpy::<JoulesPerKg>::from(50_000);
+        let tdry_bulb = Temperature::<Kelvin>::from(298.15);
+        let si = UnitPreferences::default();
+        assert_eq!(si.show(&enthalpy).to_string(), "50 kj kg⁻¹");
+        assert_eq!(si.show(&tdry_bulb).to_string(), "25 C");
+        let ip = UnitPreferences::from(UnitSystem::IP);
+        let shown = ip.show(&enthalpy);
+        assert_eq!(shown.unit, UnitKind::BtuPerPound);
+        assert!((shown.value - 21.496).abs() < 0.001);
+        assert_eq!(format!("{:.1}", ip.show(&tdry_bulb)), "77.0 F");
+    }
+
+    #[test]
+    fn overrides() {
+        let hum_ratio = HumidityRatio::<KgPerKg>::from(0.0125);
+        let preferences = UnitPreferences::new(UnitSystem::IP)
+            .with_unit::<KgPerKg>()
+            .with_unit::<GramsPerKg>()
+            .with_temperature_unit::<Kelvin>();
+        assert_eq!(preferences.show(&hum_ratio).to_string(), "12.5 g kg⁻¹");
+        assert_eq!(preferences.temperature_unit(), UnitKind::Kelvin);
+        let shown = preferences.show(&Temperature::<Celcius>::from(25));
+        assert_eq!(shown.format_with(&FormatOptions::default()), "298.15 K");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let preferences = UnitPreferences::new(UnitSystem::SI);
+        let shown = preferences.show(&HumidityRatio::<KgPerKg>::from(0.0125));
+        let json = serde_json::to_value(shown).unwrap();
+        assert_eq!(json["unit"], "g kg⁻¹");
+        assert!((json["value"].as_f64().unwrap() - 12.5).abs() < 1E-9);
+    }
+}
@@ -1,11 +1,21 @@
-use core::cmp;
-use core::marker::PhantomData;
-use core::ops;
+use super::Quantity;
+use crate::units::{dimension, PressureUnit};
 
-use crate::units::PressureUnit;
-use crate::NewQuantity;
+pub type Pressure<U> = Quantity<dimension::Pressure, U>;
 
-NewQuantity!(Pressure, PressureUnit, 200);
+impl<U: PressureUnit> Pressure<U> {
+    /// Pressure from its exact internal value in mPa.
+    #[must_use]
+    pub const fn from_milli_pascal(milli_pascal: i64) -> Self {
+        Self::from_base_units(milli_pascal)
+    }
+
+    /// Exact internal value in mPa whatever the unit `U`.
+    #[must_use]
+    pub const fn as_milli_pascal(&self) -> i64 {
+        self.as_base_units()
+    }
+}
 
 #[cfg(test)]
 mod pressure_tests {
@@ -23,4 +33,12 @@ mod pressure_tests {
         assert!((f64::from(pa) - a).abs() < 1E-8);
         assert_eq!(pb, pc);
     }
+
+    #[test]
+    fn raw_value() {
+        let pres = Pressure::<Psi>::from(14.7);
+        assert_eq!(pres.as_milli_pascal(), 101_352_972);
+        let round_trip = Pressure::<Atmosphere>::from_milli_pascal(pres.as_milli_pascal());
+        assert_eq!(round_trip.as_milli_pascal(), pres.as_milli_pascal());
+    }
 }
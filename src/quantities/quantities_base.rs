@@ -1,193 +1,374 @@
-#[macro_export]
-macro_rules! NewQuantity {
-    ($quantity:ident, $units:ident, $tolerance:expr) => {
-        #[derive(Debug)]
-        pub struct $quantity<T: $units> {
-            base_unit: i64,
-            unit: PhantomData<T>,
+use core::cmp;
+use core::iter;
+use core::marker::PhantomData;
+use core::ops;
+
+use crate::units::{Dimension, Unit, UnitKind};
+
+/// Quantity of dimension `D` stored as an integer number of base units and expressed in unit `U`.
+/// Converting between units of the same dimension only changes `U`. Every linear quantity is an
+/// alias of this type, a new one only needs a dimension and its units. `Temperature` has an offset
+/// between units and keeps its own type.
+#[derive(Debug)]
+pub struct Quantity<D: Dimension, U: Unit<Dimension = D>> {
+    base_unit: i64,
+    unit: PhantomData<(D, U)>,
+}
+
+impl<D, U> Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    /// Quantity from its exact internal value, an integer number of the base units of `D`. Inverse
+    /// of `as_base_units`, for persistence and FFI that must round trip without going through
+    /// `f64`.
+    #[must_use]
+    pub const fn from_base_units(base_units: i64) -> Self {
+        Self::from_base_unit(base_units)
+    }
+
+    /// Exact internal value, an integer number of the base units of `D` whatever the unit `U`.
+    #[must_use]
+    pub const fn as_base_units(&self) -> i64 {
+        self.base_unit
+    }
+
+    /// Name of the unit `U`, such as "pascal".
+    #[must_use]
+    pub fn unit_name() -> &'static str {
+        U::singular_name()
+    }
+
+    /// Abbreviation of the unit `U`, such as "Pa".
+    #[must_use]
+    pub fn unit_abbreviation() -> &'static str {
+        U::abbreviation()
+    }
+
+    /// Unit `U` as a runtime value.
+    #[must_use]
+    pub fn unit(&self) -> UnitKind {
+        U::kind()
+    }
+
+    /// Nearest multiple of `step` in the unit `U`, such as 10 Pa. Unchanged when `step` is not
+    /// positive.
+    #[must_use]
+    pub fn round_to(&self, step: f64) -> Self {
+        self.quantize(step, f64::round)
+    }
+
+    /// Largest multiple of `step` in the unit `U` not above the quantity. Unchanged when `step` is
+    /// not positive.
+    #[must_use]
+    pub fn floor_to(&self, step: f64) -> Self {
+        self.quantize(step, f64::floor)
+    }
+
+    /// Smallest multiple of `step` in the unit `U` not below the quantity. Unchanged when `step`
+    /// is not positive.
+    #[must_use]
+    pub fn ceil_to(&self, step: f64) -> Self {
+        self.quantize(step, f64::ceil)
+    }
+
+    fn quantize(&self, step: f64, to_integer: fn(f64) -> f64) -> Self {
+        if !(step > 0.0 && step.is_finite()) {
+            return Self::from_base_unit(self.base_unit);
+        }
+        let step = step * U::conv_factor_base_unit() as f64;
+        Self::from_base_unit((to_integer(self.base_unit as f64 / step) * step).round() as i64)
+    }
+
+    /// Arithmetic mean of `quantities`. `None` when there are none.
+    #[must_use]
+    pub fn average<'a, I>(quantities: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        average_base_units(quantities.into_iter().map(|q| q.base_unit)).map(Self::from_base_unit)
+    }
+
+    const fn from_base_unit(base_unit: i64) -> Self {
+        Quantity {
+            base_unit,
+            unit: PhantomData,
+        }
+    }
+
+    fn to_base_unit(n: f64) -> i64 {
+        (n * U::conv_factor_base_unit() as f64) as i64
+    }
+
+    fn in_unit(&self) -> f64 {
+        self.base_unit as f64 / U::conv_factor_base_unit() as f64
+    }
+}
+
+/// Mean of integer base units, accumulated without overflow. `None` when there are none.
+pub(crate) fn average_base_units(base_units: impl Iterator<Item = i64>) -> Option<i64> {
+    let (sum, count) = base_units.fold((0_i128, 0_i128), |(sum, count), base_unit| {
+        (sum + i128::from(base_unit), count + 1)
+    });
+    (count > 0).then(|| (sum / count) as i64)
+}
+
+macro_rules! ImplQuantityFromNumber {
+    ($N:ty) => {
+        impl<D, U> From<$N> for Quantity<D, U>
+        where
+            D: Dimension,
+            U: Unit<Dimension = D>,
+        {
+            fn from(n: $N) -> Self {
+                Self::from_base_unit(Self::to_base_unit(n as f64))
+            }
+        }
+
+        impl<D, U> From<Quantity<D, U>> for $N
+        where
+            D: Dimension,
+            U: Unit<Dimension = D>,
+        {
+            fn from(q: Quantity<D, U>) -> $N {
+                q.in_unit() as $N
+            }
+        }
+
+        impl<D, U> From<&Quantity<D, U>> for $N
+        where
+            D: Dimension,
+            U: Unit<Dimension = D>,
+        {
+            fn from(q: &Quantity<D, U>) -> $N {
+                q.in_unit() as $N
+            }
         }
+    };
+}
 
-        macro_rules! ImplQuantityFromNumber {
-            ($N:ty) => {
-                impl<T> From<$N> for $quantity<T>
-                where
-                    T: $units,
-                {
-                    fn from(n: $N) -> Self {
-                        $quantity {
-                            base_unit: (n as f64 * T::conv_factor_base_unit() as f64) as i64,
-                            unit: PhantomData,
-                        }
-                    }
-                }
-
-                impl<T> From<$quantity<T>> for $N
-                where
-                    T: $units,
-                {
-                    fn from(t: $quantity<T>) -> $N {
-                        (t.base_unit as f64 / (T::conv_factor_base_unit() as f64)) as $N
-                    }
-                }
-
-                impl<T> From<&$quantity<T>> for $N
-                where
-                    T: $units,
-                {
-                    fn from(t: &$quantity<T>) -> $N {
-                        (t.base_unit as f64 / (T::conv_factor_base_unit() as f64)) as $N
-                    }
-                }
-            };
+macro_rules! ImplOpsForNumber {
+    ($N:ty) => {
+        impl<D, U> ops::Add<$N> for Quantity<D, U>
+        where
+            D: Dimension,
+            U: Unit<Dimension = D>,
+        {
+            type Output = Self;
+            fn add(self, rhs: $N) -> Self::Output {
+                Self::from_base_unit(self.base_unit + Self::to_base_unit(rhs as f64))
+            }
         }
 
-        macro_rules! ImplOpsForNumber {
-            ($N:ty) => {
-                impl<T> ops::Add<$N> for $quantity<T>
-                where
-                    T: $units,
-                {
-                    type Output = Self;
-                    fn add(self, rhs: $N) -> Self::Output {
-                        $quantity {
-                            base_unit: self.base_unit
-                                + (rhs as f64 * T::conv_factor_base_unit() as f64) as i64,
-                            unit: PhantomData,
-                        }
-                    }
-                }
-
-                impl<T> ops::Add<$quantity<T>> for $N
-                where
-                    T: $units,
-                {
-                    type Output = $quantity<T>;
-                    fn add(self, rhs: $quantity<T>) -> Self::Output {
-                        $quantity {
-                            base_unit: rhs.base_unit
-                                + (self as f64 * T::conv_factor_base_unit() as f64) as i64,
-                            unit: PhantomData,
-                        }
-                    }
-                }
-
-                impl<T> ops::Sub<$N> for $quantity<T>
-                where
-                    T: $units,
-                {
-                    type Output = Self;
-                    fn sub(self, rhs: $N) -> Self::Output {
-                        $quantity {
-                            base_unit: self.base_unit
-                                - (rhs as f64 * T::conv_factor_base_unit() as f64) as i64,
-                            unit: PhantomData,
-                        }
-                    }
-                }
-
-                impl<T> ops::Mul<$N> for $quantity<T>
-                where
-                    T: $units,
-                {
-                    type Output = Self;
-                    fn mul(self, rhs: $N) -> Self::Output {
-                        $quantity {
-                            base_unit: (rhs as f64 * self.base_unit as f64) as i64,
-                            unit: PhantomData,
-                        }
-                    }
-                }
-
-                impl<T> ops::Mul<$quantity<T>> for $N
-                where
-                    T: $units,
-                {
-                    type Output = $quantity<T>;
-                    fn mul(self, rhs: $quantity<T>) -> Self::Output {
-                        $quantity {
-                            base_unit: (self as f64 * rhs.base_unit as f64) as i64,
-                            unit: PhantomData,
-                        }
-                    }
-                }
-
-                impl<T> ops::Div<$N> for $quantity<T>
-                where
-                    T: $units,
-                {
-                    type Output = Self;
-                    fn div(self, rhs: $N) -> Self::Output {
-                        $quantity {
-                            base_unit: ((self.base_unit as f64) / rhs as f64) as i64,
-                            unit: PhantomData,
-                        }
-                    }
-                }
-
-                impl<T> ops::Div<$quantity<T>> for $N
-                where
-                    T: $units,
-                {
-                    type Output = $N;
-                    fn div(self, rhs: $quantity<T>) -> Self::Output {
-                        ((T::conv_factor_base_unit() as f64 * self as f64) / (rhs.base_unit as f64))
-                            as $N
-                    }
-                }
-            };
+        impl<D, U> ops::Add<Quantity<D, U>> for $N
+        where
+            D: Dimension,
+            U: Unit<Dimension = D>,
+        {
+            type Output = Quantity<D, U>;
+            fn add(self, rhs: Quantity<D, U>) -> Self::Output {
+                Quantity::from_base_unit(
+                    rhs.base_unit + Quantity::<D, U>::to_base_unit(self as f64),
+                )
+            }
         }
 
-        impl<'a, T1, T2> From<&'a $quantity<T1>> for $quantity<T2>
+        impl<D, U> ops::Sub<$N> for Quantity<D, U>
         where
-            T1: $units,
-            T2: $units,
+            D: Dimension,
+            U: Unit<Dimension = D>,
         {
-            fn from(t1: &'a $quantity<T1>) -> Self {
-                $quantity {
-                    base_unit: (t1.base_unit),
-                    unit: (PhantomData),
-                }
+            type Output = Self;
+            fn sub(self, rhs: $N) -> Self::Output {
+                Self::from_base_unit(self.base_unit - Self::to_base_unit(rhs as f64))
             }
         }
 
-        impl<T1, T2> ops::Div<$quantity<T2>> for $quantity<T1>
+        impl<D, U> ops::Mul<$N> for Quantity<D, U>
         where
-            T1: $units,
-            T2: $units,
+            D: Dimension,
+            U: Unit<Dimension = D>,
         {
-            type Output = f64;
-            fn div(self, rhs: $quantity<T2>) -> Self::Output {
-                (self.base_unit as f64) / (rhs.base_unit as f64)
+            type Output = Self;
+            fn mul(self, rhs: $N) -> Self::Output {
+                Self::from_base_unit((rhs as f64 * self.base_unit as f64) as i64)
             }
         }
 
-        impl<T1, T2> ops::Sub<$quantity<T2>> for $quantity<T1>
+        impl<D, U> ops::Mul<Quantity<D, U>> for $N
         where
-            T1: $units,
-            T2: $units,
+            D: Dimension,
+            U: Unit<Dimension = D>,
         {
-            type Output = $quantity<T1>;
-            fn sub(self, rhs: $quantity<T2>) -> Self::Output {
-                $quantity {
-                    base_unit: (self.base_unit - rhs.base_unit),
-                    unit: (PhantomData),
-                }
+            type Output = Quantity<D, U>;
+            fn mul(self, rhs: Quantity<D, U>) -> Self::Output {
+                Quantity::from_base_unit((self as f64 * rhs.base_unit as f64) as i64)
             }
         }
 
-        impl<T1, T2> PartialEq<$quantity<T1>> for $quantity<T2>
+        impl<D, U> ops::Div<$N> for Quantity<D, U>
         where
-            T1: $units,
-            T2: $units,
+            D: Dimension,
+            U: Unit<Dimension = D>,
         {
-            fn eq(&self, other: &$quantity<T1>) -> bool {
-                (self.base_unit - other.base_unit).abs() < $tolerance
+            type Output = Self;
+            fn div(self, rhs: $N) -> Self::Output {
+                Self::from_base_unit((self.base_unit as f64 / rhs as f64) as i64)
             }
         }
 
-        ImplQuantityFromNumber!(i64);
-        ImplQuantityFromNumber!(f64);
-        ImplOpsForNumber!(f64);
-        ImplOpsForNumber!(i64);
+        impl<D, U> ops::Div<Quantity<D, U>> for $N
+        where
+            D: Dimension,
+            U: Unit<Dimension = D>,
+        {
+            type Output = $N;
+            fn div(self, rhs: Quantity<D, U>) -> Self::Output {
+                ((U::conv_factor_base_unit() as f64 * self as f64) / (rhs.base_unit as f64)) as $N
+            }
+        }
     };
 }
+
+impl<'a, D, U1, U2> From<&'a Quantity<D, U1>> for Quantity<D, U2>
+where
+    D: Dimension,
+    U1: Unit<Dimension = D>,
+    U2: Unit<Dimension = D>,
+{
+    fn from(q: &'a Quantity<D, U1>) -> Self {
+        Self::from_base_unit(q.base_unit)
+    }
+}
+
+/// Dividing quantities of the same dimension gives a dimensionless ratio, whatever their units.
+impl<D, U1, U2> ops::Div<Quantity<D, U2>> for Quantity<D, U1>
+where
+    D: Dimension,
+    U1: Unit<Dimension = D>,
+    U2: Unit<Dimension = D>,
+{
+    type Output = f64;
+    fn div(self, rhs: Quantity<D, U2>) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<D, U1, U2> ops::Div<&Quantity<D, U2>> for &Quantity<D, U1>
+where
+    D: Dimension,
+    U1: Unit<Dimension = D>,
+    U2: Unit<Dimension = D>,
+{
+    type Output = f64;
+    fn div(self, rhs: &Quantity<D, U2>) -> Self::Output {
+        (self.base_unit as f64) / (rhs.base_unit as f64)
+    }
+}
+
+impl<D, U1, U2> ops::Sub<Quantity<D, U2>> for Quantity<D, U1>
+where
+    D: Dimension,
+    U1: Unit<Dimension = D>,
+    U2: Unit<Dimension = D>,
+{
+    type Output = Quantity<D, U1>;
+    fn sub(self, rhs: Quantity<D, U2>) -> Self::Output {
+        Self::from_base_unit(self.base_unit - rhs.base_unit)
+    }
+}
+
+impl<D, U> iter::Sum for Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::from_base_unit(iter.map(|q| q.base_unit).sum())
+    }
+}
+
+impl<'a, D, U> iter::Sum<&'a Quantity<D, U>> for Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::from_base_unit(iter.map(|q| q.base_unit).sum())
+    }
+}
+
+impl<D, U1, U2> PartialEq<Quantity<D, U1>> for Quantity<D, U2>
+where
+    D: Dimension,
+    U1: Unit<Dimension = D>,
+    U2: Unit<Dimension = D>,
+{
+    fn eq(&self, other: &Quantity<D, U1>) -> bool {
+        (self.base_unit - other.base_unit).abs() < D::TOLERANCE
+    }
+}
+
+ImplQuantityFromNumber!(i64);
+ImplQuantityFromNumber!(f64);
+ImplOpsForNumber!(f64);
+ImplOpsForNumber!(i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::quantities::{Pressure, SpecificEnthalpy};
+    use crate::units::{Atmosphere, BtuPerPound, KilojoulesPerKg, Pascal, Psi, UnitKind};
+
+    #[test]
+    fn ratios() {
+        let vap_pres = Pressure::<Pascal>::from(1_013.25);
+        let pres_ambient = Pressure::<Atmosphere>::from(1);
+        assert!((&vap_pres / &pres_ambient - 0.01).abs() < 1E-9);
+        assert!((Pressure::<Psi>::from(7.347_97) / pres_ambient - 0.5).abs() < 1E-6);
+        let ratio = SpecificEnthalpy::<KilojoulesPerKg>::from(46.52)
+            / SpecificEnthalpy::<BtuPerPound>::from(40);
+        assert!((ratio - 0.5).abs() < 1E-9);
+    }
+
+    #[test]
+    fn unit_introspection() {
+        assert_eq!(Pressure::<Pascal>::unit_abbreviation(), "Pa");
+        assert_eq!(Pressure::<Atmosphere>::unit_name(), "atmosphere");
+        assert_eq!(Pressure::<Psi>::from(14.7).unit(), UnitKind::Psi);
+    }
+
+    #[test]
+    fn quantize() {
+        let pres = Pressure::<Pascal>::from(101_325.4);
+        assert!((f64::from(pres.round_to(10.0)) - 101_330.0).abs() < 1E-9);
+        assert!((f64::from(pres.floor_to(10.0)) - 101_320.0).abs() < 1E-9);
+        assert!((f64::from(pres.ceil_to(0.5)) - 101_325.5).abs() < 1E-9);
+        let pres = Pressure::<Psi>::from(-14.66);
+        assert!((f64::from(pres.round_to(0.1)) + 14.7).abs() < 1E-6);
+        assert!((f64::from(pres.floor_to(0.1)) + 14.7).abs() < 1E-6);
+        assert!((f64::from(pres.ceil_to(0.1)) + 14.6).abs() < 1E-6);
+        assert_eq!(pres.round_to(0.0), pres);
+    }
+
+    #[test]
+    fn sum_and_average() {
+        let loads = [
+            Pressure::<Pascal>::from(100),
+            Pressure::<Pascal>::from(250),
+            Pressure::<Pascal>::from(300),
+        ];
+        assert_eq!(
+            loads.iter().sum::<Pressure<Pascal>>(),
+            Pressure::<Pascal>::from(650)
+        );
+        let average = Pressure::average(&loads).unwrap();
+        assert!((f64::from(average) - 216.666).abs() < 1E-3);
+        let owned: Pressure<Psi> = vec![Pressure::<Psi>::from(1), Pressure::<Psi>::from(2)]
+            .into_iter()
+            .sum();
+        assert_eq!(owned, Pressure::<Psi>::from(3));
+        assert!(Pressure::<Pascal>::average(&[]).is_none());
+    }
+}
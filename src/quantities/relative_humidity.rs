@@ -0,0 +1,76 @@
+use super::QuantityErr;
+
+/// Relative humidity, always within [0-1]. Constructors make the choice between a fraction and a
+/// percentage explicit so the two can't be mixed up.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct RelativeHumidity {
+    fraction: f64,
+}
+
+impl RelativeHumidity {
+    /// Relative humidity from a fraction [0-1].
+    /// Errors with `Value` when it is not finite and with `Range` when it is outside [0-1].
+    pub fn from_fraction(fraction: f64) -> Result<Self, QuantityErr> {
+        if !fraction.is_finite() {
+            return Err(QuantityErr::Value);
+        }
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(QuantityErr::Range);
+        }
+        Ok(RelativeHumidity { fraction })
+    }
+
+    /// Relative humidity from a percentage [0-100].
+    /// Errors with `Value` when it is not finite and with `Range` when it is outside [0-100].
+    pub fn from_percent(percent: f64) -> Result<Self, QuantityErr> {
+        Self::from_fraction(percent / 100.0)
+    }
+
+    /// Relative humidity as a fraction [0-1]
+    #[must_use]
+    pub fn as_fraction(&self) -> f64 {
+        self.fraction
+    }
+
+    /// Relative humidity as a percentage [0-100]
+    #[must_use]
+    pub fn as_percent(&self) -> f64 {
+        self.fraction * 100.0
+    }
+}
+
+impl From<RelativeHumidity> for f64 {
+    fn from(rel_hum: RelativeHumidity) -> f64 {
+        rel_hum.fraction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let a = RelativeHumidity::from_percent(45.5).unwrap();
+        let b = RelativeHumidity::from_fraction(0.455).unwrap();
+        assert!((a.as_fraction() - b.as_fraction()).abs() < 1E-12);
+        assert!((b.as_percent() - 45.5).abs() < 1E-12);
+        assert!((f64::from(a) - 0.455).abs() < 1E-12);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        assert!(matches!(
+            RelativeHumidity::from_fraction(45.5),
+            Err(QuantityErr::Range)
+        ));
+        assert!(matches!(
+            RelativeHumidity::from_percent(-1.0),
+            Err(QuantityErr::Range)
+        ));
+        assert!(matches!(
+            RelativeHumidity::from_percent(f64::NAN),
+            Err(QuantityErr::Value)
+        ));
+    }
+}
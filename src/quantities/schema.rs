@@ -0,0 +1,104 @@
+//! JSON Schemas of the quantities. Each is a bare number in its unit, as serialized with the
+//! `serde` feature, and is inlined where it is used. The unit abbreviation is kept in an `x-unit`
+//! keyword since the doc comment of a field replaces the description.
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use super::{Quantity, RelativeHumidity, Temperature};
+use crate::units::{Dimension, TemperatureUnit, Unit};
+
+impl<D, U> JsonSchema for Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Quantity_in_{:?}", U::kind()).into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "description": format!("in {}", U::abbreviation()),
+            "x-unit": U::abbreviation(),
+        })
+    }
+}
+
+impl<T: TemperatureUnit> JsonSchema for Temperature<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Temperature_in_{:?}", T::kind()).into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "description": format!("in {}", T::abbreviation()),
+            "x-unit": T::abbreviation(),
+        })
+    }
+}
+
+impl JsonSchema for RelativeHumidity {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "RelativeHumidity".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "description": "fraction [0-1]",
+            "minimum": 0.0,
+            "maximum": 1.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "process")]
+    use crate::process::CoilLeaving;
+    use crate::quantities::Pressure;
+    use crate::state::MoistAirState;
+    use crate::units::{KilojoulesPerKg, Psi};
+
+    #[test]
+    fn quantities() {
+        let schema = schemars::schema_for!(Pressure<Psi>);
+        assert_eq!(schema.get("type").unwrap(), "number");
+        assert_eq!(schema.get("description").unwrap(), "in psi");
+        let schema = schemars::schema_for!(RelativeHumidity);
+        assert_eq!(schema.get("maximum").unwrap(), 1.0);
+    }
+
+    #[test]
+    fn results() {
+        let schema = schemars::schema_for!(MoistAirState);
+        let properties = schema.get("properties").unwrap();
+        assert_eq!(properties["tdry_bulb"]["x-unit"], "C");
+        assert_eq!(properties["pres_ambient"]["x-unit"], "Pa");
+    }
+
+    #[cfg(feature = "process")]
+    #[test]
+    fn process_results() {
+        let schema = schemars::schema_for!(CoilLeaving<KilojoulesPerKg>);
+        let properties = schema.get("properties").unwrap();
+        assert_eq!(properties["total"]["x-unit"], "kj kg⁻¹");
+        assert_eq!(properties["leaving"]["$ref"], "#/$defs/MoistAirState");
+    }
+}
@@ -0,0 +1,241 @@
+//! Serde support. Quantities serialize as a bare number in their own unit. `serialize_in`,
+//! `deserialize_in` and `SerializeIn` pick another unit when the data is written, so values
+//! stored in SI can be sent in IP units and back. `as_string` writes them as text with the unit
+//! instead, for configuration files edited by hand.
+//! ```
+//! use psychrometry::quantities::{deserialize_in, serialize_in, Temperature};
+//! use psychrometry::units::{Celcius, Fahrenheit};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading {
+//!     #[serde(
+//!         serialize_with = "serialize_in::<Fahrenheit, _, _>",
+//!         deserialize_with = "deserialize_in::<Fahrenheit, _, _>"
+//!     )]
+//!     tdry_bulb: Temperature<Celcius>,
+//! }
+//! ```
+use core::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Quantity, RelativeHumidity, Temperature};
+use crate::units::{Dimension, TemperatureUnit, Unit};
+
+/// Quantity that can be expressed in the unit `U`.
+pub trait InUnit<U> {
+    /// Value in the unit `U`.
+    fn value_in(&self) -> f64;
+    /// Quantity from its value in the unit `U`.
+    fn from_value_in(value: f64) -> Self;
+}
+
+impl<D, U1, U2> InUnit<U2> for Quantity<D, U1>
+where
+    D: Dimension,
+    U1: Unit<Dimension = D>,
+    U2: Unit<Dimension = D>,
+{
+    fn value_in(&self) -> f64 {
+        f64::from(Quantity::<D, U2>::from(self))
+    }
+
+    fn from_value_in(value: f64) -> Self {
+        Self::from(&Quantity::<D, U2>::from(value))
+    }
+}
+
+impl<T1, T2> InUnit<T2> for Temperature<T1>
+where
+    T1: TemperatureUnit,
+    T2: TemperatureUnit,
+{
+    fn value_in(&self) -> f64 {
+        f64::from(Temperature::<T2>::from(self))
+    }
+
+    fn from_value_in(value: f64) -> Self {
+        Self::from(&Temperature::<T2>::from(value))
+    }
+}
+
+/// Serialize `quantity` as a number in the unit `U`, for `#[serde(serialize_with)]`.
+pub fn serialize_in<U, Q, S>(quantity: &Q, serializer: S) -> Result<S::Ok, S::Error>
+where
+    Q: InUnit<U>,
+    S: Serializer,
+{
+    serializer.serialize_f64(quantity.value_in())
+}
+
+/// Deserialize a quantity from a number in the unit `U`, for `#[serde(deserialize_with)]`.
+pub fn deserialize_in<'de, U, Q, D>(deserializer: D) -> Result<Q, D::Error>
+where
+    Q: InUnit<U>,
+    D: Deserializer<'de>,
+{
+    f64::deserialize(deserializer).map(Q::from_value_in)
+}
+
+/// Quantities as text with their unit, such as "23.5 C", for `#[serde(with = "as_string")]`.
+/// Parsing follows `FromStr`.
+pub mod as_string {
+    use core::fmt::Display;
+    use core::str::FromStr;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serialize `quantity` as its `Display` text.
+    pub fn serialize<Q: Display, S: Serializer>(
+        quantity: &Q,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(quantity)
+    }
+
+    /// Deserialize a quantity from text with its unit.
+    pub fn deserialize<'de, Q, D>(deserializer: D) -> Result<Q, D::Error>
+    where
+        Q: FromStr,
+        Q::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Borrowed quantity that serializes as a number in the unit `U`.
+#[derive(Debug)]
+pub struct SerializeIn<'a, U, Q> {
+    quantity: &'a Q,
+    unit: PhantomData<U>,
+}
+
+impl<'a, U, Q: InUnit<U>> SerializeIn<'a, U, Q> {
+    /// Wrap `quantity` to serialize it in the unit `U`.
+    #[must_use]
+    pub fn new(quantity: &'a Q) -> Self {
+        SerializeIn {
+            quantity,
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<U, Q: InUnit<U>> Serialize for SerializeIn<'_, U, Q> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_in::<U, Q, S>(self.quantity, serializer)
+    }
+}
+
+impl<D, U> Serialize for Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(f64::from(self))
+    }
+}
+
+impl<'de, D, U> Deserialize<'de> for Quantity<D, U>
+where
+    D: Dimension,
+    U: Unit<Dimension = D>,
+{
+    fn deserialize<DE: Deserializer<'de>>(deserializer: DE) -> Result<Self, DE::Error> {
+        f64::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T: TemperatureUnit> Serialize for Temperature<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(f64::from(self))
+    }
+}
+
+impl<'de, T: TemperatureUnit> Deserialize<'de> for Temperature<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f64::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for RelativeHumidity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.as_fraction())
+    }
+}
+
+/// Fraction [0-1]. Errors when it is outside [0-1].
+impl<'de> Deserialize<'de> for RelativeHumidity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fraction = f64::deserialize(deserializer)?;
+        Self::from_fraction(fraction).map_err(|_| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Float(fraction),
+                &"a relative humidity fraction [0-1]",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Pressure;
+    use crate::units::{Celcius, Fahrenheit, Pascal, Psi};
+
+    #[derive(Serialize, Deserialize)]
+    struct Reading {
+        #[serde(
+            serialize_with = "serialize_in::<Fahrenheit, _, _>",
+            deserialize_with = "deserialize_in::<Fahrenheit, _, _>"
+        )]
+        tdry_bulb: Temperature<Celcius>,
+        pres_ambient: Pressure<Pascal>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "as_string")]
+        tsetpoint: Temperature<Celcius>,
+        #[serde(with = "as_string")]
+        pres_ambient: Pressure<Psi>,
+    }
+
+    #[test]
+    fn text() {
+        let config: Config =
+            serde_json::from_str(r#"{"tsetpoint":"23.5 °C","pres_ambient":"14.7 psi"}"#).unwrap();
+        assert_eq!(config.tsetpoint, Temperature::<Celcius>::from(23.5));
+        assert_eq!(config.pres_ambient, Pressure::<Psi>::from(14.7));
+        let json = serde_json::to_string(&config).unwrap();
+        let config: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(config.tsetpoint, Temperature::<Celcius>::from(23.5));
+        assert!(
+            serde_json::from_str::<Config>(r#"{"tsetpoint":"23.5 K","pres_ambient":"1"}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn chosen_unit() {
+        let reading = Reading {
+            tdry_bulb: Temperature::<Celcius>::from(25),
+            pres_ambient: Pressure::<Pascal>::from(101_325),
+        };
+        let json = serde_json::to_string(&reading).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!((value["tdry_bulb"].as_f64().unwrap() - 77.0).abs() < 1E-3);
+        assert!((value["pres_ambient"].as_f64().unwrap() - 101_325.0).abs() < 1E-9);
+        let reading: Reading = serde_json::from_str(&json).unwrap();
+        assert_eq!(reading.tdry_bulb, Temperature::<Celcius>::from(25));
+        assert_eq!(reading.pres_ambient, Pressure::<Pascal>::from(101_325));
+        let psi = serde_json::to_string(&SerializeIn::<Psi, _>::new(&reading.pres_ambient));
+        assert!((psi.unwrap().parse::<f64>().unwrap() - 14.696).abs() < 1E-3);
+        let rel_hum: RelativeHumidity = serde_json::from_str("0.45").unwrap();
+        assert_eq!(serde_json::to_string(&rel_hum).unwrap(), "0.45");
+        assert!(serde_json::from_str::<RelativeHumidity>("45").is_err());
+    }
+}
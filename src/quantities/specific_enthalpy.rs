@@ -1,11 +1,21 @@
-use crate::units::SpecificEnthalpyUnit;
-use crate::NewQuantity;
+use super::Quantity;
+use crate::units::{dimension, SpecificEnthalpyUnit};
 
-use core::cmp;
-use core::marker::PhantomData;
-use core::ops;
+pub type SpecificEnthalpy<U> = Quantity<dimension::SpecificEnthalpy, U>;
 
-NewQuantity!(SpecificEnthalpy, SpecificEnthalpyUnit, 200);
+impl<U: SpecificEnthalpyUnit> SpecificEnthalpy<U> {
+    /// Specific enthalpy from its exact internal value in mJ kg⁻¹.
+    #[must_use]
+    pub const fn from_milli_joules_per_kg(milli_joules_per_kg: i64) -> Self {
+        Self::from_base_units(milli_joules_per_kg)
+    }
+
+    /// Exact internal value in mJ kg⁻¹ whatever the unit `U`.
+    #[must_use]
+    pub const fn as_milli_joules_per_kg(&self) -> i64 {
+        self.as_base_units()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -23,4 +33,11 @@ mod tests {
         assert_eq!(ea, eb);
         assert_eq!(eb, ec);
     }
+
+    #[test]
+    fn raw_value() {
+        let enthalpy = SpecificEnthalpy::<KilojoulesPerKg>::from_milli_joules_per_kg(47_015_610);
+        assert_eq!(enthalpy.as_milli_joules_per_kg(), 47_015_610);
+        assert_eq!(enthalpy, SpecificEnthalpy::<JoulesPerKg>::from(47_015.61));
+    }
 }
@@ -0,0 +1,22 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type SpecificEntropy<U> = Quantity<dimension::SpecificEntropy, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{BtuPerPoundRankine, JoulesPerKgKelvin, KilojoulesPerKgKelvin};
+
+    #[test]
+    fn create() {
+        let a = 0.5; //kj/kg/K
+        let b = 0.119_423; //btu/lb/R
+        let ea = SpecificEntropy::<KilojoulesPerKgKelvin>::from(a);
+        let eb = SpecificEntropy::<BtuPerPoundRankine>::from(b);
+        let c = 500; //j/kg/K
+        let ec = SpecificEntropy::<JoulesPerKgKelvin>::from(c);
+        assert_eq!(ea, eb);
+        assert_eq!(eb, ec);
+    }
+}
@@ -0,0 +1,23 @@
+use super::Quantity;
+use crate::units::dimension;
+
+/// Same dimension as specific entropy so the units are shared.
+pub type SpecificHeat<U> = Quantity<dimension::SpecificEntropy, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{BtuPerPoundRankine, JoulesPerKgKelvin, KilojoulesPerKgKelvin};
+
+    #[test]
+    fn create() {
+        let a = 1.006; //kj/kg/K
+        let b = 0.240_279; //btu/lb/R
+        let ca = SpecificHeat::<KilojoulesPerKgKelvin>::from(a);
+        let cb = SpecificHeat::<BtuPerPoundRankine>::from(b);
+        let c = 1006; //j/kg/K
+        let cc = SpecificHeat::<JoulesPerKgKelvin>::from(c);
+        assert_eq!(ca, cb);
+        assert_eq!(cb, cc);
+    }
+}
@@ -0,0 +1,20 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type SpecificVolume<U> = Quantity<dimension::SpecificVolume, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{CubicFeetPerPound, CubicMetersPerKg};
+
+    #[test]
+    fn create() {
+        let a = 0.8301; //m³/kg
+        let b = 13.297; //ft³/lb
+        let va = SpecificVolume::<CubicMetersPerKg>::from(a);
+        let vb = SpecificVolume::<CubicFeetPerPound>::from(b);
+        assert!((f64::from(SpecificVolume::<CubicMetersPerKg>::from(&vb)) - a).abs() < 1E-4);
+        assert!((f64::from(SpecificVolume::<CubicFeetPerPound>::from(&va)) - b).abs() < 1E-3);
+    }
+}
@@ -2,7 +2,9 @@ use core::cmp;
 use core::marker::PhantomData;
 use core::ops;
 
-use crate::units::TemperatureUnit;
+use super::quantities_base::average_base_units;
+use super::QuantityErr;
+use crate::units::{TemperatureUnit, UnitKind};
 
 const TEMP_TOLERANCE: i64 = 200; //Microkelvins
 #[derive(Debug)]
@@ -49,6 +51,107 @@ macro_rules! ImplTemperatureFromNumber {
 ImplTemperatureFromNumber!(i64);
 ImplTemperatureFromNumber!(f64);
 
+impl<T> Temperature<T>
+where
+    T: crate::units::TemperatureUnit,
+{
+    /// Temperature from its exact internal value in µK. Inverse of `as_micro_kelvin`, for
+    /// persistence and FFI that must round trip without going through `f64`.
+    #[must_use]
+    pub const fn from_micro_kelvin(micro_kelvin: i64) -> Self {
+        Temperature {
+            micro_kelvin,
+            unit: PhantomData,
+        }
+    }
+
+    /// Exact internal value in µK whatever the unit `T`.
+    #[must_use]
+    pub const fn as_micro_kelvin(&self) -> i64 {
+        self.micro_kelvin
+    }
+
+    /// Name of the unit `T`, such as "kelvin".
+    #[must_use]
+    pub fn unit_name() -> &'static str {
+        T::singular_name()
+    }
+
+    /// Abbreviation of the unit `T`, such as "K".
+    #[must_use]
+    pub fn unit_abbreviation() -> &'static str {
+        T::abbreviation()
+    }
+
+    /// Unit `T` as a runtime value.
+    #[must_use]
+    pub fn unit(&self) -> UnitKind {
+        T::kind()
+    }
+
+    /// Nearest multiple of `step` in the unit `T`, such as 0.1 °C. Multiples are counted from the
+    /// zero of `T`, not from absolute zero. Unchanged when `step` is not positive.
+    #[must_use]
+    pub fn round_to(&self, step: f64) -> Self {
+        self.quantize(step, f64::round)
+    }
+
+    /// Largest multiple of `step` in the unit `T` not above the temperature. Unchanged when `step`
+    /// is not positive.
+    #[must_use]
+    pub fn floor_to(&self, step: f64) -> Self {
+        self.quantize(step, f64::floor)
+    }
+
+    /// Smallest multiple of `step` in the unit `T` not below the temperature. Unchanged when
+    /// `step` is not positive.
+    #[must_use]
+    pub fn ceil_to(&self, step: f64) -> Self {
+        self.quantize(step, f64::ceil)
+    }
+
+    fn quantize(&self, step: f64, to_integer: fn(f64) -> f64) -> Self {
+        if !(step > 0.0 && step.is_finite()) {
+            return Self::from_micro_kelvin(self.micro_kelvin);
+        }
+        let offset = T::conv_offset_micro_kelvin();
+        let step = step * T::conv_factor_micro_kelvin() as f64;
+        let from_zero = (to_integer((self.micro_kelvin - offset) as f64 / step) * step).round();
+        Self::from_micro_kelvin(offset + from_zero as i64)
+    }
+
+    /// Arithmetic mean of `temperatures`, taken on the absolute scale so the result is the same in
+    /// every unit. Temperatures don't add up, so there is no `Sum`. `None` when there are none.
+    #[must_use]
+    pub fn average<'a, I>(temperatures: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        average_base_units(temperatures.into_iter().map(|t| t.micro_kelvin))
+            .map(Self::from_micro_kelvin)
+    }
+
+    /// Checked alternative to `from`. Errors with `Value` when `value` is not finite and with
+    /// `Range` when it is below absolute zero or too large to be stored in the unit. There are no
+    /// tighter bounds such as -100 °C to 200 °C: the valid range depends on the formula, so each
+    /// function checks its own, and `TemperatureRange::Extended` goes up to 300 °C.
+    pub fn try_new(value: f64) -> Result<Self, QuantityErr> {
+        if !value.is_finite() {
+            return Err(QuantityErr::Value);
+        }
+        let micro_kelvin =
+            value * T::conv_factor_micro_kelvin() as f64 + T::conv_offset_micro_kelvin() as f64;
+        if !(0.0..i64::MAX as f64).contains(&micro_kelvin) {
+            return Err(QuantityErr::Range);
+        }
+        Ok(Temperature {
+            micro_kelvin: micro_kelvin as i64,
+            unit: PhantomData,
+        })
+    }
+}
+
 impl<'a, T1, T2> From<&'a Temperature<T1>> for Temperature<T2>
 where
     T1: crate::units::TemperatureUnit,
@@ -227,4 +330,71 @@ mod tests {
         assert!((f64::from(tf_from_k) - b).abs() < 0.000_2);
         assert!((f64::from(tk) - c).abs() < 0.000_2);
     }
+
+    #[test]
+    fn try_new_temperatures() {
+        assert!(Temperature::<Celcius>::try_new(-273.0).is_ok());
+        assert!(matches!(
+            Temperature::<Celcius>::try_new(-274.0),
+            Err(QuantityErr::Range)
+        ));
+        assert!(matches!(
+            Temperature::<Fahrenheit>::try_new(-460.0),
+            Err(QuantityErr::Range)
+        ));
+        assert!(matches!(
+            Temperature::<Kelvin>::try_new(-0.1),
+            Err(QuantityErr::Range)
+        ));
+        assert!(matches!(
+            Temperature::<Kelvin>::try_new(f64::NAN),
+            Err(QuantityErr::Value)
+        ));
+        let tc = Temperature::<Celcius>::try_new(21.5).unwrap();
+        assert_eq!(tc, Temperature::<Celcius>::from(21.5));
+    }
+
+    #[test]
+    fn raw_value() {
+        let tc = Temperature::<Celcius>::from(21.5);
+        assert_eq!(tc.as_micro_kelvin(), 294_650_000);
+        let tf = Temperature::<Fahrenheit>::from_micro_kelvin(tc.as_micro_kelvin());
+        assert_eq!(tf.as_micro_kelvin(), 294_650_000);
+        assert_eq!(tf, tc);
+    }
+
+    #[test]
+    fn unit_introspection() {
+        assert_eq!(Temperature::<Fahrenheit>::unit_abbreviation(), "F");
+        assert_eq!(Temperature::<Kelvin>::unit_name(), "kelvin");
+        assert_eq!(Temperature::<Celcius>::from(20).unit(), UnitKind::Celcius);
+    }
+
+    #[test]
+    fn quantize() {
+        let tc = Temperature::<Celcius>::from(21.46);
+        assert!((f64::from(tc.round_to(0.1)) - 21.5).abs() < 1E-9);
+        assert!((f64::from(tc.floor_to(0.1)) - 21.4).abs() < 1E-9);
+        assert!((f64::from(tc.ceil_to(0.5)) - 21.5).abs() < 1E-9);
+        let tk = Temperature::<Kelvin>::from(&tc);
+        assert!((f64::from(tk.round_to(0.1)) - 294.6).abs() < 1E-9);
+        let tf = Temperature::<Fahrenheit>::from(-40.26);
+        assert!((f64::from(tf.round_to(0.1)) + 40.3).abs() < 1E-5);
+        assert!((f64::from(tf.ceil_to(1.0)) + 40.0).abs() < 1E-5);
+        assert_eq!(tf.round_to(-1.0), tf);
+    }
+
+    #[test]
+    fn average() {
+        let samples = [
+            Temperature::<Fahrenheit>::from(50),
+            Temperature::<Fahrenheit>::from(70),
+            Temperature::<Fahrenheit>::from(72),
+        ];
+        let average = Temperature::average(&samples).unwrap();
+        assert!((f64::from(&average) - 64.0).abs() < 1E-3);
+        let tc = Temperature::<Celcius>::from(&average);
+        assert!((f64::from(&tc) - 17.777_8).abs() < 1E-3);
+        assert!(Temperature::<Celcius>::average(&[]).is_none());
+    }
 }
@@ -0,0 +1,73 @@
+use super::{
+    Density, HumidityRatio, Pressure, RelativeHumidity, SpecificEnthalpy, SpecificEntropy,
+    Temperature,
+};
+use crate::units::{
+    Celcius, JoulesPerKg, JoulesPerKgKelvin, KgPerCubicMeter, KgPerKg, Pascal, TemperatureUnit,
+    Unit, UnitKind,
+};
+
+/// Quantity whose dimension is only known at runtime, as returned by property queries. Each
+/// variant holds the quantity in its SI unit.
+#[derive(Debug, PartialEq)]
+pub enum QuantityValue {
+    /// Temperature in °C
+    Temperature(Temperature<Celcius>),
+    /// Humidity ratio in kg_H₂O kg_Air⁻¹
+    HumidityRatio(HumidityRatio<KgPerKg>),
+    /// Relative humidity [0-1]
+    RelativeHumidity(RelativeHumidity),
+    /// Pressure in Pa
+    Pressure(Pressure<Pascal>),
+    /// Specific enthalpy in J kg⁻¹
+    SpecificEnthalpy(SpecificEnthalpy<JoulesPerKg>),
+    /// Specific entropy in J kg⁻¹ K⁻¹
+    SpecificEntropy(SpecificEntropy<JoulesPerKgKelvin>),
+    /// Density in kg m⁻³
+    Density(Density<KgPerCubicMeter>),
+}
+
+impl QuantityValue {
+    /// Value in the unit returned by `unit`, relative humidity as a fraction [0-1].
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        match self {
+            QuantityValue::Temperature(quantity) => f64::from(quantity),
+            QuantityValue::HumidityRatio(quantity) => f64::from(quantity),
+            QuantityValue::RelativeHumidity(quantity) => quantity.as_fraction(),
+            QuantityValue::Pressure(quantity) => f64::from(quantity),
+            QuantityValue::SpecificEnthalpy(quantity) => f64::from(quantity),
+            QuantityValue::SpecificEntropy(quantity) => f64::from(quantity),
+            QuantityValue::Density(quantity) => f64::from(quantity),
+        }
+    }
+
+    /// Unit of the value, `None` for relative humidity which has none.
+    #[must_use]
+    pub fn unit(&self) -> Option<UnitKind> {
+        match self {
+            QuantityValue::Temperature(_) => Some(Celcius::kind()),
+            QuantityValue::HumidityRatio(_) => Some(KgPerKg::kind()),
+            QuantityValue::RelativeHumidity(_) => None,
+            QuantityValue::Pressure(_) => Some(Pascal::kind()),
+            QuantityValue::SpecificEnthalpy(_) => Some(JoulesPerKg::kind()),
+            QuantityValue::SpecificEntropy(_) => Some(JoulesPerKgKelvin::kind()),
+            QuantityValue::Density(_) => Some(KgPerCubicMeter::kind()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value() {
+        let value = QuantityValue::Temperature(Temperature::<Celcius>::from(21.5));
+        assert!((value.value() - 21.5).abs() < 1E-9);
+        assert_eq!(value.unit(), Some(UnitKind::Celcius));
+        let value = QuantityValue::RelativeHumidity(RelativeHumidity::from_percent(40.0).unwrap());
+        assert!((value.value() - 0.4).abs() < 1E-12);
+        assert_eq!(value.unit(), None);
+    }
+}
@@ -0,0 +1,22 @@
+use super::Quantity;
+use crate::units::dimension;
+
+pub type VolumetricFlowRate<U> = Quantity<dimension::VolumetricFlowRate, U>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{CubicFeetPerMinute, CubicMetersPerHour, LitersPerSecond};
+
+    #[test]
+    fn create() {
+        let a = 1000; //cfm
+        let b = 471.947_443_2; //L/s
+        let c = 1_699.010_795_5; //m³/h
+        let qa = VolumetricFlowRate::<CubicFeetPerMinute>::from(a);
+        let qb = VolumetricFlowRate::<LitersPerSecond>::from(b);
+        let qc = VolumetricFlowRate::<CubicMetersPerHour>::from(c);
+        assert_eq!(qa, qb);
+        assert_eq!(qb, qc);
+    }
+}
@@ -0,0 +1,178 @@
+//! Random moist air states for Monte Carlo sizing studies and load analysis. States are drawn
+//! uniformly from a range of dry bulb temperature and relative humidity, so every sample is a
+//! consistent state at the given ambient pressure.
+use rand::distr::Distribution;
+use rand::{Rng, RngExt};
+
+use crate::psychrolib::PsychroLibErr;
+use crate::quantities::{Pressure, RelativeHumidity, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Pascal, PressureUnit, TemperatureUnit};
+
+/// Broad climate of a site. The ranges are rough annual spans of outdoor air meant for
+/// exploratory studies, not design data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClimateZone {
+    /// Hot and humid all year, 20 °C to 35 °C and 60 % to 95 %.
+    Tropical,
+    /// Hot days, cool nights and dry air, 5 °C to 45 °C and 5 % to 40 %.
+    Arid,
+    /// Warm summers and cool winters, -5 °C to 30 °C and 40 % to 90 %.
+    #[default]
+    Temperate,
+    /// Long freezing winters, -25 °C to 25 °C and 40 % to 90 %.
+    Cold,
+    /// Conditioned indoor air, 18 °C to 26 °C and 30 % to 60 %.
+    Indoor,
+}
+
+/// Ranges of dry bulb temperature and relative humidity to sample from.
+#[derive(Debug)]
+pub struct ClimateRange {
+    /// Lowest and highest dry bulb temperature
+    pub tdry_bulb: (Temperature<Celcius>, Temperature<Celcius>),
+    /// Lowest and highest relative humidity
+    pub rel_hum: (RelativeHumidity, RelativeHumidity),
+}
+
+impl ClimateRange {
+    /// Range from the lowest and highest dry bulb temperature in °F  or °C or K and relative
+    /// humidity.
+    #[must_use]
+    pub fn new<T: TemperatureUnit>(
+        tdry_bulb: (Temperature<T>, Temperature<T>),
+        rel_hum: (RelativeHumidity, RelativeHumidity),
+    ) -> Self {
+        ClimateRange {
+            tdry_bulb: (
+                Temperature::<Celcius>::from(&tdry_bulb.0),
+                Temperature::<Celcius>::from(&tdry_bulb.1),
+            ),
+            rel_hum,
+        }
+    }
+}
+
+impl From<ClimateZone> for ClimateRange {
+    fn from(zone: ClimateZone) -> Self {
+        let (tmin, tmax, rel_hum_min, rel_hum_max) = match zone {
+            ClimateZone::Tropical => (20.0, 35.0, 0.60, 0.95),
+            ClimateZone::Arid => (5.0, 45.0, 0.05, 0.40),
+            ClimateZone::Temperate => (-5.0, 30.0, 0.40, 0.90),
+            ClimateZone::Cold => (-25.0, 25.0, 0.40, 0.90),
+            ClimateZone::Indoor => (18.0, 26.0, 0.30, 0.60),
+        };
+        let rel_hum = |fraction| {
+            // The fractions above are within [0-1].
+            RelativeHumidity::from_fraction(fraction).unwrap_or_else(|_| unreachable!())
+        };
+        ClimateRange {
+            tdry_bulb: (
+                Temperature::<Celcius>::from(tmin),
+                Temperature::<Celcius>::from(tmax),
+            ),
+            rel_hum: (rel_hum(rel_hum_min), rel_hum(rel_hum_max)),
+        }
+    }
+}
+
+/// Uniform distribution of moist air states over a `ClimateRange` at a fixed ambient pressure.
+#[derive(Debug)]
+pub struct StateDistribution {
+    tdry_bulb_c: (f64, f64),
+    rel_hum: (f64, f64),
+    pres_ambient: Pressure<Pascal>,
+}
+
+impl StateDistribution {
+    /// Distribution over `range` at `pres_ambient`. Errors with `Range` when a lower bound is
+    /// above its upper bound, and with the error of `MoistAirState::from_rel_hum` when the
+    /// warmest and most humid corner of the range is not a valid state.
+    /// `pres_ambient` Ambient pressure in Psi  or Pa or atm
+    pub fn new<P: PressureUnit>(
+        range: &ClimateRange,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Self, PsychroLibErr> {
+        let tdry_bulb_c = (f64::from(&range.tdry_bulb.0), f64::from(&range.tdry_bulb.1));
+        let rel_hum = (range.rel_hum.0.as_fraction(), range.rel_hum.1.as_fraction());
+        if tdry_bulb_c.0 > tdry_bulb_c.1 || rel_hum.0 > rel_hum.1 {
+            return Err(PsychroLibErr::Range);
+        }
+        let pres_ambient = Pressure::<Pascal>::from(&pres_ambient);
+        // Every other state of the range holds less water, so it is valid as well.
+        for tdry_bulb in [tdry_bulb_c.0, tdry_bulb_c.1] {
+            MoistAirState::from_rel_hum(
+                Temperature::<Celcius>::from(tdry_bulb),
+                rel_hum.1,
+                Pressure::<Pascal>::from(&pres_ambient),
+            )?;
+        }
+        Ok(StateDistribution {
+            tdry_bulb_c,
+            rel_hum,
+            pres_ambient,
+        })
+    }
+}
+
+impl Distribution<MoistAirState> for StateDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MoistAirState {
+        let tdry_bulb = rng.random_range(self.tdry_bulb_c.0..=self.tdry_bulb_c.1);
+        let rel_hum = rng.random_range(self.rel_hum.0..=self.rel_hum.1);
+        MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(tdry_bulb),
+            rel_hum,
+            Pressure::<Pascal>::from(&self.pres_ambient),
+        )
+        // Checked when the distribution was built.
+        .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Fahrenheit;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn climate_zone() {
+        let distribution = StateDistribution::new(
+            &ClimateRange::from(ClimateZone::Tropical),
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        for state in distribution.sample_iter(&mut rng).take(500) {
+            assert!((20.0..=35.0).contains(&state.tdry_bulb_c()));
+            let rel_hum = state.rel_hum().unwrap();
+            assert!((0.6 - 1E-6..=0.95 + 1E-6).contains(&rel_hum));
+        }
+    }
+
+    #[test]
+    fn user_range() {
+        let rel_hum = |percent| RelativeHumidity::from_percent(percent).unwrap();
+        let range = ClimateRange::new(
+            (
+                Temperature::<Fahrenheit>::from(90),
+                Temperature::<Fahrenheit>::from(60),
+            ),
+            (rel_hum(30.0), rel_hum(50.0)),
+        );
+        let pres = || Pressure::<Pascal>::from(101_325);
+        assert!(matches!(
+            StateDistribution::new(&range, pres()),
+            Err(PsychroLibErr::Range)
+        ));
+        let hot = ClimateRange::new(
+            (
+                Temperature::<Celcius>::from(20),
+                Temperature::<Celcius>::from(250),
+            ),
+            (rel_hum(30.0), rel_hum(50.0)),
+        );
+        assert!(StateDistribution::new(&hot, pres()).is_err());
+    }
+}
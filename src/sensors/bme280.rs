@@ -0,0 +1,73 @@
+use crate::psychrolib::PsychroLibErr;
+use crate::quantities::{Pressure, RelativeHumidity, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Pascal};
+
+/// Compensated output of a Bosch BME280, or of a BMP280 which has no humidity sensor, as returned
+/// by the integer compensation formulas of the datasheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bme280Output {
+    /// Temperature in 0.01 °C, `int32_t` from `BME280_compensate_T_int32`.
+    pub temperature: i32,
+    /// Pressure in Pa, `uint32_t` from `BME280_compensate_P_int32`.
+    pub pressure: u32,
+    /// Relative humidity in %, Q22.10 fixed point (1024 = 1 %) `uint32_t` from
+    /// `bme280_compensate_H_int32`. `None` for a BMP280.
+    pub humidity: Option<u32>,
+}
+
+impl Bme280Output {
+    /// Dry bulb temperature
+    #[must_use]
+    pub fn tdry_bulb(&self) -> Temperature<Celcius> {
+        Temperature::<Celcius>::from(f64::from(self.temperature) / 100.0)
+    }
+
+    /// Ambient pressure
+    #[must_use]
+    pub fn pres_ambient(&self) -> Pressure<Pascal> {
+        Pressure::<Pascal>::from(i64::from(self.pressure))
+    }
+
+    /// Relative humidity, `None` for a BMP280. Errors with `Range` when the output is above 100 %.
+    pub fn rel_hum(&self) -> Result<Option<RelativeHumidity>, PsychroLibErr> {
+        self.humidity
+            .map(|humidity| RelativeHumidity::from_percent(f64::from(humidity) / 1024.0))
+            .transpose()
+            .map_err(PsychroLibErr::from)
+    }
+
+    /// Moist air state of the output, with the sensor's own pressure as ambient pressure. Errors
+    /// with `Value` for a BMP280, which doesn't measure humidity.
+    pub fn to_state(&self) -> Result<MoistAirState, PsychroLibErr> {
+        let rel_hum = self.rel_hum()?.ok_or(PsychroLibErr::Value)?;
+        MoistAirState::from_rel_hum(self.tdry_bulb(), rel_hum.as_fraction(), self.pres_ambient())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output() {
+        let output = Bme280Output {
+            temperature: 2_508,
+            pressure: 96_386,
+            humidity: Some(47_445),
+        };
+        assert!((f64::from(&output.tdry_bulb()) - 25.08).abs() < 1E-6);
+        assert_eq!(output.pres_ambient(), Pressure::<Pascal>::from(96_386));
+        let rel_hum = output.rel_hum().unwrap().unwrap();
+        assert!((rel_hum.as_percent() - 46.333).abs() < 1E-3);
+        let state = output.to_state().unwrap();
+        assert!((state.pres_ambient_pa() - 96_386.0).abs() < 1E-3);
+        assert!((state.rel_hum().unwrap() - 0.463_33).abs() < 1E-4);
+        let bmp280 = Bme280Output {
+            humidity: None,
+            ..output
+        };
+        assert!(bmp280.rel_hum().unwrap().is_none());
+        assert!(matches!(bmp280.to_state(), Err(PsychroLibErr::Value)));
+    }
+}
@@ -0,0 +1,72 @@
+use super::SensorReading;
+use crate::psychrolib::PsychroLibErr;
+use crate::quantities::{RelativeHumidity, Temperature};
+use crate::units::Celcius;
+
+/// Bit of the temperature word that marks temperatures below 0 °C.
+const SIGN_BIT: u16 = 0x8000;
+
+/// Return the dry bulb temperature from the 16 bit temperature word of a DHT22 or AM2302, in
+/// 0.1 °C. The word is sign and magnitude, not two's complement: the top bit marks a negative
+/// temperature and the other bits hold its magnitude.
+/// Reference: Aosong AM2302 datasheet sec. 7.3
+#[must_use]
+pub fn get_dht_tdry_bulb(raw: u16) -> Temperature<Celcius> {
+    let deci_celcius = f64::from(raw & !SIGN_BIT);
+    let deci_celcius = if raw & SIGN_BIT == 0 {
+        deci_celcius
+    } else {
+        -deci_celcius
+    };
+    Temperature::<Celcius>::from(deci_celcius / 10.0)
+}
+
+/// Return the relative humidity from the 16 bit humidity word of a DHT22 or AM2302, in 0.1 %.
+/// Errors with `Range` when the word is above 1000.
+/// Reference: Aosong AM2302 datasheet sec. 7.3
+pub fn get_dht_rel_hum(raw: u16) -> Result<RelativeHumidity, PsychroLibErr> {
+    Ok(RelativeHumidity::from_percent(f64::from(raw) / 10.0)?)
+}
+
+/// Return the reading of the 5 byte payload of a DHT22 or AM2302: the humidity word, the
+/// temperature word and a checksum, the low byte of the sum of the first four bytes. Errors with
+/// `Value` when the checksum doesn't match.
+/// Reference: Aosong AM2302 datasheet sec. 7.3
+pub fn get_dht_reading(payload: &[u8; 5]) -> Result<SensorReading, PsychroLibErr> {
+    let checksum = payload[..4]
+        .iter()
+        .fold(0_u8, |sum, byte| sum.wrapping_add(*byte));
+    if checksum != payload[4] {
+        return Err(PsychroLibErr::Value);
+    }
+    Ok(SensorReading {
+        tdry_bulb: get_dht_tdry_bulb(u16::from_be_bytes([payload[2], payload[3]])),
+        rel_hum: get_dht_rel_hum(u16::from_be_bytes([payload[0], payload[1]]))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert!((f64::from(&get_dht_tdry_bulb(0x0115)) - 27.7).abs() < 1E-6);
+        assert!((f64::from(&get_dht_tdry_bulb(0x8065)) + 10.1).abs() < 1E-6);
+        assert!((get_dht_rel_hum(0x028C).unwrap().as_percent() - 65.2).abs() < 1E-9);
+        assert!(matches!(get_dht_rel_hum(1001), Err(PsychroLibErr::Range)));
+    }
+
+    #[test]
+    fn payload() {
+        let reading = get_dht_reading(&[0x02, 0x8C, 0x01, 0x5F, 0xEE]).unwrap();
+        assert!((reading.rel_hum.as_percent() - 65.2).abs() < 1E-9);
+        assert!((f64::from(&reading.tdry_bulb) - 35.1).abs() < 1E-6);
+        let reading = get_dht_reading(&[0x01, 0xF4, 0x80, 0x65, 0xDA]).unwrap();
+        assert!((f64::from(&reading.tdry_bulb) + 10.1).abs() < 1E-6);
+        assert!(matches!(
+            get_dht_reading(&[0x02, 0x8C, 0x01, 0x5F, 0xEF]),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
@@ -0,0 +1,16 @@
+//! Conversions from the raw output of common temperature and humidity sensors and of building
+//! automation registers into quantities and moist air states.
+mod bme280;
+pub use bme280::Bme280Output;
+
+mod dht;
+pub use dht::{get_dht_reading, get_dht_rel_hum, get_dht_tdry_bulb};
+
+mod reading;
+pub use reading::SensorReading;
+
+mod register;
+pub use register::{RegisterFormat, RegisterScaling};
+
+mod sht;
+pub use sht::{get_sht_reading, get_sht_rel_hum, get_sht_tdry_bulb, ShtSeries};
@@ -0,0 +1,28 @@
+use crate::psychrolib::PsychroLibErr;
+use crate::quantities::{Pressure, RelativeHumidity, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, PressureUnit};
+
+/// Dry bulb temperature and relative humidity read from a sensor.
+#[derive(Debug)]
+pub struct SensorReading {
+    /// Dry bulb temperature
+    pub tdry_bulb: Temperature<Celcius>,
+    /// Relative humidity
+    pub rel_hum: RelativeHumidity,
+}
+
+impl SensorReading {
+    /// Moist air state of the reading at the ambient pressure `pres_ambient`.
+    /// `pres_ambient` Ambient pressure in Psi  or Pa or atm
+    pub fn to_state<P: PressureUnit>(
+        &self,
+        pres_ambient: Pressure<P>,
+    ) -> Result<MoistAirState, PsychroLibErr> {
+        MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(&self.tdry_bulb),
+            self.rel_hum.as_fraction(),
+            pres_ambient,
+        )
+    }
+}
@@ -0,0 +1,106 @@
+use crate::psychrolib::PsychroLibErr;
+
+/// How a 16 bit register holds its raw count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RegisterFormat {
+    /// 0 to 65535.
+    #[default]
+    Unsigned,
+    /// -32768 to 32767, two's complement.
+    Signed,
+}
+
+/// Linear scaling between the raw count of a 16 bit Modbus or analog input register and a value in
+/// engineering units: value = count × `scale` + `offset`, in the unit of the quantity it holds.
+/// For example a temperature register in 0.1 °C is decoded as `Temperature<Celcius>` with a scale
+/// of 0.1 and no offset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegisterScaling {
+    /// Engineering units per count
+    pub scale: f64,
+    /// Engineering value at a count of zero
+    pub offset: f64,
+    /// Range of the raw count
+    pub format: RegisterFormat,
+}
+
+impl RegisterScaling {
+    /// Quantity held by the register word `register`.
+    #[must_use]
+    pub fn decode<Q: From<f64>>(&self, register: u16) -> Q {
+        let count = match self.format {
+            RegisterFormat::Unsigned => f64::from(register),
+            RegisterFormat::Signed => f64::from(register as i16),
+        };
+        Q::from(count * self.scale + self.offset)
+    }
+
+    /// Register word holding `quantity`, to the nearest count. Errors with `Value` when the
+    /// scale is zero or not finite and with `Range` when the count doesn't fit the register.
+    pub fn encode<'a, Q>(&self, quantity: &'a Q) -> Result<u16, PsychroLibErr>
+    where
+        f64: From<&'a Q>,
+    {
+        if self.scale == 0.0 || !self.scale.is_finite() {
+            return Err(PsychroLibErr::Value);
+        }
+        let count = ((f64::from(quantity) - self.offset) / self.scale).round();
+        let range = match self.format {
+            RegisterFormat::Unsigned => 0.0..=65_535.0,
+            RegisterFormat::Signed => -32_768.0..=32_767.0,
+        };
+        if !range.contains(&count) {
+            return Err(PsychroLibErr::Range);
+        }
+        Ok(match self.format {
+            RegisterFormat::Unsigned => count as u16,
+            RegisterFormat::Signed => count as i16 as u16,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::{Pressure, Temperature};
+    use crate::units::{Celcius, Fahrenheit, Pascal};
+
+    #[test]
+    fn signed_temperature() {
+        let scaling = RegisterScaling {
+            scale: 0.1,
+            offset: 0.0,
+            format: RegisterFormat::Signed,
+        };
+        let tdry_bulb: Temperature<Celcius> = scaling.decode(0xFF9B);
+        assert!((f64::from(&tdry_bulb) + 10.1).abs() < 1E-6);
+        assert_eq!(scaling.encode(&tdry_bulb).unwrap(), 0xFF9B);
+        let tf = Temperature::<Fahrenheit>::from(&tdry_bulb);
+        assert!(matches!(scaling.encode(&tf), Ok(0x008A)));
+    }
+
+    #[test]
+    fn scaled_pressure() {
+        let scaling = RegisterScaling {
+            scale: 2.0,
+            offset: 80_000.0,
+            format: RegisterFormat::Unsigned,
+        };
+        let pres: Pressure<Pascal> = scaling.decode(10_662);
+        assert_eq!(pres, Pressure::<Pascal>::from(101_324));
+        let register = scaling.encode(&Pressure::<Pascal>::from(101_325));
+        assert_eq!(register.unwrap(), 10_663);
+        assert!(matches!(
+            scaling.encode(&Pressure::<Pascal>::from(70_000)),
+            Err(PsychroLibErr::Range)
+        ));
+        let zero = RegisterScaling {
+            scale: 0.0,
+            ..scaling
+        };
+        assert!(matches!(
+            zero.encode(&Pressure::<Pascal>::from(90_000)),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
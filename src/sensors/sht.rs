@@ -0,0 +1,113 @@
+use super::SensorReading;
+use crate::psychrolib::PsychroLibErr;
+use crate::quantities::{RelativeHumidity, Temperature};
+use crate::units::Celcius;
+
+/// Full scale of the 16 bit words, 2¹⁶ - 1.
+const FULL_SCALE: f64 = 65_535.0;
+/// Generator polynomial of the CRC-8 sent after each word, x⁸ + x⁵ + x⁴ + 1.
+const CRC_POLYNOMIAL: u8 = 0x31;
+/// Initial value of the CRC-8 sent after each word.
+const CRC_INIT: u8 = 0xFF;
+
+/// Sensirion humidity sensor family. The two share the temperature conversion but not the
+/// humidity one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShtSeries {
+    /// SHT30, SHT31 and SHT35.
+    #[default]
+    Sht3x,
+    /// SHT40, SHT41, SHT43 and SHT45.
+    Sht4x,
+}
+
+/// Return the dry bulb temperature from the raw 16 bit temperature word of an SHT3x or SHT4x.
+/// Reference: Sensirion SHT3x-DIS datasheet (2019) sec. 4.13, SHT4x datasheet (2021) sec. 4.6
+#[must_use]
+pub fn get_sht_tdry_bulb(raw: u16) -> Temperature<Celcius> {
+    Temperature::<Celcius>::from(-45.0 + 175.0 * f64::from(raw) / FULL_SCALE)
+}
+
+/// Return the relative humidity from the raw 16 bit humidity word of a sensor of the `series`.
+/// SHT4x words can fall outside [0-100] %, they are cropped as the datasheet advises.
+/// Reference: Sensirion SHT3x-DIS datasheet (2019) sec. 4.13, SHT4x datasheet (2021) sec. 4.6
+#[must_use]
+pub fn get_sht_rel_hum(raw: u16, series: ShtSeries) -> RelativeHumidity {
+    let fraction = f64::from(raw) / FULL_SCALE;
+    let fraction = match series {
+        ShtSeries::Sht3x => fraction,
+        ShtSeries::Sht4x => (-0.06 + 1.25 * fraction).clamp(0.0, 1.0),
+    };
+    // Always within [0-1] for a 16 bit word.
+    RelativeHumidity::from_fraction(fraction).unwrap_or_else(|_| unreachable!())
+}
+
+/// Return the reading of the 6 byte measurement payload of a sensor of the `series`, as read over
+/// I²C: the temperature word, its CRC, the humidity word and its CRC. Errors with `Value` when a
+/// CRC doesn't match.
+/// Reference: Sensirion SHT3x-DIS datasheet (2019) sec. 4.12, SHT4x datasheet (2021) sec. 4.4
+pub fn get_sht_reading(
+    payload: &[u8; 6],
+    series: ShtSeries,
+) -> Result<SensorReading, PsychroLibErr> {
+    let word = |msb: usize| -> Result<u16, PsychroLibErr> {
+        let bytes = [payload[msb], payload[msb + 1]];
+        if crc8(&bytes) != payload[msb + 2] {
+            return Err(PsychroLibErr::Value);
+        }
+        Ok(u16::from_be_bytes(bytes))
+    };
+    Ok(SensorReading {
+        tdry_bulb: get_sht_tdry_bulb(word(0)?),
+        rel_hum: get_sht_rel_hum(word(3)?, series),
+    })
+}
+
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(CRC_INIT, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 == 0 {
+                crc << 1
+            } else {
+                (crc << 1) ^ CRC_POLYNOMIAL
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Pressure;
+    use crate::units::Pascal;
+
+    #[test]
+    fn conversions() {
+        assert!((f64::from(&get_sht_tdry_bulb(0)) + 45.0).abs() < 1E-6);
+        assert!((f64::from(&get_sht_tdry_bulb(0x6666)) - 25.0).abs() < 1E-3);
+        let rel_hum = get_sht_rel_hum(0x8000, ShtSeries::Sht3x);
+        assert!((rel_hum.as_percent() - 50.0).abs() < 1E-2);
+        let rel_hum = get_sht_rel_hum(0x8000, ShtSeries::Sht4x);
+        assert!((rel_hum.as_percent() - 56.5).abs() < 1E-2);
+        assert!(get_sht_rel_hum(0, ShtSeries::Sht4x).as_fraction().abs() < f64::EPSILON);
+        assert!((get_sht_rel_hum(u16::MAX, ShtSeries::Sht4x).as_fraction() - 1.0).abs() < 1E-12);
+    }
+
+    #[test]
+    fn payload() {
+        assert_eq!(crc8(&[0xBE, 0xEF]), 0x92);
+        let mut payload = [0x66, 0x66, 0, 0x80, 0x00, 0];
+        payload[2] = crc8(&payload[0..2]);
+        payload[5] = crc8(&payload[3..5]);
+        let reading = get_sht_reading(&payload, ShtSeries::Sht3x).unwrap();
+        assert!((f64::from(&reading.tdry_bulb) - 25.0).abs() < 1E-3);
+        assert!((reading.rel_hum.as_percent() - 50.0).abs() < 1E-2);
+        let state = reading.to_state(Pressure::<Pascal>::from(101_325)).unwrap();
+        assert!((state.rel_hum().unwrap() - 0.5).abs() < 1E-3);
+        payload[5] ^= 1;
+        assert!(matches!(
+            get_sht_reading(&payload, ShtSeries::Sht3x),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
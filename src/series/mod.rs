@@ -0,0 +1,240 @@
+//! Resampling of irregular time series to a fixed timestep, so sensor records can feed the
+//! accumulators and risk metrics that expect evenly spaced samples.
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Duration, Pressure, Quantity, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, Dimension, DurationUnit, Pascal, TemperatureUnit, Unit};
+
+/// How values between two samples are filled in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Hold the previous sample until the next one.
+    #[default]
+    Hold,
+    /// Linear between the previous and the next sample. Moist air states are interpolated in
+    /// enthalpy and humidity ratio, so mixing two states gives the same air as the interpolation.
+    Linear,
+}
+
+/// Values that can be interpolated between two samples.
+pub trait Interpolate: Sized {
+    /// Return the value `fraction` [0-1] of the way from `self` to `other`.
+    fn interpolate(&self, other: &Self, fraction: f64) -> Result<Self, PsychroLibErr>;
+}
+
+impl<D: Dimension, U: Unit<Dimension = D>> Interpolate for Quantity<D, U> {
+    fn interpolate(&self, other: &Self, fraction: f64) -> Result<Self, PsychroLibErr> {
+        let (from, to) = (self.as_base_units(), other.as_base_units());
+        let delta = ((to - from) as f64 * fraction).round() as i64;
+        Ok(Self::from_base_units(from + delta))
+    }
+}
+
+impl<T: TemperatureUnit> Interpolate for Temperature<T> {
+    fn interpolate(&self, other: &Self, fraction: f64) -> Result<Self, PsychroLibErr> {
+        let (from, to) = (self.as_micro_kelvin(), other.as_micro_kelvin());
+        let delta = ((to - from) as f64 * fraction).round() as i64;
+        Ok(Self::from_micro_kelvin(from + delta))
+    }
+}
+
+impl Interpolate for MoistAirState {
+    fn interpolate(&self, other: &Self, fraction: f64) -> Result<Self, PsychroLibErr> {
+        let lerp = |from: f64, to: f64| from + (to - from) * fraction;
+        let hum_ratio = lerp(self.hum_ratio(), other.hum_ratio());
+        let enthalpy = lerp(
+            si::moist_air_enthalpy(self.tdry_bulb_c(), self.hum_ratio()),
+            si::moist_air_enthalpy(other.tdry_bulb_c(), other.hum_ratio()),
+        );
+        MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(si::tdry_bulb_from_enthalpy_and_hum_ratio(
+                enthalpy, hum_ratio,
+            )),
+            hum_ratio,
+            Pressure::<Pascal>::from(lerp(self.pres_ambient_pa(), other.pres_ambient_pa())),
+        )
+    }
+}
+
+/// Value measured at a point in time.
+#[derive(Debug)]
+pub struct TimedSample<U: DurationUnit, S> {
+    /// Time of the measurement from any fixed origin
+    pub time: Duration<U>,
+    /// Measured value
+    pub value: S,
+}
+
+/// Value on the fixed timestep.
+#[derive(Debug)]
+pub struct Resampled<U: DurationUnit, S> {
+    /// Time on the fixed timestep from the same origin as the samples
+    pub time: Duration<U>,
+    /// Value filled in from the samples around `time`
+    pub value: S,
+    /// True when `time` falls between two samples further apart than the largest gap allowed.
+    /// The value is still filled in but rests on no nearby measurement.
+    pub gap: bool,
+}
+
+/// Return `samples` on a fixed timestep of `step` from the first to the last sample. Times
+/// between two samples further apart than `max_gap` are flagged as gaps. `Value` when `step` is
+/// not positive or the samples are not in time order.
+pub fn resample<U: DurationUnit, S: Interpolate>(
+    samples: &[TimedSample<U, S>],
+    step: &Duration<U>,
+    max_gap: &Duration<U>,
+    interpolation: Interpolation,
+) -> Result<Vec<Resampled<U, S>>, PsychroLibErr> {
+    let step = f64::from(step);
+    let max_gap = f64::from(max_gap);
+    let ordered = samples
+        .windows(2)
+        .all(|pair| pair[0].time.as_base_units() <= pair[1].time.as_base_units());
+    if step.is_nan() || step <= 0.0 || !ordered {
+        return Err(PsychroLibErr::Value);
+    }
+    let (Some(first), Some(last)) = (samples.first(), samples.last()) else {
+        return Ok(Vec::new());
+    };
+    let (start, end) = (f64::from(&first.time), f64::from(&last.time));
+    let mut resampled = Vec::new();
+    let mut index = 0;
+    for count in 0.. {
+        let time = start + f64::from(count) * step;
+        if time > end {
+            break;
+        }
+        while samples
+            .get(index + 1)
+            .is_some_and(|next| f64::from(&next.time) <= time)
+        {
+            index += 1;
+        }
+        let previous = &samples[index];
+        let previous_time = f64::from(&previous.time);
+        let (value, gap) = match samples.get(index + 1) {
+            Some(next) if time > previous_time => {
+                let span = f64::from(&next.time) - previous_time;
+                let fraction = match interpolation {
+                    Interpolation::Hold => 0.0,
+                    Interpolation::Linear => (time - previous_time) / span,
+                };
+                (
+                    previous.value.interpolate(&next.value, fraction)?,
+                    span > max_gap,
+                )
+            }
+            _ => (previous.value.interpolate(&previous.value, 0.0)?, false),
+        };
+        resampled.push(Resampled {
+            time: Duration::<U>::from(time),
+            value,
+            gap,
+        });
+    }
+    Ok(resampled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Minute;
+
+    fn sample(minute: i64, tdry_bulb: f64, hum_ratio: f64) -> TimedSample<Minute, MoistAirState> {
+        TimedSample {
+            time: Duration::<Minute>::from(minute),
+            value: MoistAirState::from_hum_ratio(
+                Temperature::<Celcius>::from(tdry_bulb),
+                hum_ratio,
+                Pressure::<Pascal>::from(101_325),
+            )
+            .unwrap(),
+        }
+    }
+
+    fn samples() -> [TimedSample<Minute, MoistAirState>; 3] {
+        [
+            sample(0, 20.0, 0.008),
+            sample(10, 30.0, 0.012),
+            sample(60, 22.0, 0.008),
+        ]
+    }
+
+    #[test]
+    fn linear() {
+        let resampled = resample(
+            &samples(),
+            &Duration::<Minute>::from(5),
+            &Duration::<Minute>::from(15),
+            Interpolation::Linear,
+        )
+        .unwrap();
+        assert_eq!(resampled.len(), 13);
+        assert_eq!(resampled[1].time, Duration::<Minute>::from(5));
+        let state = &resampled[1].value;
+        assert!((state.hum_ratio() - 0.010).abs() < 1E-12);
+        assert!((f64::from(state.tdry_bulb::<Celcius>()) - 25.018).abs() < 0.001);
+        let gaps: Vec<bool> = resampled.iter().map(|point| point.gap).collect();
+        assert_eq!(
+            gaps,
+            [false, false, false, true, true, true, true, true, true, true, true, true, false]
+        );
+        assert_eq!(
+            resampled[12].value.tdry_bulb::<Celcius>(),
+            Temperature::<Celcius>::from(22)
+        );
+    }
+
+    #[test]
+    fn hold() {
+        let resampled = resample(
+            &samples(),
+            &Duration::<Minute>::from(7),
+            &Duration::<Minute>::from(60),
+            Interpolation::Hold,
+        )
+        .unwrap();
+        assert_eq!(resampled.len(), 9);
+        assert_eq!(
+            resampled[1].value.tdry_bulb::<Celcius>(),
+            Temperature::<Celcius>::from(20)
+        );
+        assert_eq!(
+            resampled[2].value.tdry_bulb::<Celcius>(),
+            Temperature::<Celcius>::from(30)
+        );
+        assert!(resampled.iter().all(|point| !point.gap));
+    }
+
+    #[test]
+    fn quantities() {
+        let samples = [
+            TimedSample {
+                time: Duration::<Minute>::from(0),
+                value: Pressure::<Pascal>::from(100_000),
+            },
+            TimedSample {
+                time: Duration::<Minute>::from(4),
+                value: Pressure::<Pascal>::from(101_000),
+            },
+        ];
+        let resampled = resample(
+            &samples,
+            &Duration::<Minute>::from(1),
+            &Duration::<Minute>::from(5),
+            Interpolation::Linear,
+        )
+        .unwrap();
+        assert_eq!(resampled[1].value, Pressure::<Pascal>::from(100_250));
+        assert!(matches!(
+            resample(
+                &samples,
+                &Duration::<Minute>::from(0),
+                &Duration::<Minute>::from(5),
+                Interpolation::Linear,
+            ),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
@@ -0,0 +1,514 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, Temperature};
+use crate::units::{Celcius, Pascal, PressureUnit, TemperatureUnit};
+
+use super::{MoistAirState, Site};
+
+/// Default agreement required between redundant inputs, in K of dew point.
+const DEFAULT_TOLERANCE_K: f64 = 0.5;
+
+/// Inputs that fix the moisture content of a state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoistureInput {
+    /// Humidity ratio
+    HumRatio,
+    /// Relative humidity
+    RelHum,
+    /// Dew point temperature
+    TDewPoint,
+    /// Wet bulb temperature
+    TWetBulb,
+}
+
+/// A single disagreement, missing or out of range input found while building a state.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inconsistency {
+    /// No dry bulb temperature was given.
+    TDryBulbMissing,
+    /// No ambient pressure was given, directly or through a site.
+    PresAmbientMissing,
+    /// None of humidity ratio, dew point, relative humidity and wet bulb was given.
+    MoistureMissing,
+    /// Dry bulb temperature `tdry_bulb` in °C is outside -100 °C to 200 °C.
+    TDryBulbOutOfRange { tdry_bulb: f64 },
+    /// Wet bulb temperature `twet_bulb` in °C is outside -100 °C to 200 °C.
+    TWetBulbOutOfRange { twet_bulb: f64 },
+    /// Dew point temperature `tdew_point` in °C is outside -100 °C to 200 °C.
+    TDewPointOutOfRange { tdew_point: f64 },
+    /// Relative humidity `rel_hum` is outside [0-1].
+    RelHumOutOfRange { rel_hum: f64 },
+    /// Humidity ratio `hum_ratio` is negative.
+    HumRatioNegative { hum_ratio: f64 },
+    /// Humidity ratio `hum_ratio` is above the one of vapor at 99 % of the ambient pressure.
+    HumRatioTooHigh { hum_ratio: f64 },
+    /// Ambient pressure `pres_ambient` in Pa is not positive.
+    PresNotPositive { pres_ambient: f64 },
+    /// Ambient pressure `pres_ambient` in Pa is below 1 Pa.
+    PresBelowMinimum { pres_ambient: f64 },
+    /// `input` implies a vapor pressure above 99 % of the ambient pressure.
+    VapPresTooHigh { input: MoistureInput },
+    /// Dew point is above the wet bulb temperature by `excess_k` K.
+    DewPointAboveWetBulb { excess_k: f64 },
+    /// Wet bulb is above the dry bulb temperature by `excess_k` K.
+    WetBulbAboveDryBulb { excess_k: f64 },
+    /// Dew point is above the dry bulb temperature by `excess_k` K.
+    DewPointAboveDryBulb { excess_k: f64 },
+    /// `input` implies a dew point `deviation_k` K away from the one of the built state.
+    Mismatch {
+        input: MoistureInput,
+        deviation_k: f64,
+    },
+}
+
+/// Every disagreement and out of range input found while building a state, so a form can show
+/// them all at once.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConsistencyReport {
+    pub issues: Vec<Inconsistency>,
+}
+
+/// Builds a `MoistAirState` from any combination of dry bulb, wet bulb and dew point temperatures,
+/// relative humidity and humidity ratio. When more than one moisture input is given the first of
+/// humidity ratio, dew point, relative humidity and wet bulb fixes the state and the others are
+/// checked against it. Swapped sensor channels and out of range inputs show up as an
+/// `Inconsistent` error listing every issue.
+#[derive(Debug)]
+pub struct MoistAirStateBuilder {
+    tdry_bulb: Option<Temperature<Celcius>>,
+    twet_bulb: Option<Temperature<Celcius>>,
+    tdew_point: Option<Temperature<Celcius>>,
+    rel_hum: Option<f64>,
+    hum_ratio: Option<f64>,
+    pres_ambient: Option<Pressure<Pascal>>,
+    tolerance_k: f64,
+}
+
+impl Default for MoistAirStateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MoistAirStateBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        MoistAirStateBuilder {
+            tdry_bulb: None,
+            twet_bulb: None,
+            tdew_point: None,
+            rel_hum: None,
+            hum_ratio: None,
+            pres_ambient: None,
+            tolerance_k: DEFAULT_TOLERANCE_K,
+        }
+    }
+
+    /// Dry bulb temperature in °F  or °C or K
+    #[must_use]
+    pub fn tdry_bulb<T: TemperatureUnit>(mut self, tdry_bulb: Temperature<T>) -> Self {
+        self.tdry_bulb = Some(Temperature::<Celcius>::from(&tdry_bulb));
+        self
+    }
+
+    /// Wet bulb temperature in °F  or °C or K
+    #[must_use]
+    pub fn twet_bulb<T: TemperatureUnit>(mut self, twet_bulb: Temperature<T>) -> Self {
+        self.twet_bulb = Some(Temperature::<Celcius>::from(&twet_bulb));
+        self
+    }
+
+    /// Dew point temperature in °F  or °C or K
+    #[must_use]
+    pub fn tdew_point<T: TemperatureUnit>(mut self, tdew_point: Temperature<T>) -> Self {
+        self.tdew_point = Some(Temperature::<Celcius>::from(&tdew_point));
+        self
+    }
+
+    /// Relative humidity [0-1]
+    #[must_use]
+    pub fn rel_hum(mut self, rel_hum: f64) -> Self {
+        self.rel_hum = Some(rel_hum);
+        self
+    }
+
+    /// Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+    #[must_use]
+    pub fn hum_ratio(mut self, hum_ratio: f64) -> Self {
+        self.hum_ratio = Some(hum_ratio);
+        self
+    }
+
+    /// Ambient pressure in Psi  or Pa or atm
+    #[must_use]
+    pub fn pres_ambient<P: PressureUnit>(mut self, pres_ambient: Pressure<P>) -> Self {
+        self.pres_ambient = Some(Pressure::<Pascal>::from(&pres_ambient));
+        self
+    }
+
+    /// Ambient pressure of `site`, its measured pressure or the standard atmosphere at its
+    /// altitude. A later `pres_ambient` overrides it.
+    pub fn site(self, site: &Site) -> Result<Self, PsychroLibErr> {
+        Ok(self.pres_ambient(site.pres_ambient::<Pascal>()?))
+    }
+
+    /// Allowed disagreement between redundant inputs in K. Defaults to 0.5 K.
+    #[must_use]
+    pub fn tolerance(mut self, tolerance_k: f64) -> Self {
+        self.tolerance_k = tolerance_k;
+        self
+    }
+
+    /// Return every missing or out of range input, every ordering violation between the
+    /// temperatures and every disagreement between redundant moisture inputs, empty exactly when
+    /// `build` succeeds.
+    #[must_use]
+    pub fn validate(&self) -> ConsistencyReport {
+        self.evaluate().err().unwrap_or_default()
+    }
+
+    /// Returns the state, or `Inconsistent` listing every input that is missing, out of range or
+    /// disagrees with the others.
+    pub fn build(self) -> Result<MoistAirState, PsychroLibErr> {
+        self.evaluate().map_err(PsychroLibErr::Inconsistent)
+    }
+
+    /// Shared by `validate` and `build` so the two can't drift apart.
+    fn evaluate(&self) -> Result<MoistAirState, ConsistencyReport> {
+        let mut report = ConsistencyReport::default();
+        let in_range = |t: f64| si::check_tdry_bulb_range(t).is_ok();
+        let tdb = self.tdry_bulb.as_ref().map(f64::from);
+        let twb = self.twet_bulb.as_ref().map(f64::from);
+        let tdp = self.tdew_point.as_ref().map(f64::from);
+        let pa = self.pres_ambient.as_ref().map(f64::from);
+        match tdb {
+            None => report.issues.push(Inconsistency::TDryBulbMissing),
+            Some(tdry_bulb) if !in_range(tdry_bulb) => report
+                .issues
+                .push(Inconsistency::TDryBulbOutOfRange { tdry_bulb }),
+            Some(_) => {}
+        }
+        if let Some(rel_hum) = self.rel_hum {
+            if !(0.0..=1.0).contains(&rel_hum) {
+                report
+                    .issues
+                    .push(Inconsistency::RelHumOutOfRange { rel_hum });
+            }
+        }
+        if let Some(hum_ratio) = self.hum_ratio {
+            if hum_ratio.is_nan() || hum_ratio < 0.0 {
+                report
+                    .issues
+                    .push(Inconsistency::HumRatioNegative { hum_ratio });
+            } else if hum_ratio > si::MAX_HUM_RATIO {
+                report
+                    .issues
+                    .push(Inconsistency::HumRatioTooHigh { hum_ratio });
+            }
+        }
+        match pa {
+            None => report.issues.push(Inconsistency::PresAmbientMissing),
+            Some(pres_ambient) if si::check_pres_positive(pres_ambient).is_err() => report
+                .issues
+                .push(Inconsistency::PresNotPositive { pres_ambient }),
+            Some(pres_ambient) if si::check_pres_ambient(pres_ambient).is_err() => report
+                .issues
+                .push(Inconsistency::PresBelowMinimum { pres_ambient }),
+            Some(_) => {}
+        }
+        if let Some(twet_bulb) = twb.filter(|&twb| !in_range(twb)) {
+            report
+                .issues
+                .push(Inconsistency::TWetBulbOutOfRange { twet_bulb });
+        }
+        if let Some(tdew_point) = tdp.filter(|&tdp| !in_range(tdp)) {
+            report
+                .issues
+                .push(Inconsistency::TDewPointOutOfRange { tdew_point });
+        }
+        if let Some(tdb) = tdb {
+            report
+                .issues
+                .extend(self.check_ordering(tdb, twb, tdp).issues);
+        }
+        if self.hum_ratio.is_none() && tdp.is_none() && self.rel_hum.is_none() && twb.is_none() {
+            report.issues.push(Inconsistency::MoistureMissing);
+        }
+        let (Some(tdb), Some(pa)) = (tdb, pa) else {
+            return Err(report);
+        };
+        if !report.issues.is_empty() {
+            return Err(report);
+        }
+
+        // A wet bulb within the tolerance above the dry bulb is read as saturated.
+        let implied = |input: MoistureInput| -> Option<Result<f64, PsychroLibErr>> {
+            match input {
+                MoistureInput::HumRatio => self.hum_ratio.map(Ok),
+                MoistureInput::TDewPoint => {
+                    tdp.map(|tdp| si::hum_ratio_from_vap_pres(si::sat_vap_pres(tdp), pa))
+                }
+                MoistureInput::RelHum => self
+                    .rel_hum
+                    .map(|rel_hum| si::hum_ratio_from_rel_hum(tdb, rel_hum, pa)),
+                MoistureInput::TWetBulb => {
+                    twb.map(|twb| si::hum_ratio_from_twet_bulb(tdb, twb.min(tdb), pa))
+                }
+            }
+        };
+        let inputs = [
+            MoistureInput::HumRatio,
+            MoistureInput::TDewPoint,
+            MoistureInput::RelHum,
+            MoistureInput::TWetBulb,
+        ];
+        let mut given = Vec::new();
+        for input in inputs {
+            match implied(input) {
+                Some(Ok(hum_ratio)) => given.push((input, hum_ratio)),
+                Some(Err(_)) => report.issues.push(Inconsistency::VapPresTooHigh { input }),
+                None => {}
+            }
+        }
+        if !report.issues.is_empty() {
+            return Err(report);
+        }
+        let [(input, hum_ratio), others @ ..] = &given[..] else {
+            return Err(report);
+        };
+        let Ok(state) = MoistAirState::from_hum_ratio(
+            Temperature::<Celcius>::from(tdb),
+            *hum_ratio,
+            Pressure::<Pascal>::from(pa),
+        ) else {
+            report
+                .issues
+                .push(Inconsistency::VapPresTooHigh { input: *input });
+            return Err(report);
+        };
+
+        // Without a dew point for the state there is nothing to compare the others against.
+        if let Ok(tdew_point) = si::tdew_point_from_hum_ratio(tdb, state.hum_ratio(), pa) {
+            for &(input, other_hum_ratio) in others {
+                let Ok(other_tdew_point) = si::tdew_point_from_hum_ratio(tdb, other_hum_ratio, pa)
+                else {
+                    continue;
+                };
+                let deviation_k = (other_tdew_point - tdew_point).abs();
+                if deviation_k > self.tolerance_k {
+                    report
+                        .issues
+                        .push(Inconsistency::Mismatch { input, deviation_k });
+                }
+            }
+        }
+        if !report.issues.is_empty() {
+            return Err(report);
+        }
+        Ok(state)
+    }
+
+    /// Thermodynamics requires dew point ≤ wet bulb ≤ dry bulb.
+    fn check_ordering(&self, tdb: f64, twb: Option<f64>, tdp: Option<f64>) -> ConsistencyReport {
+        let mut report = ConsistencyReport::default();
+        let mut check = |lower: f64, upper: f64, issue: fn(f64) -> Inconsistency| {
+            let excess_k = lower - upper;
+            if excess_k > self.tolerance_k {
+                report.issues.push(issue(excess_k));
+            }
+        };
+        match (tdp, twb) {
+            (Some(tdp), Some(twb)) => {
+                check(tdp, twb, |excess_k| Inconsistency::DewPointAboveWetBulb {
+                    excess_k,
+                });
+                check(twb, tdb, |excess_k| Inconsistency::WetBulbAboveDryBulb {
+                    excess_k,
+                });
+            }
+            (Some(tdp), None) => {
+                check(tdp, tdb, |excess_k| Inconsistency::DewPointAboveDryBulb {
+                    excess_k,
+                });
+            }
+            (None, Some(twb)) => {
+                check(twb, tdb, |excess_k| Inconsistency::WetBulbAboveDryBulb {
+                    excess_k,
+                });
+            }
+            (None, None) => {}
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Fahrenheit;
+
+    #[test]
+    fn consistent_inputs() {
+        let state = MoistAirState::builder()
+            .tdry_bulb(Temperature::<Celcius>::from(25))
+            .twet_bulb(Temperature::<Celcius>::from(17.9))
+            .tdew_point(Temperature::<Fahrenheit>::from(56.95))
+            .rel_hum(0.5)
+            .pres_ambient(Pressure::<Pascal>::from(101_325))
+            .build()
+            .unwrap();
+        assert!((state.hum_ratio() - 0.009_881).abs() < 1E-4);
+    }
+
+    #[test]
+    fn swapped_channels() {
+        let result = MoistAirState::builder()
+            .tdry_bulb(Temperature::<Celcius>::from(17.9))
+            .twet_bulb(Temperature::<Celcius>::from(25))
+            .pres_ambient(Pressure::<Pascal>::from(101_325))
+            .build();
+        let Err(PsychroLibErr::Inconsistent(report)) = result else {
+            panic!("expected an inconsistency report");
+        };
+        assert_eq!(report.issues.len(), 1);
+        assert!(matches!(
+            report.issues[0],
+            Inconsistency::WetBulbAboveDryBulb { .. }
+        ));
+    }
+
+    #[test]
+    fn mismatched_rel_hum() {
+        let result = MoistAirState::builder()
+            .tdry_bulb(Temperature::<Celcius>::from(25))
+            .tdew_point(Temperature::<Celcius>::from(13.86))
+            .rel_hum(0.7)
+            .pres_ambient(Pressure::<Pascal>::from(101_325))
+            .build();
+        let Err(PsychroLibErr::Inconsistent(report)) = result else {
+            panic!("expected an inconsistency report");
+        };
+        assert!(matches!(
+            report.issues[..],
+            [Inconsistency::Mismatch {
+                input: MoistureInput::RelHum,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn every_violation() {
+        let builder = MoistAirState::builder()
+            .tdry_bulb(Temperature::<Celcius>::from(20))
+            .twet_bulb(Temperature::<Celcius>::from(22))
+            .rel_hum(1.2)
+            .hum_ratio(-0.001)
+            .pres_ambient(Pressure::<Pascal>::from(-5));
+        assert_eq!(
+            builder.validate().issues,
+            [
+                Inconsistency::RelHumOutOfRange { rel_hum: 1.2 },
+                Inconsistency::HumRatioNegative { hum_ratio: -0.001 },
+                Inconsistency::PresNotPositive { pres_ambient: -5.0 },
+                Inconsistency::WetBulbAboveDryBulb { excess_k: 2.0 },
+            ]
+        );
+        let Err(PsychroLibErr::Inconsistent(report)) = builder.build() else {
+            panic!("expected an inconsistency report");
+        };
+        assert_eq!(report.issues.len(), 4);
+        let builder = MoistAirState::builder().tdry_bulb(Temperature::<Celcius>::from(250));
+        assert!(matches!(
+            builder.validate().issues[..],
+            [Inconsistency::TDryBulbOutOfRange { .. }, ..]
+        ));
+    }
+
+    #[test]
+    fn missing_inputs() {
+        let builder = MoistAirState::builder()
+            .tdry_bulb(Temperature::<Celcius>::from(25))
+            .pres_ambient(Pressure::<Pascal>::from(101_325));
+        assert_eq!(builder.validate().issues, [Inconsistency::MoistureMissing]);
+        assert!(matches!(
+            builder.build(),
+            Err(PsychroLibErr::Inconsistent(_))
+        ));
+        assert_eq!(
+            MoistAirState::builder().validate().issues,
+            [
+                Inconsistency::TDryBulbMissing,
+                Inconsistency::PresAmbientMissing,
+                Inconsistency::MoistureMissing,
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_agrees_with_build() {
+        let base = || {
+            MoistAirState::builder()
+                .tdry_bulb(Temperature::<Celcius>::from(20))
+                .pres_ambient(Pressure::<Pascal>::from(101_325))
+        };
+        let builders = [
+            base()
+                .rel_hum(0.5)
+                .pres_ambient(Pressure::<Pascal>::from(0.5)),
+            base().tdew_point(Temperature::<Celcius>::from(-150)),
+            base().twet_bulb(Temperature::<Celcius>::from(-150)),
+            base().hum_ratio(100.0),
+            base().twet_bulb(Temperature::<Celcius>::from(20.3)),
+            base()
+                .tdry_bulb(Temperature::<Celcius>::from(150))
+                .rel_hum(0.5),
+            base().rel_hum(0.5),
+        ];
+        let issues = builders
+            .iter()
+            .map(|b| b.validate().issues)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            issues,
+            [
+                vec![Inconsistency::PresBelowMinimum { pres_ambient: 0.5 }],
+                vec![Inconsistency::TDewPointOutOfRange { tdew_point: -150.0 }],
+                vec![Inconsistency::TWetBulbOutOfRange { twet_bulb: -150.0 }],
+                vec![Inconsistency::HumRatioTooHigh { hum_ratio: 100.0 }],
+                vec![],
+                vec![Inconsistency::VapPresTooHigh {
+                    input: MoistureInput::RelHum
+                }],
+                vec![],
+            ]
+        );
+        for (builder, issues) in builders.into_iter().zip(issues) {
+            assert_eq!(builder.build().is_ok(), issues.is_empty());
+        }
+    }
+
+    #[test]
+    fn every_secondary_input_reported() {
+        let result = MoistAirState::builder()
+            .tdry_bulb(Temperature::<Celcius>::from(150))
+            .hum_ratio(0.01)
+            .rel_hum(1.0)
+            .twet_bulb(Temperature::<Celcius>::from(140))
+            .pres_ambient(Pressure::<Pascal>::from(101_325))
+            .build();
+        let Err(PsychroLibErr::Inconsistent(report)) = result else {
+            panic!("expected an inconsistency report");
+        };
+        assert_eq!(
+            report.issues,
+            [
+                Inconsistency::VapPresTooHigh {
+                    input: MoistureInput::RelHum
+                },
+                Inconsistency::VapPresTooHigh {
+                    input: MoistureInput::TWetBulb
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, Temperature};
+use crate::units::{Celcius, Pascal, PressureUnit, TemperatureUnit};
+
+use super::{Diagnostics, MoistAirState};
+
+/// Input moved to its limit of validity by a clamped constructor. Temperatures are in °C.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClampWarning {
+    /// Dry bulb temperature outside -100 °C to 200 °C.
+    TDryBulb { given: f64, limit: f64 },
+    /// Relative humidity outside [0-1].
+    RelHum { given: f64, limit: f64 },
+    /// Humidity ratio below zero or above saturation, in kg_H₂O kg_Air⁻¹.
+    HumRatio { given: f64, limit: f64 },
+    /// Dew point temperature above the dry bulb temperature.
+    TDewPoint { given: f64, limit: f64 },
+    /// Wet bulb temperature above the dry bulb temperature.
+    TWetBulb { given: f64, limit: f64 },
+    /// Ambient pressure below 1 Pa, in Pa.
+    PresAmbient { given: f64, limit: f64 },
+    /// Vapor pressure above 99 % of the ambient pressure, in Pa.
+    VapPres { given: f64, limit: f64 },
+}
+
+impl ClampWarning {
+    /// True when a supersaturated input was lowered to saturation.
+    fn is_saturation_cap(&self) -> bool {
+        match *self {
+            ClampWarning::TDryBulb { .. }
+            | ClampWarning::PresAmbient { .. }
+            | ClampWarning::VapPres { .. } => false,
+            ClampWarning::RelHum { given, limit }
+            | ClampWarning::HumRatio { given, limit }
+            | ClampWarning::TDewPoint { given, limit }
+            | ClampWarning::TWetBulb { given, limit } => given > limit,
+        }
+    }
+}
+
+/// Result of a clamped calculation with every input that had to be moved to get it.
+#[derive(Debug)]
+pub struct Clamped<V> {
+    /// Result computed from the clamped inputs.
+    pub value: V,
+    /// Inputs that were out of range, empty when the result is exact.
+    pub warnings: Vec<ClampWarning>,
+}
+
+/// Clamp `given` into `lower..=upper`, recording a warning when it moves.
+fn clamp(
+    given: f64,
+    lower: f64,
+    upper: f64,
+    warning: fn(f64, f64) -> ClampWarning,
+    warnings: &mut Vec<ClampWarning>,
+) -> f64 {
+    let limit = given.clamp(lower, upper);
+    if limit != given {
+        warnings.push(warning(given, limit));
+    }
+    limit
+}
+
+/// Dry bulb temperature in °C and pressure in Pa, checked and clamped.
+fn clamped_inputs<T: TemperatureUnit, P: PressureUnit>(
+    tdry_bulb: &Temperature<T>,
+    pres_ambient: &Pressure<P>,
+    warnings: &mut Vec<ClampWarning>,
+) -> Result<(f64, f64), PsychroLibErr> {
+    let tdb = f64::from(&Temperature::<Celcius>::from(tdry_bulb));
+    let pres = f64::from(&Pressure::<Pascal>::from(pres_ambient));
+    si::check_pres_positive(pres)?;
+    let pres = clamp(
+        pres,
+        si::MIN_PRES_AMBIENT,
+        f64::INFINITY,
+        |given, limit| ClampWarning::PresAmbient { given, limit },
+        warnings,
+    );
+    let (lower, upper) = si::TEMPERATURE_BOUNDS;
+    let tdb = clamp(
+        tdb,
+        lower,
+        upper,
+        |given, limit| ClampWarning::TDryBulb { given, limit },
+        warnings,
+    );
+    Ok((tdb, pres))
+}
+
+/// Humidity ratio of `vap_pres` in Pa, capping it at 99 % of `pres`.
+fn capped_hum_ratio(
+    vap_pres: f64,
+    pres: f64,
+    warnings: &mut Vec<ClampWarning>,
+) -> Result<f64, PsychroLibErr> {
+    let vap_pres = clamp(
+        vap_pres,
+        0.0,
+        si::MAX_VAP_PRES_FRACTION * pres,
+        |given, limit| ClampWarning::VapPres { given, limit },
+        warnings,
+    );
+    // Rounding can put the ratio at the cap a hair above `MAX_HUM_RATIO`.
+    Ok(si::hum_ratio_from_vap_pres(vap_pres, pres)?.min(si::MAX_HUM_RATIO))
+}
+
+impl MoistAirState {
+    /// State from dry bulb temperature and humidity ratio, clamping out of range inputs instead
+    /// of failing. The humidity ratio is limited to saturation when saturation is defined at the
+    /// pressure, and to about 61.6 kg_H₂O kg_Air⁻¹, 99 % water vapor, above the boiling point.
+    /// A pressure below 1 Pa is raised to it. Still `Value` for a pressure that isn't positive,
+    /// which can't be clamped.
+    /// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+    pub fn from_hum_ratio_clamped<T: TemperatureUnit, P: PressureUnit>(
+        tdry_bulb: Temperature<T>,
+        hum_ratio: f64,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Clamped<Self>, PsychroLibErr> {
+        let mut warnings = Vec::new();
+        let (tdb, pres) = clamped_inputs(&tdry_bulb, &pres_ambient, &mut warnings)?;
+        let upper = si::sat_hum_ratio(tdb, pres).unwrap_or(si::MAX_HUM_RATIO);
+        let hum_ratio = clamp(
+            hum_ratio,
+            0.0,
+            upper,
+            |given, limit| ClampWarning::HumRatio { given, limit },
+            &mut warnings,
+        );
+        Self::clamped_state(tdb, hum_ratio, pres, warnings)
+    }
+
+    /// State from dry bulb temperature and relative humidity [0-1], clamping out of range inputs
+    /// instead of failing. Above the boiling point the vapor pressure is capped at 99 % of the
+    /// ambient pressure. Still `Value` for a pressure that isn't positive.
+    pub fn from_rel_hum_clamped<T: TemperatureUnit, P: PressureUnit>(
+        tdry_bulb: Temperature<T>,
+        rel_hum: f64,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Clamped<Self>, PsychroLibErr> {
+        let mut warnings = Vec::new();
+        let (tdb, pres) = clamped_inputs(&tdry_bulb, &pres_ambient, &mut warnings)?;
+        let rel_hum = clamp(
+            rel_hum,
+            0.0,
+            1.0,
+            |given, limit| ClampWarning::RelHum { given, limit },
+            &mut warnings,
+        );
+        let hum_ratio = capped_hum_ratio(rel_hum * si::sat_vap_pres(tdb), pres, &mut warnings)?;
+        Self::clamped_state(tdb, hum_ratio, pres, warnings)
+    }
+
+    /// State from dry bulb and dew point temperatures, clamping out of range inputs instead of
+    /// failing. A dew point above the dry bulb temperature is lowered to it, and its vapor pressure
+    /// is capped at 99 % of the ambient pressure. Still `Value` for a pressure that isn't positive.
+    pub fn from_tdew_point_clamped<T: TemperatureUnit, TD: TemperatureUnit, P: PressureUnit>(
+        tdry_bulb: Temperature<T>,
+        tdew_point: Temperature<TD>,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Clamped<Self>, PsychroLibErr> {
+        let mut warnings = Vec::new();
+        let (tdb, pres) = clamped_inputs(&tdry_bulb, &pres_ambient, &mut warnings)?;
+        let tdp = clamp(
+            f64::from(&Temperature::<Celcius>::from(&tdew_point)),
+            si::TEMPERATURE_BOUNDS.0,
+            tdb,
+            |given, limit| ClampWarning::TDewPoint { given, limit },
+            &mut warnings,
+        );
+        let hum_ratio = capped_hum_ratio(si::sat_vap_pres(tdp), pres, &mut warnings)?;
+        Self::clamped_state(tdb, hum_ratio, pres, warnings)
+    }
+
+    /// State from dry bulb and wet bulb temperatures, clamping out of range inputs instead of
+    /// failing. A wet bulb above the dry bulb temperature is lowered to it. Still `Value` for a
+    /// pressure that isn't positive.
+    pub fn from_twet_bulb_clamped<T: TemperatureUnit, TW: TemperatureUnit, P: PressureUnit>(
+        tdry_bulb: Temperature<T>,
+        twet_bulb: Temperature<TW>,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Clamped<Self>, PsychroLibErr> {
+        let mut warnings = Vec::new();
+        let (tdb, pres) = clamped_inputs(&tdry_bulb, &pres_ambient, &mut warnings)?;
+        let twb = clamp(
+            f64::from(&Temperature::<Celcius>::from(&twet_bulb)),
+            si::TEMPERATURE_BOUNDS.0,
+            tdb,
+            |given, limit| ClampWarning::TWetBulb { given, limit },
+            &mut warnings,
+        );
+        let hum_ratio = si::hum_ratio_from_twet_bulb(tdb, twb, pres)?;
+        Self::clamped_state(tdb, hum_ratio, pres, warnings)
+    }
+
+    fn clamped_state(
+        tdb: f64,
+        hum_ratio: f64,
+        pres: f64,
+        warnings: Vec<ClampWarning>,
+    ) -> Result<Clamped<Self>, PsychroLibErr> {
+        let diagnostics = Diagnostics {
+            rel_hum_capped: warnings.iter().any(ClampWarning::is_saturation_cap),
+            input_clamped: warnings.iter().any(|warning| !warning.is_saturation_cap()),
+            ..Diagnostics::default()
+        };
+        Ok(Clamped {
+            value: Self::from_hum_ratio(
+                Temperature::<Celcius>::from(tdb),
+                hum_ratio,
+                Pressure::<Pascal>::from(pres),
+            )?
+            .with_diagnostics(diagnostics),
+            warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pres() -> Pressure<Pascal> {
+        Pressure::<Pascal>::from(101_325)
+    }
+
+    #[test]
+    fn rel_hum() {
+        let clamped =
+            MoistAirState::from_rel_hum_clamped(Temperature::<Celcius>::from(25), 1.04, pres())
+                .unwrap();
+        assert_eq!(
+            clamped.warnings,
+            [ClampWarning::RelHum {
+                given: 1.04,
+                limit: 1.0
+            }]
+        );
+        assert!((clamped.value.rel_hum().unwrap() - 1.0).abs() < 1E-9);
+        let clamped =
+            MoistAirState::from_rel_hum_clamped(Temperature::<Celcius>::from(25), 0.5, pres())
+                .unwrap();
+        assert!(clamped.warnings.is_empty());
+    }
+
+    #[test]
+    fn temperatures() {
+        let clamped = MoistAirState::from_tdew_point_clamped(
+            Temperature::<Celcius>::from(250),
+            Temperature::<Celcius>::from(20),
+            pres(),
+        )
+        .unwrap();
+        assert_eq!(
+            clamped.warnings,
+            [ClampWarning::TDryBulb {
+                given: 250.0,
+                limit: 200.0
+            }]
+        );
+        let clamped = MoistAirState::from_twet_bulb_clamped(
+            Temperature::<Celcius>::from(20),
+            Temperature::<Celcius>::from(20.3),
+            pres(),
+        )
+        .unwrap();
+        assert!(matches!(
+            clamped.warnings[..],
+            [ClampWarning::TWetBulb { limit, .. }] if (limit - 20.0).abs() < 1E-9
+        ));
+        assert!((clamped.value.rel_hum().unwrap() - 1.0).abs() < 1E-6);
+    }
+
+    #[test]
+    fn hum_ratio() {
+        let clamped =
+            MoistAirState::from_hum_ratio_clamped(Temperature::<Celcius>::from(20), -0.001, pres())
+                .unwrap();
+        assert!(matches!(
+            clamped.warnings[..],
+            [ClampWarning::HumRatio { limit, .. }] if limit == 0.0
+        ));
+        let clamped =
+            MoistAirState::from_hum_ratio_clamped(Temperature::<Celcius>::from(20), 0.05, pres())
+                .unwrap();
+        assert!((clamped.value.rel_hum().unwrap() - 1.0).abs() < 1E-9);
+        assert!(matches!(
+            MoistAirState::from_hum_ratio_clamped(
+                Temperature::<Celcius>::from(20),
+                0.01,
+                Pressure::<Pascal>::from(0)
+            ),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn hum_ratio_above_boiling_point() {
+        let clamped =
+            MoistAirState::from_hum_ratio_clamped(Temperature::<Celcius>::from(150), 100.0, pres())
+                .unwrap();
+        assert!(matches!(
+            clamped.warnings[..],
+            [ClampWarning::HumRatio { limit, .. }] if limit == si::MAX_HUM_RATIO
+        ));
+        assert!((clamped.value.hum_ratio() - si::MAX_HUM_RATIO).abs() < 1E-9);
+        let clamped =
+            MoistAirState::from_hum_ratio_clamped(Temperature::<Celcius>::from(150), 0.5, pres())
+                .unwrap();
+        assert!(clamped.warnings.is_empty());
+    }
+
+    #[test]
+    fn vap_pres_above_ambient() {
+        let clamped =
+            MoistAirState::from_rel_hum_clamped(Temperature::<Celcius>::from(150), 0.5, pres())
+                .unwrap();
+        assert!(matches!(
+            clamped.warnings[..],
+            [ClampWarning::VapPres { limit, .. }] if (limit - 0.99 * 101_325.0).abs() < 1E-6
+        ));
+        assert!(clamped.value.diagnostics().input_clamped);
+        assert!((clamped.value.hum_ratio() - si::MAX_HUM_RATIO).abs() < 1E-6);
+    }
+
+    #[test]
+    fn pres_below_minimum() {
+        let clamped = MoistAirState::from_hum_ratio_clamped(
+            Temperature::<Celcius>::from(-50),
+            0.0,
+            Pressure::<Pascal>::from(0.5),
+        )
+        .unwrap();
+        assert_eq!(
+            clamped.warnings,
+            [ClampWarning::PresAmbient {
+                given: 0.5,
+                limit: 1.0
+            }]
+        );
+        assert!(clamped.value.diagnostics().input_clamped);
+    }
+}
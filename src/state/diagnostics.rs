@@ -0,0 +1,113 @@
+/// Inputs the library adjusted on the way to a state. Kept with the state so data quality
+/// monitoring can count how often sensors produce borderline values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Diagnostics {
+    /// Humidity ratio at the 1E-7 kg_H₂O kg_Air⁻¹ floor of the calculations, raised to it when
+    /// it was lower.
+    pub hum_ratio_floored: bool,
+    /// Supersaturated input capped at saturation: relative humidity above 1, or humidity ratio,
+    /// dew point or wet bulb temperature above their saturation value.
+    pub rel_hum_capped: bool,
+    /// Any other input moved to its limit of validity by a clamped constructor.
+    pub input_clamped: bool,
+}
+
+impl Diagnostics {
+    /// True when no input was adjusted.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Number of states with each kind of adjustment, for data quality reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DiagnosticCounts {
+    /// States counted
+    pub states: u64,
+    /// States with the humidity ratio at its floor
+    pub hum_ratio_floored: u64,
+    /// States with a supersaturated input capped at saturation
+    pub rel_hum_capped: u64,
+    /// States with another input clamped
+    pub input_clamped: u64,
+}
+
+impl DiagnosticCounts {
+    /// Count one state with the adjustments of `diagnostics`.
+    pub fn add(&mut self, diagnostics: &Diagnostics) {
+        self.states += 1;
+        self.hum_ratio_floored += u64::from(diagnostics.hum_ratio_floored);
+        self.rel_hum_capped += u64::from(diagnostics.rel_hum_capped);
+        self.input_clamped += u64::from(diagnostics.input_clamped);
+    }
+
+    /// Share of the states counted with the humidity ratio at its floor [0-1], 0 when none were
+    /// counted.
+    #[must_use]
+    pub fn hum_ratio_floored_fraction(&self) -> f64 {
+        if self.states == 0 {
+            return 0.0;
+        }
+        self.hum_ratio_floored as f64 / self.states as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::{Pressure, Temperature};
+    use crate::state::MoistAirState;
+    use crate::units::{Celcius, Pascal};
+
+    fn pres() -> Pressure<Pascal> {
+        Pressure::<Pascal>::from(101_325)
+    }
+
+    #[test]
+    fn states() {
+        let state =
+            MoistAirState::from_hum_ratio(Temperature::<Celcius>::from(20), 0.008, pres()).unwrap();
+        assert!(state.diagnostics().is_clean());
+        let state =
+            MoistAirState::from_hum_ratio(Temperature::<Celcius>::from(20), 1E-9, pres()).unwrap();
+        assert!(state.diagnostics().hum_ratio_floored);
+        assert!((state.hum_ratio() - 1E-7).abs() < 1E-12);
+        let state =
+            MoistAirState::from_rel_hum(Temperature::<Celcius>::from(-20), 0.0, pres()).unwrap();
+        assert!(state.diagnostics().hum_ratio_floored);
+    }
+
+    #[test]
+    fn clamped() {
+        let clamped =
+            MoistAirState::from_rel_hum_clamped(Temperature::<Celcius>::from(25), 1.03, pres())
+                .unwrap();
+        let diagnostics = clamped.value.diagnostics();
+        assert!(diagnostics.rel_hum_capped);
+        assert!(!diagnostics.input_clamped);
+        let clamped =
+            MoistAirState::from_rel_hum_clamped(Temperature::<Celcius>::from(-120), 0.5, pres())
+                .unwrap();
+        assert!(clamped.value.diagnostics().input_clamped);
+    }
+
+    #[test]
+    fn counts() {
+        let mut counts = DiagnosticCounts::default();
+        for hum_ratio in [0.008, 0.0, 0.0, 0.01] {
+            let state =
+                MoistAirState::from_hum_ratio(Temperature::<Celcius>::from(20), hum_ratio, pres())
+                    .unwrap();
+            counts.add(&state.diagnostics());
+        }
+        assert_eq!(counts.states, 4);
+        assert_eq!(counts.hum_ratio_floored, 2);
+        assert_eq!(counts.rel_hum_capped, 0);
+        assert!((counts.hum_ratio_floored_fraction() - 0.5).abs() < 1E-12);
+    }
+}
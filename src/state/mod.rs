@@ -0,0 +1,17 @@
+mod moist_air;
+pub use moist_air::MoistAirState;
+
+mod diagnostics;
+pub use diagnostics::{DiagnosticCounts, Diagnostics};
+
+mod clamped;
+pub use clamped::{ClampWarning, Clamped};
+
+mod property;
+pub use property::Property;
+
+mod builder;
+pub use builder::{ConsistencyReport, Inconsistency, MoistAirStateBuilder, MoistureInput};
+
+mod site;
+pub use site::Site;
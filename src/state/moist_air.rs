@@ -0,0 +1,239 @@
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, Temperature};
+use crate::units::{Celcius, Pascal, PressureUnit, TemperatureUnit};
+
+use super::{Diagnostics, MoistAirStateBuilder};
+
+/// State of moist air fixed by dry bulb temperature, humidity ratio and ambient pressure.
+/// Every other property is derived from these three.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MoistAirState {
+    tdry_bulb: Temperature<Celcius>,
+    hum_ratio: f64,
+    pres_ambient: Pressure<Pascal>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    diagnostics: Diagnostics,
+}
+
+impl MoistAirState {
+    /// Returns an empty builder for states made of redundant or measured inputs.
+    #[must_use]
+    pub fn builder() -> MoistAirStateBuilder {
+        MoistAirStateBuilder::new()
+    }
+
+    /// State from dry bulb temperature and humidity ratio. A humidity ratio below 1E-7 is raised
+    /// to it and recorded in `diagnostics`. Errors with `Range` for a humidity ratio above about
+    /// 61.6, where water vapor is 99 % of the ambient pressure, or a pressure below 1 Pa.
+    /// `hum_ratio` Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+    pub fn from_hum_ratio<T: TemperatureUnit, P: PressureUnit>(
+        tdry_bulb: Temperature<T>,
+        hum_ratio: f64,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Self, PsychroLibErr> {
+        si::check_pres_ambient(f64::from(&Pressure::<Pascal>::from(&pres_ambient)))?;
+        if !(0.0..=si::MAX_HUM_RATIO).contains(&hum_ratio) {
+            return Err(PsychroLibErr::Range);
+        }
+        Ok(MoistAirState {
+            tdry_bulb: Temperature::<Celcius>::from(&tdry_bulb),
+            hum_ratio: hum_ratio.max(si::MIN_HUM_RATIO),
+            pres_ambient: Pressure::<Pascal>::from(&pres_ambient),
+            diagnostics: Diagnostics {
+                hum_ratio_floored: hum_ratio <= si::MIN_HUM_RATIO,
+                ..Diagnostics::default()
+            },
+        })
+    }
+
+    /// State from dry bulb temperature and relative humidity [0-1].
+    pub fn from_rel_hum<T: TemperatureUnit, P: PressureUnit>(
+        tdry_bulb: Temperature<T>,
+        rel_hum: f64,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Self, PsychroLibErr> {
+        let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+        let pa = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+        let hum_ratio = si::hum_ratio_from_rel_hum(tdc, rel_hum, pa)?;
+        Self::from_hum_ratio(tdry_bulb, hum_ratio, pres_ambient)
+    }
+
+    /// State from dry bulb and dew point temperatures. Errors with `Range` when the dew point is
+    /// above the dry bulb temperature or outside -100 °C to 200 °C.
+    pub fn from_tdew_point<T: TemperatureUnit, TD: TemperatureUnit, P: PressureUnit>(
+        tdry_bulb: Temperature<T>,
+        tdew_point: Temperature<TD>,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Self, PsychroLibErr> {
+        let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+        let tdp = f64::from(&Temperature::<Celcius>::from(&tdew_point));
+        si::check_tdry_bulb_range(tdp)?;
+        if tdp > tdc {
+            return Err(PsychroLibErr::Range);
+        }
+        let pa = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+        let hum_ratio = si::hum_ratio_from_vap_pres(si::sat_vap_pres(tdp), pa)?;
+        Self::from_hum_ratio(tdry_bulb, hum_ratio, pres_ambient)
+    }
+
+    /// State from dry bulb and wet bulb temperatures.
+    pub fn from_twet_bulb<T: TemperatureUnit, TW: TemperatureUnit, P: PressureUnit>(
+        tdry_bulb: Temperature<T>,
+        twet_bulb: Temperature<TW>,
+        pres_ambient: Pressure<P>,
+    ) -> Result<Self, PsychroLibErr> {
+        let tdc = f64::from(&Temperature::<Celcius>::from(&tdry_bulb));
+        let twb = f64::from(&Temperature::<Celcius>::from(&twet_bulb));
+        let pa = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+        let hum_ratio = si::hum_ratio_from_twet_bulb(tdc, twb, pa)?;
+        Self::from_hum_ratio(tdry_bulb, hum_ratio, pres_ambient)
+    }
+
+    /// Dry bulb temperature in °F  or °C or K
+    #[must_use]
+    pub fn tdry_bulb<T: TemperatureUnit>(&self) -> Temperature<T> {
+        Temperature::<T>::from(&self.tdry_bulb)
+    }
+
+    /// Humidity ratio in lb_H₂O lb_Air⁻¹  or kg_H₂O kg_Air⁻¹
+    #[must_use]
+    pub fn hum_ratio(&self) -> f64 {
+        self.hum_ratio
+    }
+
+    /// Ambient pressure in Psi  or Pa or atm
+    #[must_use]
+    pub fn pres_ambient<P: PressureUnit>(&self) -> Pressure<P> {
+        Pressure::<P>::from(&self.pres_ambient)
+    }
+
+    /// Inputs adjusted on the way to this state.
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics
+    }
+
+    /// Relative humidity [0-1]
+    pub fn rel_hum(&self) -> Result<f64, PsychroLibErr> {
+        si::rel_hum_from_hum_ratio(self.tdry_bulb_c(), self.hum_ratio, self.pres_ambient_pa())
+    }
+
+    /// Dew point temperature in °F  or °C or K
+    pub fn tdew_point<T: TemperatureUnit>(&self) -> Result<Temperature<T>, PsychroLibErr> {
+        let tdp = si::tdew_point_from_hum_ratio(
+            self.tdry_bulb_c(),
+            self.hum_ratio,
+            self.pres_ambient_pa(),
+        )?;
+        Ok(Temperature::<T>::from(&Temperature::<Celcius>::from(tdp)))
+    }
+
+    /// Wet bulb temperature in °F  or °C or K
+    pub fn twet_bulb<T: TemperatureUnit>(&self) -> Result<Temperature<T>, PsychroLibErr> {
+        let twb = si::twet_bulb_from_hum_ratio(
+            self.tdry_bulb_c(),
+            self.hum_ratio,
+            self.pres_ambient_pa(),
+        )?;
+        Ok(Temperature::<T>::from(&Temperature::<Celcius>::from(twb)))
+    }
+
+    /// Adiabatic saturation temperature in °F  or °C or K, from the energy balance rather than
+    /// the closed form of `twet_bulb`. See `get_tadiabatic_saturation` for the difference.
+    pub fn tadiabatic_saturation<T: TemperatureUnit>(
+        &self,
+    ) -> Result<Temperature<T>, PsychroLibErr> {
+        let tsat = si::tadiabatic_saturation_from_hum_ratio(
+            self.tdry_bulb_c(),
+            self.hum_ratio,
+            self.pres_ambient_pa(),
+        )?;
+        Ok(Temperature::<T>::from(&Temperature::<Celcius>::from(tsat)))
+    }
+
+    pub(crate) fn tdry_bulb_c(&self) -> f64 {
+        f64::from(&self.tdry_bulb)
+    }
+
+    pub(crate) fn pres_ambient_pa(&self) -> f64 {
+        f64::from(&self.pres_ambient)
+    }
+
+    pub(crate) fn with_diagnostics(mut self, diagnostics: Diagnostics) -> Self {
+        self.diagnostics.rel_hum_capped |= diagnostics.rel_hum_capped;
+        self.diagnostics.input_clamped |= diagnostics.input_clamped;
+        self
+    }
+}
+
+#[cfg(test)]
+impl MoistAirState {
+    /// State at `tdry_bulb` in °C and `rel_hum` [0-1] at 101 325 Pa, for tests.
+    pub(crate) fn test_rel_hum(tdry_bulb: f64, rel_hum: f64) -> Self {
+        Self::from_rel_hum(
+            Temperature::<Celcius>::from(tdry_bulb),
+            rel_hum,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap()
+    }
+
+    /// State at `tdry_bulb` in °C and `hum_ratio` in kg_H₂O kg_Air⁻¹ at 101 325 Pa, for tests.
+    pub(crate) fn test_hum_ratio(tdry_bulb: f64, hum_ratio: f64) -> Self {
+        Self::from_hum_ratio(
+            Temperature::<Celcius>::from(tdry_bulb),
+            hum_ratio,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Atmosphere, Fahrenheit};
+
+    #[test]
+    fn from_rel_hum() {
+        let state = MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            0.5,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert!((state.hum_ratio() - 0.009_881).abs() < 1E-5);
+        let tdp: Temperature<Celcius> = state.tdew_point().unwrap();
+        assert_eq!(tdp, Temperature::<Celcius>::from(13.864));
+        let twb = f64::from(state.twet_bulb::<Celcius>().unwrap());
+        assert!((twb - 17.889).abs() < 0.001);
+    }
+
+    #[test]
+    fn from_twet_bulb_round_trip() {
+        let state = MoistAirState::from_twet_bulb(
+            Temperature::<Fahrenheit>::from(77),
+            Temperature::<Celcius>::from(17.889),
+            Pressure::<Atmosphere>::from(1),
+        )
+        .unwrap();
+        assert!((state.rel_hum().unwrap() - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn from_tdew_point_out_of_range() {
+        let state = |tdew_point| {
+            MoistAirState::from_tdew_point(
+                Temperature::<Celcius>::from(20),
+                Temperature::<Celcius>::from(tdew_point),
+                Pressure::<Pascal>::from(101_325),
+            )
+        };
+        assert!((state(20).unwrap().rel_hum().unwrap() - 1.0).abs() < 1E-9);
+        assert!(matches!(state(30), Err(PsychroLibErr::Range)));
+        assert!(matches!(state(-150), Err(PsychroLibErr::Range)));
+    }
+}
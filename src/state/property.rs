@@ -0,0 +1,154 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{
+    Density, HumidityRatio, Pressure, QuantityValue, RelativeHumidity, SpecificEnthalpy,
+    SpecificEntropy,
+};
+use crate::units::Pascal;
+
+use super::MoistAirState;
+
+/// Property of a moist air state, named at runtime. Parses from and displays as the name of the
+/// matching method of `MoistAirState`, such as "tdew_point".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Property {
+    /// Dry bulb temperature
+    #[default]
+    TDryBulb,
+    /// Wet bulb temperature
+    TWetBulb,
+    /// Dew point temperature
+    TDewPoint,
+    /// Relative humidity
+    RelHum,
+    /// Humidity ratio
+    HumRatio,
+    /// Partial pressure of water vapor
+    VapPres,
+    /// Ambient pressure
+    PresAmbient,
+    /// Moist air enthalpy
+    Enthalpy,
+    /// Moist air specific entropy
+    Entropy,
+    /// Moist air density
+    Density,
+}
+
+impl Property {
+    /// Every property, in declaration order.
+    pub const ALL: [Property; 10] = [
+        Property::TDryBulb,
+        Property::TWetBulb,
+        Property::TDewPoint,
+        Property::RelHum,
+        Property::HumRatio,
+        Property::VapPres,
+        Property::PresAmbient,
+        Property::Enthalpy,
+        Property::Entropy,
+        Property::Density,
+    ];
+
+    /// Name of the property, such as "tdew_point".
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Property::TDryBulb => "tdry_bulb",
+            Property::TWetBulb => "twet_bulb",
+            Property::TDewPoint => "tdew_point",
+            Property::RelHum => "rel_hum",
+            Property::HumRatio => "hum_ratio",
+            Property::VapPres => "vap_pres",
+            Property::PresAmbient => "pres_ambient",
+            Property::Enthalpy => "enthalpy",
+            Property::Entropy => "entropy",
+            Property::Density => "density",
+        }
+    }
+}
+
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Property {
+    type Err = PsychroLibErr;
+
+    /// Errors with `Value` when `text` is not the name of a property.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Property::ALL
+            .into_iter()
+            .find(|property| property.name() == text.trim())
+            .ok_or(PsychroLibErr::Value)
+    }
+}
+
+impl MoistAirState {
+    /// Return `property` in its SI unit, for callers that pick properties at runtime. `Range` for
+    /// the relative humidity of a supersaturated state.
+    pub fn get(&self, property: Property) -> Result<QuantityValue, PsychroLibErr> {
+        let (tdb, hum_ratio, pres) = (self.tdry_bulb_c(), self.hum_ratio(), self.pres_ambient_pa());
+        Ok(match property {
+            Property::TDryBulb => QuantityValue::Temperature(self.tdry_bulb()),
+            Property::TWetBulb => QuantityValue::Temperature(self.twet_bulb()?),
+            Property::TDewPoint => QuantityValue::Temperature(self.tdew_point()?),
+            Property::RelHum => {
+                QuantityValue::RelativeHumidity(RelativeHumidity::from_fraction(self.rel_hum()?)?)
+            }
+            Property::HumRatio => QuantityValue::HumidityRatio(HumidityRatio::from(hum_ratio)),
+            Property::VapPres => QuantityValue::Pressure(Pressure::<Pascal>::from(
+                si::vap_pres_from_hum_ratio(hum_ratio, pres)?,
+            )),
+            Property::PresAmbient => QuantityValue::Pressure(self.pres_ambient()),
+            Property::Enthalpy => QuantityValue::SpecificEnthalpy(SpecificEnthalpy::from(
+                si::moist_air_enthalpy(tdb, hum_ratio),
+            )),
+            Property::Entropy => QuantityValue::SpecificEntropy(SpecificEntropy::from(
+                si::moist_air_entropy(tdb, hum_ratio, pres)?,
+            )),
+            Property::Density => {
+                QuantityValue::Density(Density::from(si::moist_air_density(tdb, hum_ratio, pres)?))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Temperature;
+    use crate::units::{Celcius, UnitKind};
+
+    #[test]
+    fn get() {
+        let state = MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(25),
+            0.5,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        let property: Property = "tdew_point".parse().unwrap();
+        let tdew_point = state.get(property).unwrap();
+        assert_eq!(
+            tdew_point,
+            QuantityValue::Temperature(Temperature::<Celcius>::from(13.864))
+        );
+        let enthalpy = state.get(Property::Enthalpy).unwrap();
+        assert_eq!(enthalpy.unit(), Some(UnitKind::JoulesPerKg));
+        assert!((enthalpy.value() - 50_322.0).abs() < 10.0);
+        assert!((state.get(Property::RelHum).unwrap().value() - 0.5).abs() < 1E-9);
+        for property in Property::ALL {
+            assert!(state.get(property).is_ok());
+            assert_eq!(property.to_string().parse::<Property>().unwrap(), property);
+        }
+        assert!(matches!(
+            "humidity".parse::<Property>(),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+}
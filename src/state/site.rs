@@ -0,0 +1,114 @@
+use crate::psychrolib::{get_standard_atm_pressure, PsychroLibErr};
+use crate::quantities::{Length, Pressure};
+use crate::units::{LengthUnit, Meter, Pascal, PressureUnit, UnitSystem};
+
+use super::MoistAirStateBuilder;
+
+/// Where the air is measured. Carries the altitude so states get the local pressure instead of
+/// 1 atm, which overstates the pressure by about 12% at 1000 m.
+#[derive(Debug)]
+pub struct Site {
+    altitude: Length<Meter>,
+    pres_ambient: Option<Pressure<Pascal>>,
+    unit_system: UnitSystem,
+}
+
+impl Site {
+    /// Site at `altitude` above sea level, in ft  or m.
+    #[must_use]
+    pub fn new<L: LengthUnit>(altitude: Length<L>) -> Self {
+        Site {
+            altitude: Length::<Meter>::from(&altitude),
+            pres_ambient: None,
+            unit_system: UnitSystem::default(),
+        }
+    }
+
+    /// Measured barometric pressure in Psi  or Pa or atm, used instead of the standard atmosphere.
+    #[must_use]
+    pub fn with_pres_ambient<P: PressureUnit>(mut self, pres_ambient: Pressure<P>) -> Self {
+        self.pres_ambient = Some(Pressure::<Pascal>::from(&pres_ambient));
+        self
+    }
+
+    /// System of units results are shown in.
+    #[must_use]
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.unit_system = unit_system;
+        self
+    }
+
+    /// Altitude above sea level in ft  or m
+    #[must_use]
+    pub fn altitude<L: LengthUnit>(&self) -> Length<L> {
+        Length::<L>::from(&self.altitude)
+    }
+
+    /// System of units results are shown in.
+    #[must_use]
+    pub fn unit_system(&self) -> UnitSystem {
+        self.unit_system
+    }
+
+    /// Return the measured pressure if there is one, else the standard atmosphere pressure at the
+    /// altitude of the site.
+    /// Returns: Ambient pressure in Psi  or Pa or atm
+    pub fn pres_ambient<P: PressureUnit>(&self) -> Result<Pressure<P>, PsychroLibErr> {
+        match &self.pres_ambient {
+            Some(pres_ambient) => Ok(Pressure::<P>::from(pres_ambient)),
+            None => get_standard_atm_pressure(Length::<Meter>::from(&self.altitude)),
+        }
+    }
+
+    /// Return a builder whose pressure defaults to the pressure of the site.
+    pub fn state_builder(&self) -> Result<MoistAirStateBuilder, PsychroLibErr> {
+        MoistAirStateBuilder::new().site(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantities::Temperature;
+    use crate::units::{Celcius, Foot};
+
+    #[test]
+    fn pressure() {
+        let site = Site::new(Length::<Meter>::from(1500));
+        let pres: Pressure<Pascal> = site.pres_ambient().unwrap();
+        assert!((f64::from(pres) - 84_555.5).abs() < 1.0);
+        let site = Site::new(Length::<Foot>::from(4921.26))
+            .with_pres_ambient(Pressure::<Pascal>::from(86_000))
+            .with_unit_system(UnitSystem::IP);
+        let pres: Pressure<Pascal> = site.pres_ambient().unwrap();
+        assert_eq!(pres, Pressure::<Pascal>::from(86_000));
+        assert_eq!(site.unit_system(), UnitSystem::IP);
+        assert!((f64::from(site.altitude::<Meter>()) - 1500.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn state() {
+        let site = Site::new(Length::<Meter>::from(1500));
+        let state = site
+            .state_builder()
+            .unwrap()
+            .tdry_bulb(Temperature::<Celcius>::from(25))
+            .rel_hum(0.5)
+            .build()
+            .unwrap();
+        let pres: Pressure<Pascal> = state.pres_ambient();
+        assert!((f64::from(pres) - 84_555.5).abs() < 1.0);
+        let state = site
+            .state_builder()
+            .unwrap()
+            .tdry_bulb(Temperature::<Celcius>::from(25))
+            .rel_hum(0.5)
+            .pres_ambient(Pressure::<Pascal>::from(90_000))
+            .build()
+            .unwrap();
+        assert_eq!(
+            state.pres_ambient::<Pascal>(),
+            Pressure::<Pascal>::from(90_000)
+        );
+    }
+}
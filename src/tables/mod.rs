@@ -0,0 +1,207 @@
+//! Property tables laid out like ASHRAE Handbook - Fundamentals (2017) ch. 1 tables 2 and 3,
+//! computed with the same equations as the rest of the crate. Rows serialize to flat records, so
+//! they can be written to CSV or printed directly.
+use crate::psychrolib::{si, PsychroLibErr};
+use crate::quantities::{Pressure, SpecificEnthalpy, SpecificEntropy, Temperature};
+use crate::units::{
+    Celcius, JoulesPerKg, JoulesPerKgKelvin, Pascal, PressureUnit, TemperatureUnit,
+};
+
+/// Water at saturation, over liquid water above 0 °C and over ice below.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct SaturationRow<T: TemperatureUnit> {
+    /// Temperature
+    pub tdry_bulb: Temperature<T>,
+    /// Saturation vapor pressure
+    pub sat_vap_pres: Pressure<Pascal>,
+}
+
+/// Dry air and saturated moist air at one temperature and pressure.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct MoistAirRow<T: TemperatureUnit> {
+    /// Dry bulb temperature
+    pub tdry_bulb: Temperature<T>,
+    /// Humidity ratio at saturation in kg_H₂O kg_Air⁻¹
+    pub sat_hum_ratio: f64,
+    /// Specific volume of dry air in m³ kg_Air⁻¹
+    pub volume_dry_air: f64,
+    /// Specific volume of saturated air in m³ kg_Air⁻¹
+    pub volume_sat: f64,
+    /// Enthalpy of dry air
+    pub enthalpy_dry_air: SpecificEnthalpy<JoulesPerKg>,
+    /// Enthalpy of saturated air per kg of dry air
+    pub enthalpy_sat: SpecificEnthalpy<JoulesPerKg>,
+    /// Entropy of dry air
+    pub entropy_dry_air: SpecificEntropy<JoulesPerKgKelvin>,
+    /// Entropy of saturated air per kg of dry air
+    pub entropy_sat: SpecificEntropy<JoulesPerKgKelvin>,
+}
+
+/// Temperatures from `from` to `to` included, `step` degrees of `T` apart, each with its value in
+/// °C. `Value` when `step` is not positive or `to` is below `from`, `Range` when they leave
+/// -100 °C to 200 °C.
+fn temperatures<T: TemperatureUnit>(
+    from: &Temperature<T>,
+    to: &Temperature<T>,
+    step: f64,
+) -> Result<Vec<(Temperature<T>, f64)>, PsychroLibErr> {
+    let celcius = |t: &Temperature<T>| f64::from(&Temperature::<Celcius>::from(t));
+    si::check_tdry_bulb_range(celcius(from))?;
+    si::check_tdry_bulb_range(celcius(to))?;
+    let from = f64::from(from);
+    let to = f64::from(to);
+    if step.is_nan() || step <= 0.0 || to < from {
+        return Err(PsychroLibErr::Value);
+    }
+    // The tolerance keeps `to` when rounding leaves it a hair past the last step.
+    let count = ((to - from) / step + 1E-9).floor() as usize;
+    Ok((0..=count)
+        .map(|i| {
+            let t = Temperature::<T>::from(from + i as f64 * step);
+            let tdb = celcius(&t);
+            (t, tdb)
+        })
+        .collect())
+}
+
+/// Return the saturation table from `from` to `to` included, `step` degrees apart, with the
+/// temperatures in the unit of `from` and `to`.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 table 3
+/// `from`, `to` Temperature in °F  or °C or K
+/// `step` Temperature step in °F  or °C or K, the unit of `from` and `to`
+/// Errors with `Value` when `step` is not positive or `to` is below `from`, and with `Range`
+/// when the range leaves -100 °C to 200 °C.
+pub fn get_saturation_table<T: TemperatureUnit>(
+    from: Temperature<T>,
+    to: Temperature<T>,
+    step: f64,
+) -> Result<Vec<SaturationRow<T>>, PsychroLibErr> {
+    Ok(temperatures(&from, &to, step)?
+        .into_iter()
+        .map(|(tdry_bulb, tdb)| SaturationRow {
+            tdry_bulb,
+            sat_vap_pres: Pressure::<Pascal>::from(si::sat_vap_pres(tdb)),
+        })
+        .collect())
+}
+
+/// Return the moist air table at `pres_ambient` from `from` to `to` included, `step` degrees
+/// apart, with the temperatures in the unit of `from` and `to`. Pass the standard atmosphere for
+/// the values of the Handbook.
+/// Reference: ASHRAE Handbook - Fundamentals (2017) ch. 1 table 2
+/// `from`, `to` Dry bulb temperature in °F  or °C or K
+/// `step` Temperature step in °F  or °C or K, the unit of `from` and `to`
+/// `pres_ambient` Atmospheric pressure in Psi  or Pa or atm
+/// Errors like `get_saturation_table`, with `Value` when the pressure is not positive and with
+/// `Range` when water boils below `to` at this pressure.
+pub fn get_moist_air_table<T: TemperatureUnit, P: PressureUnit>(
+    from: Temperature<T>,
+    to: Temperature<T>,
+    step: f64,
+    pres_ambient: Pressure<P>,
+) -> Result<Vec<MoistAirRow<T>>, PsychroLibErr> {
+    let pres = f64::from(&Pressure::<Pascal>::from(&pres_ambient));
+    si::check_pres_positive(pres)?;
+    temperatures(&from, &to, step)?
+        .into_iter()
+        .map(|(tdry_bulb, tdb)| {
+            let sat_hum_ratio = si::sat_hum_ratio(tdb, pres)?;
+            Ok(MoistAirRow {
+                tdry_bulb,
+                sat_hum_ratio,
+                volume_dry_air: si::moist_air_volume(tdb, 0.0, pres)?,
+                volume_sat: si::moist_air_volume(tdb, sat_hum_ratio, pres)?,
+                enthalpy_dry_air: SpecificEnthalpy::from(si::moist_air_enthalpy(tdb, 0.0)),
+                enthalpy_sat: SpecificEnthalpy::from(si::moist_air_enthalpy(tdb, sat_hum_ratio)),
+                entropy_dry_air: SpecificEntropy::from(si::moist_air_entropy(tdb, 0.0, pres)?),
+                entropy_sat: SpecificEntropy::from(si::moist_air_entropy(
+                    tdb,
+                    sat_hum_ratio,
+                    pres,
+                )?),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Fahrenheit;
+
+    #[test]
+    fn saturation() {
+        let table = get_saturation_table(
+            Temperature::<Celcius>::from(-20),
+            Temperature::<Celcius>::from(40),
+            10.0,
+        )
+        .unwrap();
+        assert_eq!(table.len(), 7);
+        assert_eq!(table[6].tdry_bulb, Temperature::<Celcius>::from(40));
+        let sat_vap_pres = f64::from(&table[4].sat_vap_pres);
+        assert!((sat_vap_pres - 2339.3).abs() < 1.0);
+        assert!(matches!(
+            get_saturation_table(
+                Temperature::<Celcius>::from(10),
+                Temperature::<Celcius>::from(0),
+                1.0
+            ),
+            Err(PsychroLibErr::Value)
+        ));
+    }
+
+    #[test]
+    fn moist_air() {
+        let table = get_moist_air_table(
+            Temperature::<Celcius>::from(0),
+            Temperature::<Celcius>::from(30),
+            0.5,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert_eq!(table.len(), 61);
+        // 20 °C row of ASHRAE Handbook - Fundamentals (2017) ch. 1 table 2. The Handbook includes
+        // the enhancement factor, which adds about 0.4 % to the saturation humidity ratio.
+        let row = &table[40];
+        assert!((row.sat_hum_ratio - 0.014_758).abs() < 1E-4);
+        assert!((row.volume_dry_air - 0.8301).abs() < 5E-4);
+        assert!((row.volume_sat - 0.8497).abs() < 1E-3);
+        assert!((f64::from(&row.enthalpy_dry_air) - 20_121.0).abs() < 20.0);
+        assert!((f64::from(&row.enthalpy_sat) - 57_555.0).abs() < 200.0);
+        assert!((f64::from(&row.entropy_dry_air) - 71.6).abs() < 1.0);
+        // The Handbook gives 215.1 from real gas properties, the ideal mixture is lower.
+        assert!((f64::from(&row.entropy_sat) - 205.2).abs() < 0.5);
+        assert!(matches!(
+            get_moist_air_table(
+                Temperature::<Fahrenheit>::from(32),
+                Temperature::<Fahrenheit>::from(250),
+                10.0,
+                Pressure::<Pascal>::from(101_325),
+            ),
+            Err(PsychroLibErr::Range)
+        ));
+    }
+
+    #[test]
+    fn fahrenheit_rows() {
+        let table = get_moist_air_table(
+            Temperature::<Fahrenheit>::from(50),
+            Temperature::<Fahrenheit>::from(80),
+            2.0,
+            Pressure::<Pascal>::from(101_325),
+        )
+        .unwrap();
+        assert_eq!(table.len(), 16);
+        assert_eq!(table[15].tdry_bulb, Temperature::<Fahrenheit>::from(80));
+        // 68 °F is the 20 °C row.
+        assert_eq!(table[9].tdry_bulb, Temperature::<Fahrenheit>::from(68));
+        assert!((table[9].sat_hum_ratio - 0.014_758).abs() < 1E-4);
+    }
+}
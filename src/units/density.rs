@@ -0,0 +1,26 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(DensityUnit, Density);
+//Base units for Density micrograms/m³
+NewUnit!(
+    Density,
+    KgPerCubicMeter,
+    "kilogram per cubic meter",
+    "kg m⁻³",
+    1_000_000_000
+);
+NewUnit!(
+    Density,
+    GramsPerCubicMeter,
+    "gram per cubic meter",
+    "g m⁻³",
+    1_000_000
+);
+NewUnit!(
+    Density,
+    PoundsPerCubicFoot,
+    "pound per cubic foot",
+    "lb ft⁻³",
+    16_018_463_374
+);
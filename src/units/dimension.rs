@@ -0,0 +1,16 @@
+//! Dimensions of the linear quantities and the tolerance, in base units, used to compare them.
+use crate::NewDimension;
+
+NewDimension!(Density, 200);
+NewDimension!(Duration, 200);
+NewDimension!(Energy, 200);
+NewDimension!(HumidityRatio, 200);
+NewDimension!(Length, 200);
+NewDimension!(MassFlowRate, 200);
+NewDimension!(Power, 200);
+NewDimension!(Pressure, 200);
+NewDimension!(SpecificEnthalpy, 200);
+// Also the dimension of specific heat.
+NewDimension!(SpecificEntropy, 200);
+NewDimension!(SpecificVolume, 200);
+NewDimension!(VolumetricFlowRate, 10_000);
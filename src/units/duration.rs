@@ -0,0 +1,9 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(DurationUnit, Duration);
+//Base units for Duration milliseconds
+NewUnit!(Duration, Second, "second", "s", 1_000);
+NewUnit!(Duration, Minute, "minute", "min", 60_000);
+NewUnit!(Duration, Hour, "hour", "h", 3_600_000);
+NewUnit!(Duration, Day, "day", "d", 86_400_000);
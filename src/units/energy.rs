@@ -0,0 +1,9 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(EnergyUnit, Energy);
+//Base units for Energy millijoules
+NewUnit!(Energy, Joule, "joule", "J", 1_000);
+NewUnit!(Energy, Kilojoule, "kilojoule", "kJ", 1_000_000);
+NewUnit!(Energy, KilowattHour, "kilowatt hour", "kWh", 3_600_000_000);
+NewUnit!(Energy, Btu, "british thermal unit", "Btu", 1_055_056);
@@ -0,0 +1,33 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(HumidityRatioUnit, HumidityRatio);
+//Base units for Humidity Ratio micrograms/kg
+NewUnit!(
+    HumidityRatio,
+    KgPerKg,
+    "kilogram per kilogram",
+    "kg kg⁻¹",
+    1_000_000_000
+);
+NewUnit!(
+    HumidityRatio,
+    GramsPerKg,
+    "gram per kilogram",
+    "g kg⁻¹",
+    1_000_000
+);
+NewUnit!(
+    HumidityRatio,
+    PoundsPerPound,
+    "pound per pound",
+    "lb lb⁻¹",
+    1_000_000_000
+);
+NewUnit!(
+    HumidityRatio,
+    GrainsPerPound,
+    "grain per pound",
+    "gr lb⁻¹",
+    142_857
+);
@@ -0,0 +1,10 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(LengthUnit, Length);
+//Base units for Length micrometers
+NewUnit!(Length, Millimeter, "millimeter", "mm", 1_000);
+NewUnit!(Length, Meter, "meter", "m", 1_000_000);
+NewUnit!(Length, Kilometer, "kilometer", "km", 1_000_000_000);
+NewUnit!(Length, Inch, "inch", "in", 25_400);
+NewUnit!(Length, Foot, "foot", "ft", 304_800);
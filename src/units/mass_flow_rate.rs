@@ -0,0 +1,26 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(MassFlowRateUnit, MassFlowRate);
+//Base units for Mass Flow Rate milligrams/hour
+NewUnit!(
+    MassFlowRate,
+    KgPerSecond,
+    "kilogram per second",
+    "kg s⁻¹",
+    3_600_000_000
+);
+NewUnit!(
+    MassFlowRate,
+    KgPerHour,
+    "kilogram per hour",
+    "kg h⁻¹",
+    1_000_000
+);
+NewUnit!(
+    MassFlowRate,
+    PoundsPerHour,
+    "pound per hour",
+    "lb h⁻¹",
+    453_592
+);
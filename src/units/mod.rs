@@ -1,4 +1,35 @@
 mod units_base;
+pub use units_base::{Dimension, Unit, UnitKind};
+
+pub mod dimension;
+
+mod density;
+pub(crate) use density::DensityUnit;
+pub use density::{GramsPerCubicMeter, KgPerCubicMeter, PoundsPerCubicFoot};
+
+mod duration;
+pub(crate) use duration::DurationUnit;
+pub use duration::{Day, Hour, Minute, Second};
+
+mod energy;
+pub(crate) use energy::EnergyUnit;
+pub use energy::{Btu, Joule, Kilojoule, KilowattHour};
+
+mod humidity_ratio;
+pub(crate) use humidity_ratio::HumidityRatioUnit;
+pub use humidity_ratio::{GrainsPerPound, GramsPerKg, KgPerKg, PoundsPerPound};
+
+mod length;
+pub(crate) use length::LengthUnit;
+pub use length::{Foot, Inch, Kilometer, Meter, Millimeter};
+
+mod mass_flow_rate;
+pub(crate) use mass_flow_rate::MassFlowRateUnit;
+pub use mass_flow_rate::{KgPerHour, KgPerSecond, PoundsPerHour};
+
+mod power;
+pub(crate) use power::PowerUnit;
+pub use power::{BtuPerHour, Kilowatt, TonOfRefrigeration, Watt};
 
 mod pressure;
 pub(crate) use pressure::PressureUnit;
@@ -8,6 +39,23 @@ mod specific_enthalpy;
 pub(crate) use specific_enthalpy::SpecificEnthalpyUnit;
 pub use specific_enthalpy::{BtuPerPound, JoulesPerKg, KilojoulesPerKg};
 
+mod specific_entropy;
+pub(crate) use specific_entropy::SpecificEntropyUnit;
+pub use specific_entropy::{BtuPerPoundRankine, JoulesPerKgKelvin, KilojoulesPerKgKelvin};
+
+mod specific_volume;
+pub(crate) use specific_volume::SpecificVolumeUnit;
+pub use specific_volume::{CubicFeetPerPound, CubicMetersPerKg};
+
+mod system;
+pub use system::{SystemUnits, UnitSystem};
+
 mod temperature;
 pub(crate) use temperature::TemperatureUnit;
-pub use temperature::{Celcius, Fahrenheit, Kelvin};
+pub use temperature::{Celcius, Fahrenheit, Kelvin, Rankine};
+
+mod volumetric_flow_rate;
+pub(crate) use volumetric_flow_rate::VolumetricFlowRateUnit;
+pub use volumetric_flow_rate::{
+    CubicFeetPerMinute, CubicMetersPerHour, CubicMetersPerSecond, LitersPerSecond,
+};
@@ -0,0 +1,15 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(PowerUnit, Power);
+//Base units for Power microwatts
+NewUnit!(Power, Watt, "watt", "W", 1_000_000);
+NewUnit!(Power, Kilowatt, "kilowatt", "kW", 1_000_000_000);
+NewUnit!(Power, BtuPerHour, "Btu per hour", "Btu h⁻¹", 293_071);
+NewUnit!(
+    Power,
+    TonOfRefrigeration,
+    "ton of refrigeration",
+    "TR",
+    3_516_852_842
+);
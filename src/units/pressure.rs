@@ -1,7 +1,7 @@
 #[macro_use]
 use crate::{NewUnitType, NewUnit};
 
-NewUnitType!(PressureUnit);
-NewUnit!(PressureUnit, Pascal, "pascal", "Pa", 1_000);
-NewUnit!(PressureUnit, Atmosphere, "atmosphere", "atm", 101_325_000);
-NewUnit!(PressureUnit, Psi, "psi", "psi", 6_894_760);
+NewUnitType!(PressureUnit, Pressure);
+NewUnit!(Pressure, Pascal, "pascal", "Pa", 1_000);
+NewUnit!(Pressure, Atmosphere, "atmosphere", "atm", 101_325_000);
+NewUnit!(Pressure, Psi, "psi", "psi", 6_894_760);
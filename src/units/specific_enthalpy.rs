@@ -1,24 +1,24 @@
 #[macro_use]
 use crate::{NewUnitType, NewUnit};
 
-NewUnitType!(SpecificEnthalpyUnit);
+NewUnitType!(SpecificEnthalpyUnit, SpecificEnthalpy);
 //Base units for Specific Enthalpy milliJoules/kg
 NewUnit!(
-    SpecificEnthalpyUnit,
+    SpecificEnthalpy,
     JoulesPerKg,
     "joules per kilogram",
     "j kg⁻¹",
     1_000
 );
 NewUnit!(
-    SpecificEnthalpyUnit,
+    SpecificEnthalpy,
     KilojoulesPerKg,
     "kilojoules per kilogram",
     "kj kg⁻¹",
     1_000_000
 );
 NewUnit!(
-    SpecificEnthalpyUnit,
+    SpecificEnthalpy,
     BtuPerPound,
     "Btu per pound",
     "Btu lb⁻¹",
@@ -0,0 +1,26 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(SpecificEntropyUnit, SpecificEntropy);
+//Base units for Specific Entropy milliJoules/kg/K
+NewUnit!(
+    SpecificEntropy,
+    JoulesPerKgKelvin,
+    "joules per kilogram kelvin",
+    "j kg⁻¹ K⁻¹",
+    1_000
+);
+NewUnit!(
+    SpecificEntropy,
+    KilojoulesPerKgKelvin,
+    "kilojoules per kilogram kelvin",
+    "kj kg⁻¹ K⁻¹",
+    1_000_000
+);
+NewUnit!(
+    SpecificEntropy,
+    BtuPerPoundRankine,
+    "Btu per pound rankine",
+    "Btu lb⁻¹ °R⁻¹",
+    4_186_800
+);
@@ -0,0 +1,19 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(SpecificVolumeUnit, SpecificVolume);
+//Base units for Specific Volume microliters/kg
+NewUnit!(
+    SpecificVolume,
+    CubicMetersPerKg,
+    "cubic meter per kilogram",
+    "m³ kg⁻¹",
+    1_000_000_000
+);
+NewUnit!(
+    SpecificVolume,
+    CubicFeetPerPound,
+    "cubic foot per pound",
+    "ft³ lb⁻¹",
+    62_427_961
+);
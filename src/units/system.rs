@@ -0,0 +1,61 @@
+use super::{dimension, Dimension, UnitKind};
+
+/// System of units, as in PsychroLib.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitSystem {
+    /// International System, °C, Pa, J kg⁻¹.
+    #[default]
+    SI,
+    /// Inch-Pound, °F, psi, Btu lb⁻¹.
+    IP,
+}
+
+/// Unit a dimension is shown in by each system of units.
+pub trait SystemUnits: Dimension {
+    /// Unit of the International System
+    const SI: UnitKind;
+    /// Unit of the Inch-Pound system
+    const IP: UnitKind;
+}
+
+macro_rules! ImplSystemUnits {
+    ($dimension:ident, $si:ident, $ip:ident) => {
+        impl SystemUnits for dimension::$dimension {
+            const SI: UnitKind = UnitKind::$si;
+            const IP: UnitKind = UnitKind::$ip;
+        }
+    };
+}
+
+ImplSystemUnits!(Density, KgPerCubicMeter, PoundsPerCubicFoot);
+ImplSystemUnits!(Duration, Second, Second);
+ImplSystemUnits!(Energy, Kilojoule, Btu);
+ImplSystemUnits!(HumidityRatio, GramsPerKg, GrainsPerPound);
+ImplSystemUnits!(Length, Meter, Foot);
+ImplSystemUnits!(MassFlowRate, KgPerSecond, PoundsPerHour);
+ImplSystemUnits!(Power, Kilowatt, BtuPerHour);
+ImplSystemUnits!(Pressure, Pascal, Psi);
+ImplSystemUnits!(SpecificEnthalpy, KilojoulesPerKg, BtuPerPound);
+ImplSystemUnits!(SpecificEntropy, KilojoulesPerKgKelvin, BtuPerPoundRankine);
+ImplSystemUnits!(SpecificVolume, CubicMetersPerKg, CubicFeetPerPound);
+ImplSystemUnits!(VolumetricFlowRate, CubicMetersPerSecond, CubicFeetPerMinute);
+
+impl UnitSystem {
+    /// Return the unit `D` is shown in by this system.
+    #[must_use]
+    pub fn unit<D: SystemUnits>(self) -> UnitKind {
+        match self {
+            UnitSystem::SI => D::SI,
+            UnitSystem::IP => D::IP,
+        }
+    }
+
+    /// Return the unit temperatures are shown in by this system.
+    #[must_use]
+    pub fn temperature_unit(self) -> UnitKind {
+        match self {
+            UnitSystem::SI => UnitKind::Celcius,
+            UnitSystem::IP => UnitKind::Fahrenheit,
+        }
+    }
+}
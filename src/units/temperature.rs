@@ -1,23 +1,27 @@
+use super::UnitKind;
+
 pub trait TemperatureUnit {
-    fn singular_name() -> String;
-    fn abbreviation() -> String;
+    fn singular_name() -> &'static str;
+    fn abbreviation() -> &'static str;
     fn conv_factor_micro_kelvin() -> i64;
     fn conv_offset_micro_kelvin() -> i64;
+    fn kind() -> UnitKind;
 }
 
 macro_rules! NewTemperatureUnit {
     ($unit_name:ident, $singular_name:expr, $abbreviation:expr, $conv_factor:expr, $conv_offset:expr) => {
         #[derive(Debug, Clone, Eq, PartialEq)]
+        #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
         pub struct $unit_name;
 
         impl TemperatureUnit for $unit_name {
             #[inline(always)]
-            fn singular_name() -> String {
-                $singular_name.to_string()
+            fn singular_name() -> &'static str {
+                $singular_name
             }
             #[inline(always)]
-            fn abbreviation() -> String {
-                $abbreviation.to_string()
+            fn abbreviation() -> &'static str {
+                $abbreviation
             }
             #[inline(always)]
             fn conv_factor_micro_kelvin() -> i64 {
@@ -27,6 +31,10 @@ macro_rules! NewTemperatureUnit {
             fn conv_offset_micro_kelvin() -> i64 {
                 $conv_offset
             }
+            #[inline(always)]
+            fn kind() -> UnitKind {
+                UnitKind::$unit_name
+            }
         }
     };
 }
@@ -40,3 +48,10 @@ NewTemperatureUnit!(
     (1_000_000.0_f32 / 1.8_f32) as i64,
     (459_670_000.0 / 1.8) as i64
 );
+NewTemperatureUnit!(
+    Rankine,
+    "rankine",
+    "R",
+    (1_000_000.0_f32 / 1.8_f32) as i64,
+    0
+);
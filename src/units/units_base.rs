@@ -1,33 +1,181 @@
+/// Physical dimension of a quantity, such as pressure or length. Every unit of a dimension
+/// converts to the same integer base unit.
+pub trait Dimension {
+    /// Largest difference in base units for two quantities to compare equal.
+    const TOLERANCE: i64;
+}
+
+/// Unit of measurement of one dimension.
+pub trait Unit {
+    type Dimension: Dimension;
+    fn singular_name() -> &'static str;
+    fn abbreviation() -> &'static str;
+    fn conv_factor_base_unit() -> i64;
+    fn kind() -> UnitKind;
+}
+
+/// Every unit as a runtime value. Lets code that only holds a quantity through generics tell
+/// which unit it is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    Atmosphere,
+    Btu,
+    BtuPerHour,
+    BtuPerPound,
+    BtuPerPoundRankine,
+    Celcius,
+    CubicFeetPerMinute,
+    CubicFeetPerPound,
+    CubicMetersPerHour,
+    CubicMetersPerKg,
+    CubicMetersPerSecond,
+    Day,
+    Fahrenheit,
+    Foot,
+    GrainsPerPound,
+    GramsPerCubicMeter,
+    GramsPerKg,
+    Hour,
+    Inch,
+    Joule,
+    JoulesPerKg,
+    JoulesPerKgKelvin,
+    KgPerCubicMeter,
+    KgPerHour,
+    KgPerKg,
+    KgPerSecond,
+    Kelvin,
+    Kilojoule,
+    KilojoulesPerKg,
+    KilojoulesPerKgKelvin,
+    Kilometer,
+    Kilowatt,
+    KilowattHour,
+    LitersPerSecond,
+    Meter,
+    Millimeter,
+    Minute,
+    Pascal,
+    PoundsPerCubicFoot,
+    PoundsPerHour,
+    PoundsPerPound,
+    Psi,
+    Rankine,
+    Second,
+    TonOfRefrigeration,
+    Watt,
+}
+
+/// Runtime access to the constants of every unit. Temperature units convert to µK, the others to
+/// the base unit of their dimension.
+macro_rules! ImplUnitKind {
+    (linear: $($linear:ident),*; temperature: $($temperature:ident),*) => {
+        impl UnitKind {
+            /// Name of the unit, such as "pascal".
+            #[must_use]
+            pub fn singular_name(self) -> &'static str {
+                match self {
+                    $(UnitKind::$linear => <super::$linear as Unit>::singular_name(),)*
+                    $(UnitKind::$temperature => {
+                        <super::$temperature as super::TemperatureUnit>::singular_name()
+                    })*
+                }
+            }
+
+            /// Abbreviation of the unit, such as "Pa".
+            #[must_use]
+            pub fn abbreviation(self) -> &'static str {
+                match self {
+                    $(UnitKind::$linear => <super::$linear as Unit>::abbreviation(),)*
+                    $(UnitKind::$temperature => {
+                        <super::$temperature as super::TemperatureUnit>::abbreviation()
+                    })*
+                }
+            }
+
+            pub(crate) fn conv_factor(self) -> i64 {
+                match self {
+                    $(UnitKind::$linear => <super::$linear as Unit>::conv_factor_base_unit(),)*
+                    $(UnitKind::$temperature => {
+                        <super::$temperature as super::TemperatureUnit>::conv_factor_micro_kelvin()
+                    })*
+                }
+            }
+
+            pub(crate) fn conv_offset(self) -> i64 {
+                match self {
+                    $(UnitKind::$linear => 0,)*
+                    $(UnitKind::$temperature => {
+                        <super::$temperature as super::TemperatureUnit>::conv_offset_micro_kelvin()
+                    })*
+                }
+            }
+        }
+    };
+}
+
+ImplUnitKind!(
+    linear: Atmosphere, Btu, BtuPerHour, BtuPerPound, BtuPerPoundRankine, CubicFeetPerMinute,
+        CubicFeetPerPound, CubicMetersPerHour, CubicMetersPerKg, CubicMetersPerSecond, Day, Foot, GrainsPerPound, GramsPerCubicMeter,
+        GramsPerKg, Hour, Inch, Joule, JoulesPerKg, JoulesPerKgKelvin, KgPerCubicMeter, KgPerHour,
+        KgPerKg, KgPerSecond, Kilojoule, KilojoulesPerKg, KilojoulesPerKgKelvin, Kilometer,
+        Kilowatt, KilowattHour, LitersPerSecond, Meter, Millimeter, Minute, Pascal,
+        PoundsPerCubicFoot, PoundsPerHour, PoundsPerPound, Psi, Second, TonOfRefrigeration, Watt;
+    temperature: Celcius, Fahrenheit, Kelvin, Rankine
+);
+
+#[macro_export]
+macro_rules! NewDimension {
+    ($dimension:ident, $tolerance:expr) => {
+        #[derive(Debug, Clone, Eq, PartialEq)]
+        pub struct $dimension;
+
+        impl $crate::units::Dimension for $dimension {
+            const TOLERANCE: i64 = $tolerance;
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! NewUnitType {
-    ($unit_type:ident) => {
-        pub trait $unit_type {
-            fn singular_name() -> String;
-            fn abbreviation() -> String;
-            fn conv_factor_base_unit() -> i64;
+    ($unit_type:ident, $dimension:ident) => {
+        pub trait $unit_type:
+            $crate::units::Unit<Dimension = $crate::units::dimension::$dimension>
+        {
+        }
+
+        impl<U> $unit_type for U where
+            U: $crate::units::Unit<Dimension = $crate::units::dimension::$dimension>
+        {
         }
     };
 }
 
 #[macro_export]
 macro_rules! NewUnit {
-    ($unit_type:ident, $unit_name:ident, $singular_name:expr, $abbreviation:expr, $conv_factor:expr) => {
+    ($dimension:ident, $unit_name:ident, $singular_name:expr, $abbreviation:expr, $conv_factor:expr) => {
         #[derive(Debug, Clone, Eq, PartialEq)]
+        #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
         pub struct $unit_name;
 
-        impl $unit_type for $unit_name {
+        impl $crate::units::Unit for $unit_name {
+            type Dimension = $crate::units::dimension::$dimension;
             #[inline(always)]
-            fn singular_name() -> String {
-                $singular_name.to_string()
+            fn singular_name() -> &'static str {
+                $singular_name
             }
             #[inline(always)]
-            fn abbreviation() -> String {
-                $abbreviation.to_string()
+            fn abbreviation() -> &'static str {
+                $abbreviation
             }
             #[inline(always)]
             fn conv_factor_base_unit() -> i64 {
                 $conv_factor
             }
+            #[inline(always)]
+            fn kind() -> $crate::units::UnitKind {
+                $crate::units::UnitKind::$unit_name
+            }
         }
     };
 }
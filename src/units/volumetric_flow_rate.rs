@@ -0,0 +1,33 @@
+#[macro_use]
+use crate::{NewUnitType, NewUnit};
+
+NewUnitType!(VolumetricFlowRateUnit, VolumetricFlowRate);
+//Base units for Volumetric Flow Rate microliters/hour
+NewUnit!(
+    VolumetricFlowRate,
+    CubicMetersPerSecond,
+    "cubic meter per second",
+    "m³ s⁻¹",
+    3_600_000_000_000
+);
+NewUnit!(
+    VolumetricFlowRate,
+    CubicMetersPerHour,
+    "cubic meter per hour",
+    "m³ h⁻¹",
+    1_000_000_000
+);
+NewUnit!(
+    VolumetricFlowRate,
+    LitersPerSecond,
+    "liter per second",
+    "L s⁻¹",
+    3_600_000_000
+);
+NewUnit!(
+    VolumetricFlowRate,
+    CubicFeetPerMinute,
+    "cubic foot per minute",
+    "cfm",
+    1_699_010_796
+);
@@ -0,0 +1,162 @@
+//! Reference test vectors and a self test that runs them through the public API.
+//! Integrators can call `run_self_test` at startup or in their own CI to check numerical
+//! conformance with PsychroLib and the ASHRAE tables it is validated against.
+use crate::psychrolib::{get_moist_air_enthalpy_from_hum_ratio, get_sat_vap_pres, PsychroLibErr};
+use crate::quantities::{Pressure, SpecificEnthalpy, Temperature};
+use crate::state::MoistAirState;
+use crate::units::{Celcius, JoulesPerKg, Pascal};
+
+/// Saturation vapor pressure over the range of validity.
+/// ASHRAE Handbook - Fundamentals (2017) ch. 1 table 3. (°C, Pa, relative tolerance)
+const SAT_VAP_PRES: [(f64, f64, f64); 15] = [
+    (-60.0, 1.08, 0.03),
+    (-40.0, 12.84, 0.0005),
+    (-20.0, 103.24, 0.0005),
+    (-10.0, 259.87, 0.0005),
+    (0.0, 611.15, 0.0005),
+    (5.0, 872.58, 0.0005),
+    (10.0, 1228.2, 0.0005),
+    (20.0, 2339.3, 0.0005),
+    (25.0, 3169.9, 0.0005),
+    (30.0, 4247.0, 0.0005),
+    (40.0, 7384.9, 0.0005),
+    (50.0, 12352.0, 0.0003),
+    (100.0, 101_420.0, 0.0003),
+    (150.0, 476_160.0, 0.0003),
+    (200.0, 1_554_900.0, 0.0003),
+];
+
+/// Wet bulb from relative humidity that didn't converge in PsychroLib <= 2.0.0.
+/// (°C, [0-1], Pa, °C, relative tolerance)
+const TWET_BULB: [(f64, f64, f64, f64, f64); 1] =
+    [(7.0, 0.61, 100_000.0, 3.926_674_337_819_55, 0.0003)];
+
+/// Example 1 of ASHRAE Handbook - Fundamentals (2017) ch. 1. 40 °C dry bulb and 20 °C wet bulb
+/// at 101 325 Pa.
+const EXAMPLE_1: (f64, f64, f64) = (40.0, 20.0, 101_325.0);
+const EXAMPLE_1_HUM_RATIO: (f64, f64) = (0.0065, 0.0001);
+const EXAMPLE_1_TDEW_POINT: (f64, f64) = (7.0, 0.5);
+const EXAMPLE_1_REL_HUM: (f64, f64) = (0.14, 0.01);
+const EXAMPLE_1_ENTHALPY: (f64, f64) = (56_700.0, 100.0);
+
+/// Largest deviation from the reference values found for one function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deviation {
+    /// Name of the function as exported by the crate.
+    pub function: &'static str,
+    /// Largest absolute deviation in the SI unit of the result.
+    pub max_deviation: f64,
+    /// True when every reference value was reproduced within its tolerance.
+    pub within_tolerance: bool,
+}
+
+/// Deviations of every function covered by the self test.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SelfTestReport {
+    pub deviations: Vec<Deviation>,
+}
+
+impl SelfTestReport {
+    /// True when every function reproduced its reference values.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.deviations.iter().all(|d| d.within_tolerance)
+    }
+
+    fn record(&mut self, function: &'static str, calculated: f64, expected: f64, tolerance: f64) {
+        let deviation = (calculated - expected).abs();
+        let within_tolerance = deviation <= tolerance;
+        match self.deviations.iter_mut().find(|d| d.function == function) {
+            Some(d) => {
+                d.max_deviation = d.max_deviation.max(deviation);
+                d.within_tolerance &= within_tolerance;
+            }
+            None => self.deviations.push(Deviation {
+                function,
+                max_deviation: deviation,
+                within_tolerance,
+            }),
+        }
+    }
+}
+
+/// Runs the embedded reference vectors through the public API and returns the largest
+/// deviation per function. Errors only when a calculation itself fails.
+pub fn run_self_test() -> Result<SelfTestReport, PsychroLibErr> {
+    let mut report = SelfTestReport::default();
+
+    for (tdry_bulb, expected, rel_tolerance) in SAT_VAP_PRES {
+        let sat_vap_pres: Pressure<Pascal> =
+            get_sat_vap_pres(Temperature::<Celcius>::from(tdry_bulb))?;
+        report.record(
+            "get_sat_vap_pres",
+            f64::from(sat_vap_pres),
+            expected,
+            expected * rel_tolerance,
+        );
+    }
+
+    for (tdry_bulb, rel_hum, pres, expected, rel_tolerance) in TWET_BULB {
+        let state = MoistAirState::from_rel_hum(
+            Temperature::<Celcius>::from(tdry_bulb),
+            rel_hum,
+            Pressure::<Pascal>::from(pres),
+        )?;
+        let twet_bulb = f64::from(state.twet_bulb::<Celcius>()?);
+        report.record(
+            "MoistAirState::twet_bulb",
+            twet_bulb,
+            expected,
+            expected * rel_tolerance,
+        );
+    }
+
+    let (tdry_bulb, twet_bulb, pres) = EXAMPLE_1;
+    let state = MoistAirState::from_twet_bulb(
+        Temperature::<Celcius>::from(tdry_bulb),
+        Temperature::<Celcius>::from(twet_bulb),
+        Pressure::<Pascal>::from(pres),
+    )?;
+    let (expected, tolerance) = EXAMPLE_1_HUM_RATIO;
+    report.record(
+        "MoistAirState::from_twet_bulb",
+        state.hum_ratio(),
+        expected,
+        tolerance,
+    );
+    let (expected, tolerance) = EXAMPLE_1_TDEW_POINT;
+    let tdew_point = f64::from(state.tdew_point::<Celcius>()?);
+    report.record("MoistAirState::tdew_point", tdew_point, expected, tolerance);
+    let (expected, tolerance) = EXAMPLE_1_REL_HUM;
+    report.record(
+        "MoistAirState::rel_hum",
+        state.rel_hum()?,
+        expected,
+        tolerance,
+    );
+    let (expected, tolerance) = EXAMPLE_1_ENTHALPY;
+    let enthalpy: SpecificEnthalpy<JoulesPerKg> = get_moist_air_enthalpy_from_hum_ratio(
+        Temperature::<Celcius>::from(tdry_bulb),
+        state.hum_ratio(),
+    )?;
+    report.record(
+        "get_moist_air_enthalpy_from_hum_ratio",
+        f64::from(enthalpy),
+        expected,
+        tolerance,
+    );
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_test_passes() {
+        let report = run_self_test().unwrap();
+        assert!(report.passed(), "{report:?}");
+        assert_eq!(report.deviations.len(), 6);
+    }
+}